@@ -69,7 +69,8 @@ where
                         .option_parameter
                         .get("SLACK_CHANNEL_ID")
                         .unwrap()
-                        .to_string(),
+                        .as_str()
+                        .into(),
                     thread_ts: Some(reply_thread_ts),
                     text: Some(reply_text),
                     ..Default::default()
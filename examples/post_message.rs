@@ -17,7 +17,7 @@ async fn main() {
 
     let slack_api_client = default_client();
     let param = PostMessageRequest {
-        channel: slack_channel_id,
+        channel: slack_channel_id.into(),
         text: Some("Hello world".to_string()),
         attachments: Some(vec![Attachment {
             color: Some("#36a64f".to_string()),
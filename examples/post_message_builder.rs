@@ -19,7 +19,7 @@ async fn main() {
         .author_icon("https://2.bp.blogspot.com/-3o7K8_p8NNM/WGCRsl8GiCI/AAAAAAABAoc/XKnspjvc0YIoOiSRK9HW6wXhtlnZvHQ9QCLcB/s800/pyoko_hashiru.png".to_string())
         .title("slack_rust_example".to_string())
         .build()];
-    let param = PostMessageRequest::builder(slack_channel_id)
+    let param = PostMessageRequest::builder(slack_channel_id.into())
         .text("Hello World!!".to_string())
         .attachments(attachments)
         .build();
@@ -68,7 +68,8 @@ where
                         .option_parameter
                         .get("SLACK_CHANNEL_ID")
                         .unwrap()
-                        .to_string(),
+                        .as_str()
+                        .into(),
                     text: Some("Message received!!".to_string()),
                     ..Default::default()
                 };
@@ -1,7 +1,7 @@
 //! Generate a temporary Socket Mode WebSocket URL that your app can connect to in order to receive events and interactive payloads over.    
 
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
@@ -23,11 +23,12 @@ pub async fn connections_open<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("apps.connections.open");
-
-    client.post(&url, app_token).await.and_then(|result| {
-        serde_json::from_str::<ConnectionsOpenResponse>(&result).map_err(Error::SerdeJsonError)
-    })
+    client
+        .call("apps.connections.open", app_token)
+        .await
+        .and_then(|result| {
+            serde_json::from_str::<ConnectionsOpenResponse>(&result).map_err(Error::SerdeJsonError)
+        })
 }
 
 #[cfg(test)]
@@ -57,13 +58,15 @@ mod test {
     #[async_std::test]
     async fn test_connections_open() {
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post().returning(|_, _| {
-            Ok(r##"{
+        mock.expect_call()
+            .withf(|method, _| method == "apps.connections.open")
+            .returning(|_, _| {
+                Ok(r##"{
           "ok": true,
           "url": "wss://wss-somethiing.slack.com/link/?ticket=12348&app_id=5678"   
         }"##
-            .to_string())
-        });
+                .to_string())
+            });
 
         let response = connections_open(&mock, &"test_token".to_string())
             .await
@@ -1,7 +1,7 @@
 //! Uninstalls your app from a workspace.  
 
 use crate::error::Error;
-use crate::http_client::{get_slack_url, DefaultResponse, SlackWebAPIClient};
+use crate::http_client::{DefaultResponse, SlackWebAPIClient};
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
@@ -29,11 +29,10 @@ pub async fn uninstall<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("apps.uninstall");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("apps.uninstall", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<DefaultResponse>(&result).map_err(Error::SerdeJsonError)
@@ -71,7 +70,7 @@ mod test {
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
           "ok": true
         }"##
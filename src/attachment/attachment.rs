@@ -1,5 +1,6 @@
 //! [Attachment](https://api.slack.com/reference/messaging/attachments)
 
+#[cfg(feature = "block-kit")]
 use crate::block::blocks::Block;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -79,10 +80,11 @@ pub struct Attachment {
     pub original_url: Option<String>,
     pub fields: Option<Vec<AttachmentField>>,
     pub mrkdwn_in: Option<Vec<String>>,
+    #[cfg(feature = "block-kit")]
     pub blocks: Option<Vec<Block>>,
     pub footer: Option<String>,
     pub footer_icon: Option<String>,
-    pub ts: Option<i32>,
+    pub ts: Option<i64>,
 }
 
 impl Attachment {
@@ -114,10 +116,11 @@ pub struct AttachmentBuilder {
     pub original_url: Option<String>,
     pub fields: Option<Vec<AttachmentField>>,
     pub mrkdwn_in: Option<Vec<String>>,
+    #[cfg(feature = "block-kit")]
     pub blocks: Option<Vec<Block>>,
     pub footer: Option<String>,
     pub footer_icon: Option<String>,
-    pub ts: Option<i32>,
+    pub ts: Option<i64>,
 }
 
 impl AttachmentBuilder {
@@ -196,6 +199,7 @@ impl AttachmentBuilder {
         self.mrkdwn_in = Some(mrkdwn_in);
         self
     }
+    #[cfg(feature = "block-kit")]
     pub fn blocks(mut self, blocks: Vec<Block>) -> AttachmentBuilder {
         self.blocks = Some(blocks);
         self
@@ -204,7 +208,7 @@ impl AttachmentBuilder {
         self.footer = Some(footer);
         self
     }
-    pub fn ts(mut self, ts: i32) -> AttachmentBuilder {
+    pub fn ts(mut self, ts: i64) -> AttachmentBuilder {
         self.ts = Some(ts);
         self
     }
@@ -231,6 +235,7 @@ impl AttachmentBuilder {
             original_url: self.original_url,
             fields: self.fields,
             mrkdwn_in: self.mrkdwn_in,
+            #[cfg(feature = "block-kit")]
             blocks: self.blocks,
             footer: self.footer,
             footer_icon: self.footer_icon,
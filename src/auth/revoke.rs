@@ -1,7 +1,7 @@
 //! Revokes a token.
 
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
@@ -30,11 +30,10 @@ pub async fn revoke<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("auth.revoke");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("auth.revoke", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<RevokeResponse>(&result).map_err(Error::SerdeJsonError)
@@ -84,7 +83,7 @@ mod test {
         let param = RevokeRequest { test: Some(false) };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
           "ok": true,
           "revoked": true
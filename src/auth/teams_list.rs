@@ -1,7 +1,7 @@
 //! List the workspaces a token can access.
 
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
 use crate::team::teams::Team;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -33,11 +33,10 @@ pub async fn teams_list<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("auth.teams.list");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("auth.teams.list", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<TeamsListResponse>(&result).map_err(Error::SerdeJsonError)
@@ -75,12 +74,12 @@ mod test {
             ok: true,
             teams: Some(vec![
                 Team {
-                    id: Some("T12345678".to_string()),
+                    id: Some("T12345678".into()),
                     name: Some("Shinichi's workspace".to_string()),
                     ..Default::default()
                 },
                 Team {
-                    id: Some("T12345679".to_string()),
+                    id: Some("T12345679".into()),
                     name: Some("Migi's workspace".to_string()),
                     ..Default::default()
                 },
@@ -117,7 +116,7 @@ mod test {
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "teams": [
@@ -141,12 +140,12 @@ mod test {
             ok: true,
             teams: Some(vec![
                 Team {
-                    id: Some("T12345678".to_string()),
+                    id: Some("T12345678".into()),
                     name: Some("Shinichi's workspace".to_string()),
                     ..Default::default()
                 },
                 Team {
-                    id: Some("T12345679".to_string()),
+                    id: Some("T12345679".into()),
                     name: Some("Migi's workspace".to_string()),
                     ..Default::default()
                 },
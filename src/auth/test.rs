@@ -1,7 +1,8 @@
 //! Checks authentication & identity.
 
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::{TeamId, UserId};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
@@ -12,10 +13,12 @@ pub struct TestResponse {
     pub error: Option<String>,
     pub response_metadata: Option<ResponseMetadata>,
     pub url: Option<String>,
+    /// The workspace name, not its ID.
     pub team: Option<String>,
+    /// The user name, not its ID.
     pub user: Option<String>,
-    pub team_id: Option<String>,
-    pub user_id: Option<String>,
+    pub team_id: Option<TeamId>,
+    pub user_id: Option<UserId>,
 }
 
 /// Checks authentication & identity.
@@ -24,11 +27,12 @@ pub async fn test<T>(client: &T, bot_token: &str) -> Result<TestResponse, Error>
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("auth.test");
-
-    client.post(&url, bot_token).await.and_then(|result| {
-        serde_json::from_str::<TestResponse>(&result).map_err(Error::SerdeJsonError)
-    })
+    client
+        .call("auth.test", bot_token)
+        .await
+        .and_then(|result| {
+            serde_json::from_str::<TestResponse>(&result).map_err(Error::SerdeJsonError)
+        })
 }
 
 #[cfg(test)]
@@ -43,8 +47,8 @@ mod test {
             url: Some("https://subarachnoid.slack.com/".to_string()),
             team: Some("Subarachnoid Workspace".to_string()),
             user: Some("grace".to_string()),
-            team_id: Some("T12345678".to_string()),
-            user_id: Some("W12345678".to_string()),
+            team_id: Some("T12345678".into()),
+            user_id: Some("W12345678".into()),
             ..Default::default()
         };
         let json = r##"{
@@ -66,7 +70,7 @@ mod test {
     #[async_std::test]
     async fn test_auth_test() {
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post().returning(|_, _| {
+        mock.expect_call().returning(|_, _| {
             Ok(r##"{
           "ok": true,
           "url": "https://subarachnoid.slack.com/",
@@ -84,8 +88,8 @@ mod test {
             url: Some("https://subarachnoid.slack.com/".to_string()),
             team: Some("Subarachnoid Workspace".to_string()),
             user: Some("grace".to_string()),
-            team_id: Some("T12345678".to_string()),
-            user_id: Some("W12345678".to_string()),
+            team_id: Some("T12345678".into()),
+            user_id: Some("W12345678".into()),
             ..Default::default()
         };
 
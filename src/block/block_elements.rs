@@ -1,14 +1,16 @@
 //! Slack Block elements  
 use crate::block::block_object::{
     ConfirmationBlockObject, DispatchActionConfig, OptionBlockObject, OptionGroupBlockObject,
-    TextBlockObject,
+    TextBlockObject, WorkflowObject,
 };
-use serde::{Deserialize, Serialize};
+use crate::block::block_rich_text::RichTextBlock;
+use crate::block::blocks::{deserialize_known, type_name};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_with::skip_serializing_none;
 
 /// See: <https://api.slack.com/reference/block-kit/block-elements>
 #[derive(Deserialize, Serialize, Debug, PartialEq)]
-#[serde(tag = "type")]
+#[serde(tag = "type", remote = "Self")]
 pub enum BlockElement {
     #[serde(rename = "button")]
     ButtonElement(ButtonElement),
@@ -16,22 +18,86 @@ pub enum BlockElement {
     CheckboxGroupsBlockElement(CheckboxGroupsBlockElement),
     #[serde(rename = "datepicker")]
     DatePickerBlockElement(DatePickerBlockElement),
+    #[serde(rename = "datetimepicker")]
+    DateTimePickerBlockElement(DateTimePickerBlockElement),
+    #[serde(rename = "email_text_input")]
+    EmailTextInputBlockElement(EmailTextInputBlockElement),
+    #[serde(rename = "file_input")]
+    FileInputBlockElement(FileInputBlockElement),
     #[serde(rename = "image")]
     ImageBlockElement(ImageBlockElement),
     #[serde(rename = "multi_static_select")]
     MultiSelectBlockElement(MultiSelectBlockElement),
+    #[serde(rename = "number_input")]
+    NumberInputBlockElement(NumberInputBlockElement),
     #[serde(rename = "overflow")]
     OverflowBlockElement(OverflowBlockElement),
     #[serde(rename = "plain_text_input")]
     PlainTextInputBlockElement(PlainTextInputBlockElement),
     #[serde(rename = "radio_buttons")]
     RadioButtonsBlockElement(RadioButtonsBlockElement),
+    #[serde(rename = "rich_text_input")]
+    RichTextInputBlockElement(RichTextInputBlockElement),
     #[serde(rename = "static_select")]
     SelectBlockElement(SelectBlockElement),
     #[serde(rename = "timepicker")]
     TimePickerBlockElement(TimePickerBlockElement),
+    #[serde(rename = "url_text_input")]
+    UrlTextInputBlockElement(UrlTextInputBlockElement),
+    #[serde(rename = "workflow_button")]
+    WorkflowButtonElement(WorkflowButtonElement),
     #[serde(skip)]
     None,
+    /// A block element this crate doesn't model, kept as is so that it survives a round trip.
+    #[serde(skip)]
+    Unknown(serde_json::Value),
+}
+
+/// The `type` tags of the [`BlockElement`] variants that carry a payload.
+const BLOCK_ELEMENT_TYPES: &[&str] = &[
+    "button",
+    "checkboxes",
+    "datepicker",
+    "datetimepicker",
+    "email_text_input",
+    "file_input",
+    "image",
+    "multi_static_select",
+    "number_input",
+    "overflow",
+    "plain_text_input",
+    "radio_buttons",
+    "rich_text_input",
+    "static_select",
+    "timepicker",
+    "url_text_input",
+    "workflow_button",
+];
+
+impl<'de> Deserialize<'de> for BlockElement {
+    fn deserialize<D>(deserializer: D) -> Result<BlockElement, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_known(
+            deserializer,
+            BLOCK_ELEMENT_TYPES,
+            |raw| BlockElement::deserialize(raw),
+            BlockElement::Unknown,
+        )
+    }
+}
+
+impl Serialize for BlockElement {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            BlockElement::Unknown(raw) => raw.serialize(serializer),
+            value => BlockElement::serialize(value, serializer),
+        }
+    }
 }
 
 impl BlockElement {
@@ -44,10 +110,22 @@ impl BlockElement {
             BlockElement::DatePickerBlockElement(DatePickerBlockElement { .. }) => {
                 BlockElementType::Datepicker
             }
+            BlockElement::DateTimePickerBlockElement(DateTimePickerBlockElement { .. }) => {
+                BlockElementType::Datetimepicker
+            }
+            BlockElement::EmailTextInputBlockElement(EmailTextInputBlockElement { .. }) => {
+                BlockElementType::EmailTextInput
+            }
+            BlockElement::FileInputBlockElement(FileInputBlockElement { .. }) => {
+                BlockElementType::FileInput
+            }
             BlockElement::ImageBlockElement(ImageBlockElement { .. }) => BlockElementType::Image,
             BlockElement::MultiSelectBlockElement(MultiSelectBlockElement { .. }) => {
                 BlockElementType::MultiStaticSelect
             }
+            BlockElement::NumberInputBlockElement(NumberInputBlockElement { .. }) => {
+                BlockElementType::NumberInput
+            }
             BlockElement::OverflowBlockElement(OverflowBlockElement { .. }) => {
                 BlockElementType::Overflow
             }
@@ -57,13 +135,23 @@ impl BlockElement {
             BlockElement::RadioButtonsBlockElement(RadioButtonsBlockElement { .. }) => {
                 BlockElementType::RadioButtons
             }
+            BlockElement::RichTextInputBlockElement(RichTextInputBlockElement { .. }) => {
+                BlockElementType::RichTextInput
+            }
             BlockElement::SelectBlockElement(SelectBlockElement { .. }) => {
                 BlockElementType::StaticSelect
             }
             BlockElement::TimePickerBlockElement(TimePickerBlockElement { .. }) => {
                 BlockElementType::Timepicker
             }
+            BlockElement::UrlTextInputBlockElement(UrlTextInputBlockElement { .. }) => {
+                BlockElementType::UrlTextInput
+            }
+            BlockElement::WorkflowButtonElement(WorkflowButtonElement { .. }) => {
+                BlockElementType::WorkflowButton
+            }
             BlockElement::None => BlockElementType::None,
+            BlockElement::Unknown(raw) => BlockElementType::Unknown(type_name(raw)),
         }
     }
 }
@@ -128,15 +216,25 @@ pub enum BlockElementType {
     Button,
     Checkboxes,
     Datepicker,
+    Datetimepicker,
+    EmailTextInput,
+    FileInput,
     Image,
     MultiStaticSelect,
+    NumberInput,
     Overflow,
     PlainTextInput,
     RadioButtons,
+    RichTextInput,
     StaticSelect,
     Timepicker,
+    UrlTextInput,
+    WorkflowButton,
     #[serde(skip)]
     None,
+    /// A value this crate doesn't know yet.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl Default for BlockElementType {
@@ -341,6 +439,180 @@ impl DatePickerBlockElementBuilder {
     }
 }
 
+/// An element that allows the selection of a time of day formatted as a UNIX timestamp.  
+/// See: <https://api.slack.com/reference/block-kit/block-elements#datetimepicker>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct DateTimePickerBlockElement {
+    pub action_id: String,
+    pub initial_date_time: Option<i64>,
+    pub confirm: Option<ConfirmationBlockObject>,
+    pub focus_on_load: Option<bool>,
+}
+
+impl DateTimePickerBlockElement {
+    pub fn builder(action_id: String) -> DateTimePickerBlockElementBuilder {
+        DateTimePickerBlockElementBuilder::new(action_id)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct DateTimePickerBlockElementBuilder {
+    pub action_id: String,
+    pub initial_date_time: Option<i64>,
+    pub confirm: Option<ConfirmationBlockObject>,
+    pub focus_on_load: Option<bool>,
+}
+
+impl DateTimePickerBlockElementBuilder {
+    pub fn new(action_id: String) -> DateTimePickerBlockElementBuilder {
+        DateTimePickerBlockElementBuilder {
+            action_id,
+            ..Default::default()
+        }
+    }
+    pub fn initial_date_time(
+        mut self,
+        initial_date_time: i64,
+    ) -> DateTimePickerBlockElementBuilder {
+        self.initial_date_time = Some(initial_date_time);
+        self
+    }
+    pub fn confirm(
+        mut self,
+        confirm: ConfirmationBlockObject,
+    ) -> DateTimePickerBlockElementBuilder {
+        self.confirm = Some(confirm);
+        self
+    }
+    pub fn focus_on_load(mut self, focus_on_load: bool) -> DateTimePickerBlockElementBuilder {
+        self.focus_on_load = Some(focus_on_load);
+        self
+    }
+    pub fn build(self) -> DateTimePickerBlockElement {
+        DateTimePickerBlockElement {
+            action_id: self.action_id,
+            initial_date_time: self.initial_date_time,
+            confirm: self.confirm,
+            focus_on_load: self.focus_on_load,
+        }
+    }
+}
+
+/// An email input element, similar to the plain-text input element, creates a single line field where a user can enter an email address.  
+/// See: <https://api.slack.com/reference/block-kit/block-elements#email>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct EmailTextInputBlockElement {
+    pub action_id: String,
+    pub initial_value: Option<String>,
+    pub dispatch_action_config: Option<DispatchActionConfig>,
+    pub focus_on_load: Option<bool>,
+    pub placeholder: Option<TextBlockObject>,
+}
+
+impl EmailTextInputBlockElement {
+    pub fn builder(action_id: String) -> EmailTextInputBlockElementBuilder {
+        EmailTextInputBlockElementBuilder::new(action_id)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct EmailTextInputBlockElementBuilder {
+    pub action_id: String,
+    pub initial_value: Option<String>,
+    pub dispatch_action_config: Option<DispatchActionConfig>,
+    pub focus_on_load: Option<bool>,
+    pub placeholder: Option<TextBlockObject>,
+}
+
+impl EmailTextInputBlockElementBuilder {
+    pub fn new(action_id: String) -> EmailTextInputBlockElementBuilder {
+        EmailTextInputBlockElementBuilder {
+            action_id,
+            ..Default::default()
+        }
+    }
+    pub fn initial_value(mut self, initial_value: String) -> EmailTextInputBlockElementBuilder {
+        self.initial_value = Some(initial_value);
+        self
+    }
+    pub fn dispatch_action_config(
+        mut self,
+        dispatch_action_config: DispatchActionConfig,
+    ) -> EmailTextInputBlockElementBuilder {
+        self.dispatch_action_config = Some(dispatch_action_config);
+        self
+    }
+    pub fn focus_on_load(mut self, focus_on_load: bool) -> EmailTextInputBlockElementBuilder {
+        self.focus_on_load = Some(focus_on_load);
+        self
+    }
+    pub fn placeholder(
+        mut self,
+        placeholder: TextBlockObject,
+    ) -> EmailTextInputBlockElementBuilder {
+        self.placeholder = Some(placeholder);
+        self
+    }
+    pub fn build(self) -> EmailTextInputBlockElement {
+        EmailTextInputBlockElement {
+            action_id: self.action_id,
+            initial_value: self.initial_value,
+            dispatch_action_config: self.dispatch_action_config,
+            focus_on_load: self.focus_on_load,
+            placeholder: self.placeholder,
+        }
+    }
+}
+
+/// Allows user to upload files.  
+/// See: <https://api.slack.com/reference/block-kit/block-elements#file_input>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct FileInputBlockElement {
+    pub action_id: String,
+    pub filetypes: Option<Vec<String>>,
+    pub max_files: Option<i32>,
+}
+
+impl FileInputBlockElement {
+    pub fn builder(action_id: String) -> FileInputBlockElementBuilder {
+        FileInputBlockElementBuilder::new(action_id)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct FileInputBlockElementBuilder {
+    pub action_id: String,
+    pub filetypes: Option<Vec<String>>,
+    pub max_files: Option<i32>,
+}
+
+impl FileInputBlockElementBuilder {
+    pub fn new(action_id: String) -> FileInputBlockElementBuilder {
+        FileInputBlockElementBuilder {
+            action_id,
+            ..Default::default()
+        }
+    }
+    pub fn filetypes(mut self, filetypes: Vec<String>) -> FileInputBlockElementBuilder {
+        self.filetypes = Some(filetypes);
+        self
+    }
+    pub fn max_files(mut self, max_files: i32) -> FileInputBlockElementBuilder {
+        self.max_files = Some(max_files);
+        self
+    }
+    pub fn build(self) -> FileInputBlockElement {
+        FileInputBlockElement {
+            action_id: self.action_id,
+            filetypes: self.filetypes,
+            max_files: self.max_files,
+        }
+    }
+}
+
 /// An element to insert an image as part of a larger block of content.  
 /// See: <https://api.slack.com/reference/block-kit/block-elements#image>
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
@@ -482,6 +754,88 @@ impl MultiSelectBlockElementBuilder {
     }
 }
 
+/// Allows user to enter a number into a single-line field.  
+/// See: <https://api.slack.com/reference/block-kit/block-elements#number>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct NumberInputBlockElement {
+    pub is_decimal_allowed: bool,
+    pub action_id: String,
+    pub initial_value: Option<String>,
+    pub min_value: Option<String>,
+    pub max_value: Option<String>,
+    pub dispatch_action_config: Option<DispatchActionConfig>,
+    pub focus_on_load: Option<bool>,
+    pub placeholder: Option<TextBlockObject>,
+}
+
+impl NumberInputBlockElement {
+    pub fn builder(is_decimal_allowed: bool, action_id: String) -> NumberInputBlockElementBuilder {
+        NumberInputBlockElementBuilder::new(is_decimal_allowed, action_id)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct NumberInputBlockElementBuilder {
+    pub is_decimal_allowed: bool,
+    pub action_id: String,
+    pub initial_value: Option<String>,
+    pub min_value: Option<String>,
+    pub max_value: Option<String>,
+    pub dispatch_action_config: Option<DispatchActionConfig>,
+    pub focus_on_load: Option<bool>,
+    pub placeholder: Option<TextBlockObject>,
+}
+
+impl NumberInputBlockElementBuilder {
+    pub fn new(is_decimal_allowed: bool, action_id: String) -> NumberInputBlockElementBuilder {
+        NumberInputBlockElementBuilder {
+            is_decimal_allowed,
+            action_id,
+            ..Default::default()
+        }
+    }
+    pub fn initial_value(mut self, initial_value: String) -> NumberInputBlockElementBuilder {
+        self.initial_value = Some(initial_value);
+        self
+    }
+    pub fn min_value(mut self, min_value: String) -> NumberInputBlockElementBuilder {
+        self.min_value = Some(min_value);
+        self
+    }
+    pub fn max_value(mut self, max_value: String) -> NumberInputBlockElementBuilder {
+        self.max_value = Some(max_value);
+        self
+    }
+    pub fn dispatch_action_config(
+        mut self,
+        dispatch_action_config: DispatchActionConfig,
+    ) -> NumberInputBlockElementBuilder {
+        self.dispatch_action_config = Some(dispatch_action_config);
+        self
+    }
+    pub fn focus_on_load(mut self, focus_on_load: bool) -> NumberInputBlockElementBuilder {
+        self.focus_on_load = Some(focus_on_load);
+        self
+    }
+    pub fn placeholder(mut self, placeholder: TextBlockObject) -> NumberInputBlockElementBuilder {
+        self.placeholder = Some(placeholder);
+        self
+    }
+    pub fn build(self) -> NumberInputBlockElement {
+        NumberInputBlockElement {
+            is_decimal_allowed: self.is_decimal_allowed,
+            action_id: self.action_id,
+            initial_value: self.initial_value,
+            min_value: self.min_value,
+            max_value: self.max_value,
+            dispatch_action_config: self.dispatch_action_config,
+            focus_on_load: self.focus_on_load,
+            placeholder: self.placeholder,
+        }
+    }
+}
+
 /// This is like a cross between a button and a select menu.  
 /// See: <https://api.slack.com/reference/block-kit/block-elements#overflow>
 #[skip_serializing_none]
@@ -677,6 +1031,73 @@ impl RadioButtonsBlockElementBuilder {
     }
 }
 
+/// A rich text input creates a composer/WYSIWYG editor for entering formatted text.  
+/// See: <https://api.slack.com/reference/block-kit/block-elements#rich_text_input>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct RichTextInputBlockElement {
+    pub action_id: String,
+    pub initial_value: Option<RichTextBlock>,
+    pub dispatch_action_config: Option<DispatchActionConfig>,
+    pub focus_on_load: Option<bool>,
+    pub placeholder: Option<TextBlockObject>,
+}
+
+impl RichTextInputBlockElement {
+    pub fn builder(action_id: String) -> RichTextInputBlockElementBuilder {
+        RichTextInputBlockElementBuilder::new(action_id)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct RichTextInputBlockElementBuilder {
+    pub action_id: String,
+    pub initial_value: Option<RichTextBlock>,
+    pub dispatch_action_config: Option<DispatchActionConfig>,
+    pub focus_on_load: Option<bool>,
+    pub placeholder: Option<TextBlockObject>,
+}
+
+impl RichTextInputBlockElementBuilder {
+    pub fn new(action_id: String) -> RichTextInputBlockElementBuilder {
+        RichTextInputBlockElementBuilder {
+            action_id,
+            ..Default::default()
+        }
+    }
+    pub fn initial_value(
+        mut self,
+        initial_value: RichTextBlock,
+    ) -> RichTextInputBlockElementBuilder {
+        self.initial_value = Some(initial_value);
+        self
+    }
+    pub fn dispatch_action_config(
+        mut self,
+        dispatch_action_config: DispatchActionConfig,
+    ) -> RichTextInputBlockElementBuilder {
+        self.dispatch_action_config = Some(dispatch_action_config);
+        self
+    }
+    pub fn focus_on_load(mut self, focus_on_load: bool) -> RichTextInputBlockElementBuilder {
+        self.focus_on_load = Some(focus_on_load);
+        self
+    }
+    pub fn placeholder(mut self, placeholder: TextBlockObject) -> RichTextInputBlockElementBuilder {
+        self.placeholder = Some(placeholder);
+        self
+    }
+    pub fn build(self) -> RichTextInputBlockElement {
+        RichTextInputBlockElement {
+            action_id: self.action_id,
+            initial_value: self.initial_value,
+            dispatch_action_config: self.dispatch_action_config,
+            focus_on_load: self.focus_on_load,
+            placeholder: self.placeholder,
+        }
+    }
+}
+
 /// A select menu, just as with a standard HTML <select> tag, creates a drop down menu with a list of options for a user to choose.  
 /// See: <https://api.slack.com/reference/block-kit/block-elements#select>
 #[skip_serializing_none]
@@ -851,3 +1272,247 @@ impl TimePickerBlockElementBuilder {
         }
     }
 }
+
+/// A URL input element, similar to the plain-text input element, creates a single line field where a user can enter URL-encoded data.  
+/// See: <https://api.slack.com/reference/block-kit/block-elements#url>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct UrlTextInputBlockElement {
+    pub action_id: String,
+    pub initial_value: Option<String>,
+    pub dispatch_action_config: Option<DispatchActionConfig>,
+    pub focus_on_load: Option<bool>,
+    pub placeholder: Option<TextBlockObject>,
+}
+
+impl UrlTextInputBlockElement {
+    pub fn builder(action_id: String) -> UrlTextInputBlockElementBuilder {
+        UrlTextInputBlockElementBuilder::new(action_id)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct UrlTextInputBlockElementBuilder {
+    pub action_id: String,
+    pub initial_value: Option<String>,
+    pub dispatch_action_config: Option<DispatchActionConfig>,
+    pub focus_on_load: Option<bool>,
+    pub placeholder: Option<TextBlockObject>,
+}
+
+impl UrlTextInputBlockElementBuilder {
+    pub fn new(action_id: String) -> UrlTextInputBlockElementBuilder {
+        UrlTextInputBlockElementBuilder {
+            action_id,
+            ..Default::default()
+        }
+    }
+    pub fn initial_value(mut self, initial_value: String) -> UrlTextInputBlockElementBuilder {
+        self.initial_value = Some(initial_value);
+        self
+    }
+    pub fn dispatch_action_config(
+        mut self,
+        dispatch_action_config: DispatchActionConfig,
+    ) -> UrlTextInputBlockElementBuilder {
+        self.dispatch_action_config = Some(dispatch_action_config);
+        self
+    }
+    pub fn focus_on_load(mut self, focus_on_load: bool) -> UrlTextInputBlockElementBuilder {
+        self.focus_on_load = Some(focus_on_load);
+        self
+    }
+    pub fn placeholder(mut self, placeholder: TextBlockObject) -> UrlTextInputBlockElementBuilder {
+        self.placeholder = Some(placeholder);
+        self
+    }
+    pub fn build(self) -> UrlTextInputBlockElement {
+        UrlTextInputBlockElement {
+            action_id: self.action_id,
+            initial_value: self.initial_value,
+            dispatch_action_config: self.dispatch_action_config,
+            focus_on_load: self.focus_on_load,
+            placeholder: self.placeholder,
+        }
+    }
+}
+
+/// Allows users to run a link trigger with customizable inputs.  
+/// See: <https://api.slack.com/reference/block-kit/block-elements#workflow_button>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct WorkflowButtonElement {
+    pub text: TextBlockObject,
+    pub workflow: WorkflowObject,
+    pub action_id: String,
+    pub style: Option<String>,
+    pub accessibility_label: Option<String>,
+}
+
+impl WorkflowButtonElement {
+    pub fn builder(
+        text: TextBlockObject,
+        workflow: WorkflowObject,
+        action_id: String,
+    ) -> WorkflowButtonElementBuilder {
+        WorkflowButtonElementBuilder::new(text, workflow, action_id)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct WorkflowButtonElementBuilder {
+    pub text: TextBlockObject,
+    pub workflow: WorkflowObject,
+    pub action_id: String,
+    pub style: Option<String>,
+    pub accessibility_label: Option<String>,
+}
+
+impl WorkflowButtonElementBuilder {
+    pub fn new(
+        text: TextBlockObject,
+        workflow: WorkflowObject,
+        action_id: String,
+    ) -> WorkflowButtonElementBuilder {
+        WorkflowButtonElementBuilder {
+            text,
+            workflow,
+            action_id,
+            ..Default::default()
+        }
+    }
+    pub fn style(mut self, style: String) -> WorkflowButtonElementBuilder {
+        self.style = Some(style);
+        self
+    }
+    pub fn accessibility_label(
+        mut self,
+        accessibility_label: String,
+    ) -> WorkflowButtonElementBuilder {
+        self.accessibility_label = Some(accessibility_label);
+        self
+    }
+    pub fn build(self) -> WorkflowButtonElement {
+        WorkflowButtonElement {
+            text: self.text,
+            workflow: self.workflow,
+            action_id: self.action_id,
+            style: self.style,
+            accessibility_label: self.accessibility_label,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::block::block_object::{InputParameterObject, TextBlockType, TriggerObject};
+
+    #[test]
+    fn convert_input_elements() {
+        let elements = vec![
+            BlockElement::DateTimePickerBlockElement(
+                DateTimePickerBlockElement::builder("datetime".to_string())
+                    .initial_date_time(1628633820)
+                    .build(),
+            ),
+            BlockElement::EmailTextInputBlockElement(
+                EmailTextInputBlockElement::builder("email".to_string()).build(),
+            ),
+            BlockElement::FileInputBlockElement(
+                FileInputBlockElement::builder("file".to_string())
+                    .filetypes(vec!["png".to_string()])
+                    .max_files(1)
+                    .build(),
+            ),
+            BlockElement::NumberInputBlockElement(
+                NumberInputBlockElement::builder(false, "number".to_string())
+                    .min_value("1".to_string())
+                    .build(),
+            ),
+            BlockElement::UrlTextInputBlockElement(
+                UrlTextInputBlockElement::builder("url".to_string()).build(),
+            ),
+            BlockElement::WorkflowButtonElement(
+                WorkflowButtonElement::builder(
+                    TextBlockObject::builder(TextBlockType::PlainText, "Run".to_string()).build(),
+                    WorkflowObject::new(
+                        TriggerObject::builder(
+                            "https://slack.com/shortcuts/Ft0123ABC456/xyz".to_string(),
+                        )
+                        .customizable_input_parameters(vec![InputParameterObject::new(
+                            "input_parameter_a".to_string(),
+                            "Value for input param A".to_string(),
+                        )])
+                        .build(),
+                    ),
+                    "workflow".to_string(),
+                )
+                .build(),
+            ),
+        ];
+        let json = r##"[
+  {
+    "type": "datetimepicker",
+    "action_id": "datetime",
+    "initial_date_time": 1628633820
+  },
+  {
+    "type": "email_text_input",
+    "action_id": "email"
+  },
+  {
+    "type": "file_input",
+    "action_id": "file",
+    "filetypes": [
+      "png"
+    ],
+    "max_files": 1
+  },
+  {
+    "type": "number_input",
+    "is_decimal_allowed": false,
+    "action_id": "number",
+    "min_value": "1"
+  },
+  {
+    "type": "url_text_input",
+    "action_id": "url"
+  },
+  {
+    "type": "workflow_button",
+    "text": {
+      "type": "plain_text",
+      "text": "Run"
+    },
+    "workflow": {
+      "trigger": {
+        "url": "https://slack.com/shortcuts/Ft0123ABC456/xyz",
+        "customizable_input_parameters": [
+          {
+            "name": "input_parameter_a",
+            "value": "Value for input param A"
+          }
+        ]
+      }
+    },
+    "action_id": "workflow"
+  }
+]"##;
+
+        let j = serde_json::to_string_pretty(&elements).unwrap();
+        assert_eq!(json, j);
+
+        let s = serde_json::from_str::<Vec<BlockElement>>(json).unwrap();
+        assert_eq!(elements, s);
+    }
+
+    #[test]
+    fn list_every_block_element_type() {
+        let error = BlockElement::deserialize(serde_json::json!({"type": "none"})).unwrap_err();
+        assert_eq!(
+            crate::block::blocks::test::modelled_tags(error),
+            BLOCK_ELEMENT_TYPES
+        );
+    }
+}
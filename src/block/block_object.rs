@@ -62,6 +62,9 @@ pub enum TextBlockType {
     Mrkdwn,
     #[serde(skip)]
     None,
+    /// A value this crate doesn't know yet.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl Default for TextBlockType {
@@ -204,3 +207,71 @@ pub struct SelectBlockElementFilter {
     pub exclude_external_shared_channel: Option<bool>,
     pub exclude_bot_users: Option<bool>,
 }
+
+/// Defines the workflow that a workflow button triggers.  
+/// See: <https://api.slack.com/reference/block-kit/composition-objects#workflow>
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct WorkflowObject {
+    pub trigger: TriggerObject,
+}
+
+impl WorkflowObject {
+    pub fn new(trigger: TriggerObject) -> WorkflowObject {
+        WorkflowObject { trigger }
+    }
+}
+
+/// Provides a link trigger and its customizable input parameters.  
+/// See: <https://api.slack.com/reference/block-kit/composition-objects#trigger>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct TriggerObject {
+    pub url: String,
+    pub customizable_input_parameters: Option<Vec<InputParameterObject>>,
+}
+
+impl TriggerObject {
+    pub fn builder(url: String) -> TriggerObjectBuilder {
+        TriggerObjectBuilder::new(url)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct TriggerObjectBuilder {
+    pub url: String,
+    pub customizable_input_parameters: Option<Vec<InputParameterObject>>,
+}
+
+impl TriggerObjectBuilder {
+    pub fn new(url: String) -> TriggerObjectBuilder {
+        TriggerObjectBuilder {
+            url,
+            ..Default::default()
+        }
+    }
+    pub fn customizable_input_parameters(
+        mut self,
+        customizable_input_parameters: Vec<InputParameterObject>,
+    ) -> TriggerObjectBuilder {
+        self.customizable_input_parameters = Some(customizable_input_parameters);
+        self
+    }
+    pub fn build(self) -> TriggerObject {
+        TriggerObject {
+            url: self.url,
+            customizable_input_parameters: self.customizable_input_parameters,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct InputParameterObject {
+    pub name: String,
+    pub value: String,
+}
+
+impl InputParameterObject {
+    pub fn new(name: String, value: String) -> InputParameterObject {
+        InputParameterObject { name, value }
+    }
+}
@@ -0,0 +1,909 @@
+//! Displays formatted, structured representation of text.
+
+use crate::block::blocks::{deserialize_known, type_name};
+use crate::id::{ChannelId, UserId, UsergroupId};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_with::skip_serializing_none;
+
+/// Displays formatted, structured representation of text.  
+/// See: <https://api.slack.com/reference/block-kit/blocks#rich_text>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct RichTextBlock {
+    pub elements: Vec<RichTextElement>,
+    pub block_id: Option<String>,
+}
+
+impl RichTextBlock {
+    pub fn builder(elements: Vec<RichTextElement>) -> RichTextBlockBuilder {
+        RichTextBlockBuilder::new(elements)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct RichTextBlockBuilder {
+    pub elements: Vec<RichTextElement>,
+    pub block_id: Option<String>,
+}
+
+impl RichTextBlockBuilder {
+    pub fn new(elements: Vec<RichTextElement>) -> RichTextBlockBuilder {
+        RichTextBlockBuilder {
+            elements,
+            ..Default::default()
+        }
+    }
+    pub fn block_id(mut self, block_id: String) -> RichTextBlockBuilder {
+        self.block_id = Some(block_id);
+        self
+    }
+    pub fn build(self) -> RichTextBlock {
+        RichTextBlock {
+            elements: self.elements,
+            block_id: self.block_id,
+        }
+    }
+}
+
+/// See: <https://api.slack.com/reference/block-kit/blocks#rich_text>
+#[derive(Deserialize, Serialize, Debug, PartialEq)]
+#[serde(tag = "type")]
+pub enum RichTextElement {
+    #[serde(rename = "rich_text_section")]
+    RichTextSection(RichTextSection),
+    #[serde(rename = "rich_text_list")]
+    RichTextList(RichTextList),
+    #[serde(rename = "rich_text_preformatted")]
+    RichTextPreformatted(RichTextPreformatted),
+    #[serde(rename = "rich_text_quote")]
+    RichTextQuote(RichTextQuote),
+    #[serde(skip)]
+    None,
+}
+
+impl Default for RichTextElement {
+    fn default() -> Self {
+        RichTextElement::None
+    }
+}
+
+impl RichTextElement {
+    pub fn element_type(&self) -> RichTextElementType {
+        match self {
+            RichTextElement::RichTextSection(RichTextSection { .. }) => {
+                RichTextElementType::RichTextSection
+            }
+            RichTextElement::RichTextList(RichTextList { .. }) => RichTextElementType::RichTextList,
+            RichTextElement::RichTextPreformatted(RichTextPreformatted { .. }) => {
+                RichTextElementType::RichTextPreformatted
+            }
+            RichTextElement::RichTextQuote(RichTextQuote { .. }) => {
+                RichTextElementType::RichTextQuote
+            }
+            RichTextElement::None => RichTextElementType::None,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RichTextElementType {
+    RichTextSection,
+    RichTextList,
+    RichTextPreformatted,
+    RichTextQuote,
+    #[serde(skip)]
+    None,
+}
+
+impl Default for RichTextElementType {
+    fn default() -> Self {
+        RichTextElementType::None
+    }
+}
+
+/// A section of rich text, made up of inline elements.  
+/// See: <https://api.slack.com/reference/block-kit/blocks#rich_text_section>
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct RichTextSection {
+    pub elements: Vec<RichTextSectionElement>,
+}
+
+impl RichTextSection {
+    pub fn new(elements: Vec<RichTextSectionElement>) -> RichTextSection {
+        RichTextSection { elements }
+    }
+}
+
+/// A bulleted or ordered list of rich text sections.  
+/// See: <https://api.slack.com/reference/block-kit/blocks#rich_text_list>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct RichTextList {
+    pub style: String,
+    pub elements: Vec<RichTextElement>,
+    pub indent: Option<i32>,
+    pub offset: Option<i32>,
+    pub border: Option<i32>,
+}
+
+impl RichTextList {
+    pub fn builder(style: String, elements: Vec<RichTextElement>) -> RichTextListBuilder {
+        RichTextListBuilder::new(style, elements)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct RichTextListBuilder {
+    pub style: String,
+    pub elements: Vec<RichTextElement>,
+    pub indent: Option<i32>,
+    pub offset: Option<i32>,
+    pub border: Option<i32>,
+}
+
+impl RichTextListBuilder {
+    pub fn new(style: String, elements: Vec<RichTextElement>) -> RichTextListBuilder {
+        RichTextListBuilder {
+            style,
+            elements,
+            ..Default::default()
+        }
+    }
+    pub fn indent(mut self, indent: i32) -> RichTextListBuilder {
+        self.indent = Some(indent);
+        self
+    }
+    pub fn offset(mut self, offset: i32) -> RichTextListBuilder {
+        self.offset = Some(offset);
+        self
+    }
+    pub fn border(mut self, border: i32) -> RichTextListBuilder {
+        self.border = Some(border);
+        self
+    }
+    pub fn build(self) -> RichTextList {
+        RichTextList {
+            style: self.style,
+            elements: self.elements,
+            indent: self.indent,
+            offset: self.offset,
+            border: self.border,
+        }
+    }
+}
+
+/// A block of preformatted (code) text.  
+/// See: <https://api.slack.com/reference/block-kit/blocks#rich_text_preformatted>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct RichTextPreformatted {
+    pub elements: Vec<RichTextSectionElement>,
+    pub border: Option<i32>,
+}
+
+impl RichTextPreformatted {
+    pub fn builder(elements: Vec<RichTextSectionElement>) -> RichTextPreformattedBuilder {
+        RichTextPreformattedBuilder::new(elements)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct RichTextPreformattedBuilder {
+    pub elements: Vec<RichTextSectionElement>,
+    pub border: Option<i32>,
+}
+
+impl RichTextPreformattedBuilder {
+    pub fn new(elements: Vec<RichTextSectionElement>) -> RichTextPreformattedBuilder {
+        RichTextPreformattedBuilder {
+            elements,
+            ..Default::default()
+        }
+    }
+    pub fn border(mut self, border: i32) -> RichTextPreformattedBuilder {
+        self.border = Some(border);
+        self
+    }
+    pub fn build(self) -> RichTextPreformatted {
+        RichTextPreformatted {
+            elements: self.elements,
+            border: self.border,
+        }
+    }
+}
+
+/// A quoted block of rich text.  
+/// See: <https://api.slack.com/reference/block-kit/blocks#rich_text_quote>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct RichTextQuote {
+    pub elements: Vec<RichTextSectionElement>,
+    pub border: Option<i32>,
+}
+
+impl RichTextQuote {
+    pub fn builder(elements: Vec<RichTextSectionElement>) -> RichTextQuoteBuilder {
+        RichTextQuoteBuilder::new(elements)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct RichTextQuoteBuilder {
+    pub elements: Vec<RichTextSectionElement>,
+    pub border: Option<i32>,
+}
+
+impl RichTextQuoteBuilder {
+    pub fn new(elements: Vec<RichTextSectionElement>) -> RichTextQuoteBuilder {
+        RichTextQuoteBuilder {
+            elements,
+            ..Default::default()
+        }
+    }
+    pub fn border(mut self, border: i32) -> RichTextQuoteBuilder {
+        self.border = Some(border);
+        self
+    }
+    pub fn build(self) -> RichTextQuote {
+        RichTextQuote {
+            elements: self.elements,
+            border: self.border,
+        }
+    }
+}
+
+/// Inline elements of a rich text section, preformatted or quote.  
+/// See: <https://api.slack.com/reference/block-kit/blocks#element-types>
+#[derive(Deserialize, Serialize, Debug, PartialEq)]
+#[serde(tag = "type", remote = "Self")]
+pub enum RichTextSectionElement {
+    #[serde(rename = "text")]
+    RichTextTextElement(RichTextTextElement),
+    #[serde(rename = "link")]
+    RichTextLinkElement(RichTextLinkElement),
+    #[serde(rename = "user")]
+    RichTextUserElement(RichTextUserElement),
+    #[serde(rename = "channel")]
+    RichTextChannelElement(RichTextChannelElement),
+    #[serde(rename = "usergroup")]
+    RichTextUsergroupElement(RichTextUsergroupElement),
+    #[serde(rename = "emoji")]
+    RichTextEmojiElement(RichTextEmojiElement),
+    #[serde(rename = "broadcast")]
+    RichTextBroadcastElement(RichTextBroadcastElement),
+    #[serde(rename = "date")]
+    RichTextDateElement(RichTextDateElement),
+    #[serde(rename = "color")]
+    RichTextColorElement(RichTextColorElement),
+    #[serde(skip)]
+    None,
+    /// A rich text element this crate doesn't model, kept as is so that it survives a round trip.
+    #[serde(skip)]
+    Unknown(serde_json::Value),
+}
+
+/// The `type` tags of the [`RichTextSectionElement`] variants that carry a payload.
+const RICH_TEXT_SECTION_ELEMENT_TYPES: &[&str] = &[
+    "text",
+    "link",
+    "user",
+    "channel",
+    "usergroup",
+    "emoji",
+    "broadcast",
+    "date",
+    "color",
+];
+
+impl<'de> Deserialize<'de> for RichTextSectionElement {
+    fn deserialize<D>(deserializer: D) -> Result<RichTextSectionElement, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_known(
+            deserializer,
+            RICH_TEXT_SECTION_ELEMENT_TYPES,
+            |raw| RichTextSectionElement::deserialize(raw),
+            RichTextSectionElement::Unknown,
+        )
+    }
+}
+
+impl Serialize for RichTextSectionElement {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            RichTextSectionElement::Unknown(raw) => raw.serialize(serializer),
+            value => RichTextSectionElement::serialize(value, serializer),
+        }
+    }
+}
+
+impl Default for RichTextSectionElement {
+    fn default() -> Self {
+        RichTextSectionElement::None
+    }
+}
+
+impl RichTextSectionElement {
+    pub fn element_type(&self) -> RichTextSectionElementType {
+        match self {
+            RichTextSectionElement::RichTextTextElement(RichTextTextElement { .. }) => {
+                RichTextSectionElementType::Text
+            }
+            RichTextSectionElement::RichTextLinkElement(RichTextLinkElement { .. }) => {
+                RichTextSectionElementType::Link
+            }
+            RichTextSectionElement::RichTextUserElement(RichTextUserElement { .. }) => {
+                RichTextSectionElementType::User
+            }
+            RichTextSectionElement::RichTextChannelElement(RichTextChannelElement { .. }) => {
+                RichTextSectionElementType::Channel
+            }
+            RichTextSectionElement::RichTextUsergroupElement(RichTextUsergroupElement {
+                ..
+            }) => RichTextSectionElementType::Usergroup,
+            RichTextSectionElement::RichTextEmojiElement(RichTextEmojiElement { .. }) => {
+                RichTextSectionElementType::Emoji
+            }
+            RichTextSectionElement::RichTextBroadcastElement(RichTextBroadcastElement {
+                ..
+            }) => RichTextSectionElementType::Broadcast,
+            RichTextSectionElement::RichTextDateElement(RichTextDateElement { .. }) => {
+                RichTextSectionElementType::Date
+            }
+            RichTextSectionElement::RichTextColorElement(RichTextColorElement { .. }) => {
+                RichTextSectionElementType::Color
+            }
+            RichTextSectionElement::None => RichTextSectionElementType::None,
+            RichTextSectionElement::Unknown(raw) => {
+                RichTextSectionElementType::Unknown(type_name(raw))
+            }
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RichTextSectionElementType {
+    Text,
+    Link,
+    User,
+    Channel,
+    Usergroup,
+    Emoji,
+    Broadcast,
+    Date,
+    Color,
+    #[serde(skip)]
+    None,
+    /// A value this crate doesn't know yet.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl Default for RichTextSectionElementType {
+    fn default() -> Self {
+        RichTextSectionElementType::None
+    }
+}
+
+/// Text styles that can be applied to inline rich text elements.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct RichTextStyle {
+    pub bold: Option<bool>,
+    pub italic: Option<bool>,
+    pub strike: Option<bool>,
+    pub code: Option<bool>,
+}
+
+impl RichTextStyle {
+    pub fn builder() -> RichTextStyleBuilder {
+        RichTextStyleBuilder::new()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct RichTextStyleBuilder {
+    pub bold: Option<bool>,
+    pub italic: Option<bool>,
+    pub strike: Option<bool>,
+    pub code: Option<bool>,
+}
+
+impl RichTextStyleBuilder {
+    pub fn new() -> RichTextStyleBuilder {
+        RichTextStyleBuilder {
+            ..Default::default()
+        }
+    }
+    pub fn bold(mut self, bold: bool) -> RichTextStyleBuilder {
+        self.bold = Some(bold);
+        self
+    }
+    pub fn italic(mut self, italic: bool) -> RichTextStyleBuilder {
+        self.italic = Some(italic);
+        self
+    }
+    pub fn strike(mut self, strike: bool) -> RichTextStyleBuilder {
+        self.strike = Some(strike);
+        self
+    }
+    pub fn code(mut self, code: bool) -> RichTextStyleBuilder {
+        self.code = Some(code);
+        self
+    }
+    pub fn build(self) -> RichTextStyle {
+        RichTextStyle {
+            bold: self.bold,
+            italic: self.italic,
+            strike: self.strike,
+            code: self.code,
+        }
+    }
+}
+
+/// A run of text, optionally styled.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct RichTextTextElement {
+    pub text: String,
+    pub style: Option<RichTextStyle>,
+}
+
+impl RichTextTextElement {
+    pub fn builder(text: String) -> RichTextTextElementBuilder {
+        RichTextTextElementBuilder::new(text)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct RichTextTextElementBuilder {
+    pub text: String,
+    pub style: Option<RichTextStyle>,
+}
+
+impl RichTextTextElementBuilder {
+    pub fn new(text: String) -> RichTextTextElementBuilder {
+        RichTextTextElementBuilder {
+            text,
+            ..Default::default()
+        }
+    }
+    pub fn style(mut self, style: RichTextStyle) -> RichTextTextElementBuilder {
+        self.style = Some(style);
+        self
+    }
+    pub fn build(self) -> RichTextTextElement {
+        RichTextTextElement {
+            text: self.text,
+            style: self.style,
+        }
+    }
+}
+
+/// A hyperlink, optionally with display text.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct RichTextLinkElement {
+    pub url: String,
+    pub text: Option<String>,
+    #[serde(rename = "unsafe")]
+    pub is_unsafe: Option<bool>,
+    pub style: Option<RichTextStyle>,
+}
+
+impl RichTextLinkElement {
+    pub fn builder(url: String) -> RichTextLinkElementBuilder {
+        RichTextLinkElementBuilder::new(url)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct RichTextLinkElementBuilder {
+    pub url: String,
+    pub text: Option<String>,
+    pub is_unsafe: Option<bool>,
+    pub style: Option<RichTextStyle>,
+}
+
+impl RichTextLinkElementBuilder {
+    pub fn new(url: String) -> RichTextLinkElementBuilder {
+        RichTextLinkElementBuilder {
+            url,
+            ..Default::default()
+        }
+    }
+    pub fn text(mut self, text: String) -> RichTextLinkElementBuilder {
+        self.text = Some(text);
+        self
+    }
+    pub fn is_unsafe(mut self, is_unsafe: bool) -> RichTextLinkElementBuilder {
+        self.is_unsafe = Some(is_unsafe);
+        self
+    }
+    pub fn style(mut self, style: RichTextStyle) -> RichTextLinkElementBuilder {
+        self.style = Some(style);
+        self
+    }
+    pub fn build(self) -> RichTextLinkElement {
+        RichTextLinkElement {
+            url: self.url,
+            text: self.text,
+            is_unsafe: self.is_unsafe,
+            style: self.style,
+        }
+    }
+}
+
+/// A mention of a user.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct RichTextUserElement {
+    pub user_id: UserId,
+    pub style: Option<RichTextStyle>,
+}
+
+impl RichTextUserElement {
+    pub fn new(user_id: UserId) -> RichTextUserElement {
+        RichTextUserElement {
+            user_id,
+            style: None,
+        }
+    }
+}
+
+/// A mention of a channel.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct RichTextChannelElement {
+    pub channel_id: ChannelId,
+    pub style: Option<RichTextStyle>,
+}
+
+impl RichTextChannelElement {
+    pub fn new(channel_id: ChannelId) -> RichTextChannelElement {
+        RichTextChannelElement {
+            channel_id,
+            style: None,
+        }
+    }
+}
+
+/// A mention of a user group.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct RichTextUsergroupElement {
+    pub usergroup_id: UsergroupId,
+    pub style: Option<RichTextStyle>,
+}
+
+impl RichTextUsergroupElement {
+    pub fn new(usergroup_id: UsergroupId) -> RichTextUsergroupElement {
+        RichTextUsergroupElement {
+            usergroup_id,
+            style: None,
+        }
+    }
+}
+
+/// An emoji, by its short name.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct RichTextEmojiElement {
+    pub name: String,
+    pub unicode: Option<String>,
+    pub skin_tone: Option<i32>,
+}
+
+impl RichTextEmojiElement {
+    pub fn builder(name: String) -> RichTextEmojiElementBuilder {
+        RichTextEmojiElementBuilder::new(name)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct RichTextEmojiElementBuilder {
+    pub name: String,
+    pub unicode: Option<String>,
+    pub skin_tone: Option<i32>,
+}
+
+impl RichTextEmojiElementBuilder {
+    pub fn new(name: String) -> RichTextEmojiElementBuilder {
+        RichTextEmojiElementBuilder {
+            name,
+            ..Default::default()
+        }
+    }
+    pub fn unicode(mut self, unicode: String) -> RichTextEmojiElementBuilder {
+        self.unicode = Some(unicode);
+        self
+    }
+    pub fn skin_tone(mut self, skin_tone: i32) -> RichTextEmojiElementBuilder {
+        self.skin_tone = Some(skin_tone);
+        self
+    }
+    pub fn build(self) -> RichTextEmojiElement {
+        RichTextEmojiElement {
+            name: self.name,
+            unicode: self.unicode,
+            skin_tone: self.skin_tone,
+        }
+    }
+}
+
+/// A special mention such as `@here`, `@channel` or `@everyone`.
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct RichTextBroadcastElement {
+    pub range: String,
+}
+
+impl RichTextBroadcastElement {
+    pub fn new(range: String) -> RichTextBroadcastElement {
+        RichTextBroadcastElement { range }
+    }
+}
+
+/// A date, rendered in the reader's timezone.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct RichTextDateElement {
+    pub timestamp: i64,
+    pub format: String,
+    pub url: Option<String>,
+    pub fallback: Option<String>,
+}
+
+impl RichTextDateElement {
+    pub fn builder(timestamp: i64, format: String) -> RichTextDateElementBuilder {
+        RichTextDateElementBuilder::new(timestamp, format)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct RichTextDateElementBuilder {
+    pub timestamp: i64,
+    pub format: String,
+    pub url: Option<String>,
+    pub fallback: Option<String>,
+}
+
+impl RichTextDateElementBuilder {
+    pub fn new(timestamp: i64, format: String) -> RichTextDateElementBuilder {
+        RichTextDateElementBuilder {
+            timestamp,
+            format,
+            ..Default::default()
+        }
+    }
+    pub fn url(mut self, url: String) -> RichTextDateElementBuilder {
+        self.url = Some(url);
+        self
+    }
+    pub fn fallback(mut self, fallback: String) -> RichTextDateElementBuilder {
+        self.fallback = Some(fallback);
+        self
+    }
+    pub fn build(self) -> RichTextDateElement {
+        RichTextDateElement {
+            timestamp: self.timestamp,
+            format: self.format,
+            url: self.url,
+            fallback: self.fallback,
+        }
+    }
+}
+
+/// A color swatch, given as a hex value.
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct RichTextColorElement {
+    pub value: String,
+}
+
+impl RichTextColorElement {
+    pub fn new(value: String) -> RichTextColorElement {
+        RichTextColorElement { value }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::block::blocks::Block;
+
+    #[test]
+    fn convert_rich_text_block() {
+        let block = Block::RichTextBlock(RichTextBlock {
+            block_id: Some("2lS9".to_string()),
+            elements: vec![
+                RichTextElement::RichTextSection(RichTextSection::new(vec![
+                    RichTextSectionElement::RichTextTextElement(
+                        RichTextTextElement::builder("Hello ".to_string())
+                            .style(RichTextStyle::builder().bold(true).build())
+                            .build(),
+                    ),
+                    RichTextSectionElement::RichTextUserElement(RichTextUserElement::new(
+                        "U061F7AUR".into(),
+                    )),
+                    RichTextSectionElement::RichTextEmojiElement(
+                        RichTextEmojiElement::builder("wave".to_string())
+                            .unicode("1f44b".to_string())
+                            .build(),
+                    ),
+                ])),
+                RichTextElement::RichTextList(
+                    RichTextList::builder(
+                        "bullet".to_string(),
+                        vec![RichTextElement::RichTextSection(RichTextSection::new(
+                            vec![RichTextSectionElement::RichTextLinkElement(
+                                RichTextLinkElement::builder("https://slack.com".to_string())
+                                    .text("Slack".to_string())
+                                    .build(),
+                            )],
+                        ))],
+                    )
+                    .indent(0)
+                    .build(),
+                ),
+                RichTextElement::RichTextPreformatted(
+                    RichTextPreformatted::builder(vec![
+                        RichTextSectionElement::RichTextTextElement(
+                            RichTextTextElement::builder("cargo test".to_string()).build(),
+                        ),
+                    ])
+                    .build(),
+                ),
+                RichTextElement::RichTextQuote(
+                    RichTextQuote::builder(vec![
+                        RichTextSectionElement::RichTextBroadcastElement(
+                            RichTextBroadcastElement::new("here".to_string()),
+                        ),
+                        RichTextSectionElement::RichTextDateElement(
+                            RichTextDateElement::builder(1640258472, "{date_short}".to_string())
+                                .fallback("Dec 23, 2021".to_string())
+                                .build(),
+                        ),
+                    ])
+                    .build(),
+                ),
+            ],
+        });
+        let json = r##"{
+  "type": "rich_text",
+  "elements": [
+    {
+      "type": "rich_text_section",
+      "elements": [
+        {
+          "type": "text",
+          "text": "Hello ",
+          "style": {
+            "bold": true
+          }
+        },
+        {
+          "type": "user",
+          "user_id": "U061F7AUR"
+        },
+        {
+          "type": "emoji",
+          "name": "wave",
+          "unicode": "1f44b"
+        }
+      ]
+    },
+    {
+      "type": "rich_text_list",
+      "style": "bullet",
+      "elements": [
+        {
+          "type": "rich_text_section",
+          "elements": [
+            {
+              "type": "link",
+              "url": "https://slack.com",
+              "text": "Slack"
+            }
+          ]
+        }
+      ],
+      "indent": 0
+    },
+    {
+      "type": "rich_text_preformatted",
+      "elements": [
+        {
+          "type": "text",
+          "text": "cargo test"
+        }
+      ]
+    },
+    {
+      "type": "rich_text_quote",
+      "elements": [
+        {
+          "type": "broadcast",
+          "range": "here"
+        },
+        {
+          "type": "date",
+          "timestamp": 1640258472,
+          "format": "{date_short}",
+          "fallback": "Dec 23, 2021"
+        }
+      ]
+    }
+  ],
+  "block_id": "2lS9"
+}"##;
+
+        let j = serde_json::to_string_pretty(&block).unwrap();
+        assert_eq!(json, j);
+
+        let s = serde_json::from_str::<Block>(json).unwrap();
+        assert_eq!(block, s);
+    }
+
+    #[test]
+    fn round_trip_unknown_blocks() {
+        use crate::block::block_elements::BlockElementType;
+        use crate::block::blocks::BlockType;
+
+        let json = serde_json::json!([
+            {
+                "type": "table",
+                "rows": [[{"type": "raw_text", "text": "a"}]]
+            },
+            {
+                "type": "actions",
+                "elements": [{"type": "icon_button", "icon": "trash", "action_id": "delete"}]
+            },
+            {
+                "type": "rich_text",
+                "elements": [{
+                    "type": "rich_text_section",
+                    "elements": [{"type": "team", "team_id": "T0123"}]
+                }]
+            }
+        ]);
+        let blocks = serde_json::from_value::<Vec<Block>>(json.clone()).unwrap();
+
+        assert_eq!(
+            blocks[0].block_type(),
+            BlockType::Unknown("table".to_string())
+        );
+        match &blocks[1] {
+            Block::ActionBlock(block) => assert_eq!(
+                block.elements[0].block_type(),
+                BlockElementType::Unknown("icon_button".to_string())
+            ),
+            block => panic!("unexpected block: {:?}", block),
+        }
+        match &blocks[2] {
+            Block::RichTextBlock(RichTextBlock { elements, .. }) => match &elements[0] {
+                RichTextElement::RichTextSection(section) => assert_eq!(
+                    section.elements[0].element_type(),
+                    RichTextSectionElementType::Unknown("team".to_string())
+                ),
+                element => panic!("unexpected element: {:?}", element),
+            },
+            block => panic!("unexpected block: {:?}", block),
+        }
+        assert_eq!(serde_json::to_value(&blocks).unwrap(), json);
+    }
+
+    #[test]
+    fn list_every_rich_text_section_element_type() {
+        let error =
+            RichTextSectionElement::deserialize(serde_json::json!({"type": "none"})).unwrap_err();
+        assert_eq!(
+            crate::block::blocks::test::modelled_tags(error),
+            RICH_TEXT_SECTION_ELEMENT_TYPES
+        );
+    }
+}
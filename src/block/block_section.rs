@@ -14,6 +14,7 @@ pub struct SectionBlock {
     pub block_id: Option<String>,
     pub fields: Option<Vec<TextBlockObject>>,
     pub accessory: Option<BlockElement>,
+    pub expand: Option<bool>,
 }
 
 impl SectionBlock {
@@ -28,6 +29,7 @@ pub struct SectionBlockBuilder {
     pub block_id: Option<String>,
     pub fields: Option<Vec<TextBlockObject>>,
     pub accessory: Option<BlockElement>,
+    pub expand: Option<bool>,
 }
 
 impl SectionBlockBuilder {
@@ -52,12 +54,17 @@ impl SectionBlockBuilder {
         self.accessory = Some(accessory);
         self
     }
+    pub fn expand(mut self, expand: bool) -> SectionBlockBuilder {
+        self.expand = Some(expand);
+        self
+    }
     pub fn build(self) -> SectionBlock {
         SectionBlock {
             text: self.text,
             block_id: self.block_id,
             fields: self.fields,
             accessory: self.accessory,
+            expand: self.expand,
         }
     }
 }
@@ -0,0 +1,102 @@
+//! A video block is designed to embed videos in all app surfaces.
+
+use crate::block::block_object::TextBlockObject;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// A video block is designed to embed videos in all app surfaces.  
+/// See: <https://api.slack.com/reference/block-kit/blocks#video>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct VideoBlock {
+    pub alt_text: String,
+    pub title: TextBlockObject,
+    pub thumbnail_url: String,
+    pub video_url: String,
+    pub author_name: Option<String>,
+    pub block_id: Option<String>,
+    pub description: Option<TextBlockObject>,
+    pub provider_icon_url: Option<String>,
+    pub provider_name: Option<String>,
+    pub title_url: Option<String>,
+}
+
+impl VideoBlock {
+    pub fn builder(
+        alt_text: String,
+        title: TextBlockObject,
+        thumbnail_url: String,
+        video_url: String,
+    ) -> VideoBlockBuilder {
+        VideoBlockBuilder::new(alt_text, title, thumbnail_url, video_url)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct VideoBlockBuilder {
+    pub alt_text: String,
+    pub title: TextBlockObject,
+    pub thumbnail_url: String,
+    pub video_url: String,
+    pub author_name: Option<String>,
+    pub block_id: Option<String>,
+    pub description: Option<TextBlockObject>,
+    pub provider_icon_url: Option<String>,
+    pub provider_name: Option<String>,
+    pub title_url: Option<String>,
+}
+
+impl VideoBlockBuilder {
+    pub fn new(
+        alt_text: String,
+        title: TextBlockObject,
+        thumbnail_url: String,
+        video_url: String,
+    ) -> VideoBlockBuilder {
+        VideoBlockBuilder {
+            alt_text,
+            title,
+            thumbnail_url,
+            video_url,
+            ..Default::default()
+        }
+    }
+    pub fn author_name(mut self, author_name: String) -> VideoBlockBuilder {
+        self.author_name = Some(author_name);
+        self
+    }
+    pub fn block_id(mut self, block_id: String) -> VideoBlockBuilder {
+        self.block_id = Some(block_id);
+        self
+    }
+    pub fn description(mut self, description: TextBlockObject) -> VideoBlockBuilder {
+        self.description = Some(description);
+        self
+    }
+    pub fn provider_icon_url(mut self, provider_icon_url: String) -> VideoBlockBuilder {
+        self.provider_icon_url = Some(provider_icon_url);
+        self
+    }
+    pub fn provider_name(mut self, provider_name: String) -> VideoBlockBuilder {
+        self.provider_name = Some(provider_name);
+        self
+    }
+    pub fn title_url(mut self, title_url: String) -> VideoBlockBuilder {
+        self.title_url = Some(title_url);
+        self
+    }
+    pub fn build(self) -> VideoBlock {
+        VideoBlock {
+            alt_text: self.alt_text,
+            title: self.title,
+            thumbnail_url: self.thumbnail_url,
+            video_url: self.video_url,
+            author_name: self.author_name,
+            block_id: self.block_id,
+            description: self.description,
+            provider_icon_url: self.provider_icon_url,
+            provider_name: self.provider_name,
+            title_url: self.title_url,
+        }
+    }
+}
@@ -8,14 +8,18 @@ use crate::block::block_header::HeaderBlock;
 use crate::block::block_image::ImageBlock;
 use crate::block::block_input::InputBlock;
 use crate::block::block_object::{OptionBlockObject, TextBlockObject};
+use crate::block::block_rich_text::RichTextBlock;
 use crate::block::block_section::SectionBlock;
-use serde::{Deserialize, Serialize};
+use crate::block::block_video::VideoBlock;
+use crate::files::file::File;
+use serde::de;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_with::skip_serializing_none;
 
 /// Blocks are a series of components that can be combined to create visually rich and compellingly interactive messages.
 /// See: <https://api.slack.com/reference/block-kit/blocks>
 #[derive(Deserialize, Serialize, Debug, PartialEq)]
-#[serde(tag = "type")]
+#[serde(tag = "type", remote = "Self")]
 pub enum Block {
     #[serde(rename = "actions")]
     ActionBlock(ActionBlock),
@@ -31,10 +35,17 @@ pub enum Block {
     ImageBlock(ImageBlock),
     #[serde(rename = "input")]
     InputBlock(InputBlock),
+    #[serde(rename = "rich_text")]
+    RichTextBlock(RichTextBlock),
     #[serde(rename = "section")]
     SectionBlock(SectionBlock),
+    #[serde(rename = "video")]
+    VideoBlock(VideoBlock),
     #[serde(skip)]
     None,
+    /// A block this crate doesn't model, kept as is so that it survives a round trip.
+    #[serde(skip)]
+    Unknown(serde_json::Value),
 }
 
 impl Block {
@@ -47,12 +58,82 @@ impl Block {
             Block::HeaderBlock(HeaderBlock { .. }) => BlockType::Header,
             Block::ImageBlock(ImageBlock { .. }) => BlockType::Image,
             Block::InputBlock(InputBlock { .. }) => BlockType::Input,
+            Block::RichTextBlock(RichTextBlock { .. }) => BlockType::RichText,
             Block::SectionBlock(SectionBlock { .. }) => BlockType::Section,
+            Block::VideoBlock(VideoBlock { .. }) => BlockType::Video,
             Block::None => BlockType::None,
+            Block::Unknown(raw) => BlockType::Unknown(type_name(raw)),
+        }
+    }
+}
+
+/// The `type` tags of the [`Block`] variants that carry a payload.
+const BLOCK_TYPES: &[&str] = &[
+    "actions",
+    "context",
+    "divider",
+    "file",
+    "header",
+    "image",
+    "input",
+    "rich_text",
+    "section",
+    "video",
+];
+
+impl<'de> Deserialize<'de> for Block {
+    fn deserialize<D>(deserializer: D) -> Result<Block, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_known(
+            deserializer,
+            BLOCK_TYPES,
+            |raw| Block::deserialize(raw),
+            Block::Unknown,
+        )
+    }
+}
+
+impl Serialize for Block {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Block::Unknown(raw) => raw.serialize(serializer),
+            value => Block::serialize(value, serializer),
         }
     }
 }
 
+/// Deserializes a block or element whose `type` is one of `known` with `parse`, and any other
+/// with `unknown`. A known one that doesn't parse is an error rather than an unknown value.
+pub(crate) fn deserialize_known<'de, D, T>(
+    deserializer: D,
+    known: &[&str],
+    parse: impl FnOnce(&serde_json::Value) -> Result<T, serde_json::Error>,
+    unknown: impl FnOnce(serde_json::Value) -> T,
+) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let raw = serde_json::Value::deserialize(deserializer)?;
+    if known.contains(&type_name(&raw).as_str()) {
+        parse(&raw).map_err(de::Error::custom)
+    } else {
+        Ok(unknown(raw))
+    }
+}
+
+/// The `type` of a block or element this crate doesn't model.
+pub(crate) fn type_name(raw: &serde_json::Value) -> String {
+    raw.get("type")
+        .and_then(|t| t.as_str())
+        .unwrap_or_default()
+        .to_string()
+}
+
 impl Default for Block {
     fn default() -> Self {
         Block::None
@@ -69,9 +150,14 @@ pub enum BlockType {
     Header,
     Image,
     Input,
+    RichText,
     Section,
+    Video,
     #[serde(skip)]
     None,
+    /// A value this crate doesn't know yet.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl Default for BlockType {
@@ -100,10 +186,39 @@ pub struct BlockAction {
     pub selected_conversations: Option<Vec<String>>,
     pub selected_date: Option<String>,
     pub selected_time: Option<String>,
+    pub selected_date_time: Option<i64>,
+    pub files: Option<Vec<File>>,
+    pub rich_text_value: Option<RichTextBlock>,
     pub initial_option: Option<OptionBlockObject>,
     pub initial_user: Option<String>,
     pub initial_channel: Option<String>,
     pub initial_conversation: Option<String>,
     pub initial_date: Option<String>,
     pub initial_time: Option<String>,
+    pub initial_date_time: Option<i64>,
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+
+    /// The tags serde lists when it rejects an unknown variant of a `remote = "Self"` enum.
+    pub(crate) fn modelled_tags(error: serde_json::Error) -> Vec<String> {
+        let error = error.to_string();
+        let (_, tags) = error.split_once("expected one of ").unwrap();
+        tags.split(", ")
+            .map(|tag| tag.trim_matches('`').to_string())
+            .collect()
+    }
+
+    #[test]
+    fn list_every_block_type() {
+        let error = Block::deserialize(serde_json::json!({"type": "none"})).unwrap_err();
+        assert_eq!(modelled_tags(error), BLOCK_TYPES);
+    }
+
+    #[test]
+    fn reject_malformed_known_block() {
+        assert!(serde_json::from_value::<Block>(serde_json::json!({"type": "header"})).is_err());
+    }
 }
@@ -0,0 +1,336 @@
+//! Declarative macros for building blocks and views.
+//!
+//! ```
+//! use slack_rust::block::block_divider::DividerBlock;
+//! use slack_rust::block::blocks::Block;
+//! use slack_rust::{blocks, view};
+//!
+//! let blocks = blocks![
+//!     header("Deploy finished"),
+//!     section("*Status:* :white_check_mark:", accessory: button("Logs", "open_logs")),
+//!     fields("*Env*\nprod", "*Version*\n1.2.0"),
+//!     divider,
+//!     image("https://example.com/graph.png", "Latency graph"),
+//!     context("Triggered by <@U061F7AUR>"),
+//!     actions(button("Approve", "approve"), button("Deny", "deny")),
+//!     Block::DividerBlock(DividerBlock::default()),
+//! ];
+//! assert_eq!(blocks.len(), 8);
+//!
+//! let view = view!(modal {
+//!     title: "Deploy",
+//!     submit: "Send",
+//!     callback_id: "deploy",
+//!     blocks: [input("Reason", plain_text_input("reason"))],
+//! });
+//! assert_eq!(view.blocks.unwrap().len(), 1);
+//! ```
+//!
+//! Anything that is not one of the shorthands is used as-is, so `blocks!` accepts any `Block`
+//! expression and `actions`, `accessory` and `input` accept any `BlockElement` expression.
+
+use crate::block::block_object::{TextBlockObject, TextBlockType};
+
+/// Creates a `plain_text` text object.
+pub fn plain_text(text: impl ToString) -> TextBlockObject {
+    TextBlockObject::builder(TextBlockType::PlainText, text.to_string()).build()
+}
+
+/// Creates a `mrkdwn` text object.
+pub fn mrkdwn(text: impl ToString) -> TextBlockObject {
+    TextBlockObject::builder(TextBlockType::Mrkdwn, text.to_string()).build()
+}
+
+/// Builds a `Vec<Block>`.
+///
+/// Supported shorthands are `header(text)`, `section(text)`, `section(text, accessory: element)`,
+/// `fields(text, ...)`, `divider`, `image(url, alt_text)`, `context(text, ...)`,
+/// `actions(element, ...)` and `input(label, element)`. Texts are `mrkdwn` except for headers and
+/// input labels, which are `plain_text`.
+#[macro_export]
+macro_rules! blocks {
+    ($($tokens:tt)*) => {
+        $crate::__blocks!([] $($tokens)*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __blocks {
+    ([$($blocks:expr,)*]) => {
+        ::std::vec::Vec::<$crate::block::blocks::Block>::from([$($blocks),*])
+    };
+    ([$($blocks:expr,)*] header($text:expr) $(, $($rest:tt)*)?) => {
+        $crate::__blocks!([$($blocks,)* $crate::block::blocks::Block::HeaderBlock(
+            $crate::block::block_header::HeaderBlock::builder(
+                $crate::block::macros::plain_text($text),
+            )
+            .build(),
+        ),] $($($rest)*)?)
+    };
+    ([$($blocks:expr,)*] section($text:expr, accessory: $($element:tt)+) $(, $($rest:tt)*)?) => {
+        $crate::__blocks!([$($blocks,)* $crate::block::blocks::Block::SectionBlock(
+            $crate::block::block_section::SectionBlock::builder()
+                .text($crate::block::macros::mrkdwn($text))
+                .accessory($crate::__block_element!($($element)+))
+                .build(),
+        ),] $($($rest)*)?)
+    };
+    ([$($blocks:expr,)*] section($text:expr) $(, $($rest:tt)*)?) => {
+        $crate::__blocks!([$($blocks,)* $crate::block::blocks::Block::SectionBlock(
+            $crate::block::block_section::SectionBlock::builder()
+                .text($crate::block::macros::mrkdwn($text))
+                .build(),
+        ),] $($($rest)*)?)
+    };
+    ([$($blocks:expr,)*] fields($($field:expr),+ $(,)?) $(, $($rest:tt)*)?) => {
+        $crate::__blocks!([$($blocks,)* $crate::block::blocks::Block::SectionBlock(
+            $crate::block::block_section::SectionBlock::builder()
+                .fields(::std::vec![$($crate::block::macros::mrkdwn($field)),+])
+                .build(),
+        ),] $($($rest)*)?)
+    };
+    ([$($blocks:expr,)*] divider $(, $($rest:tt)*)?) => {
+        $crate::__blocks!([$($blocks,)* $crate::block::blocks::Block::DividerBlock(
+            $crate::block::block_divider::DividerBlock::default(),
+        ),] $($($rest)*)?)
+    };
+    ([$($blocks:expr,)*] image($url:expr, $alt_text:expr) $(, $($rest:tt)*)?) => {
+        $crate::__blocks!([$($blocks,)* $crate::block::blocks::Block::ImageBlock(
+            $crate::block::block_image::ImageBlock::builder(
+                ::std::string::ToString::to_string(&$url),
+                ::std::string::ToString::to_string(&$alt_text),
+            )
+            .build(),
+        ),] $($($rest)*)?)
+    };
+    ([$($blocks:expr,)*] context($($text:expr),+ $(,)?) $(, $($rest:tt)*)?) => {
+        $crate::__blocks!([$($blocks,)* $crate::block::blocks::Block::ContextBlock(
+            $crate::block::block_context::ContextBlock::builder(::std::vec![$(
+                $crate::block::block_elements::MixedElement::MarkdownBlockObject {
+                    text: ::std::string::ToString::to_string(&$text),
+                    emoji: None,
+                    verbatim: None,
+                }
+            ),+])
+            .build(),
+        ),] $($($rest)*)?)
+    };
+    ([$($blocks:expr,)*] actions($($elements:tt)+) $(, $($rest:tt)*)?) => {
+        $crate::__blocks!([$($blocks,)* $crate::block::blocks::Block::ActionBlock(
+            $crate::block::block_actions::ActionBlock::builder(
+                $crate::__block_elements!([] $($elements)+),
+            )
+            .build(),
+        ),] $($($rest)*)?)
+    };
+    ([$($blocks:expr,)*] input($label:expr, $($element:tt)+) $(, $($rest:tt)*)?) => {
+        $crate::__blocks!([$($blocks,)* $crate::block::blocks::Block::InputBlock(
+            $crate::block::block_input::InputBlock::builder(
+                $crate::block::macros::plain_text($label),
+                $crate::__block_element!($($element)+),
+            )
+            .build(),
+        ),] $($($rest)*)?)
+    };
+    ([$($blocks:expr,)*] $block:expr $(, $($rest:tt)*)?) => {
+        $crate::__blocks!([$($blocks,)* $block,] $($($rest)*)?)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __block_elements {
+    ([$($elements:expr,)*]) => {
+        ::std::vec![$($elements),*]
+    };
+    ([$($elements:expr,)*] $name:ident($($args:tt)*) $(, $($rest:tt)*)?) => {
+        $crate::__block_elements!(
+            [$($elements,)* $crate::__block_element!($name($($args)*)),] $($($rest)*)?
+        )
+    };
+    ([$($elements:expr,)*] $element:expr $(, $($rest:tt)*)?) => {
+        $crate::__block_elements!([$($elements,)* $element,] $($($rest)*)?)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __block_element {
+    (button($text:expr, $action_id:expr $(,)?)) => {
+        $crate::block::block_elements::BlockElement::ButtonElement(
+            $crate::block::block_elements::ButtonElement::builder(
+                $crate::block::macros::plain_text($text),
+                ::std::string::ToString::to_string(&$action_id),
+            )
+            .build(),
+        )
+    };
+    (plain_text_input($action_id:expr $(,)?)) => {
+        $crate::block::block_elements::BlockElement::PlainTextInputBlockElement(
+            $crate::block::block_elements::PlainTextInputBlockElement::builder(
+                ::std::string::ToString::to_string(&$action_id),
+            )
+            .build(),
+        )
+    };
+    ($element:expr) => {
+        $element
+    };
+}
+
+/// Builds a `View`.
+///
+/// Takes `modal` or `home` followed by fields: `title`, `submit` and `close` (`plain_text`),
+/// `callback_id`, `private_metadata` and `external_id` (strings), `clear_on_close` and
+/// `notify_on_close` (bools), and `blocks: [...]` using the `blocks!` syntax.
+#[macro_export]
+macro_rules! view {
+    (modal { $($fields:tt)* }) => {
+        $crate::view!(@build $crate::views::view::ViewType::Modal; $($fields)*)
+    };
+    (home { $($fields:tt)* }) => {
+        $crate::view!(@build $crate::views::view::ViewType::Home; $($fields)*)
+    };
+    (@build $type:expr; $($fields:tt)*) => {{
+        #[allow(unused_mut)]
+        let mut view = $crate::views::view::View {
+            type_filed: Some($type),
+            ..::std::default::Default::default()
+        };
+        $crate::__view_fields!(view; $($fields)*);
+        view
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __view_fields {
+    ($view:ident;) => {};
+    ($view:ident; blocks: [$($blocks:tt)*] $(, $($rest:tt)*)?) => {
+        $view.blocks = Some($crate::blocks![$($blocks)*]);
+        $crate::__view_fields!($view; $($($rest)*)?);
+    };
+    ($view:ident; title: $value:expr $(, $($rest:tt)*)?) => {
+        $view.title = Some($crate::block::macros::plain_text($value));
+        $crate::__view_fields!($view; $($($rest)*)?);
+    };
+    ($view:ident; submit: $value:expr $(, $($rest:tt)*)?) => {
+        $view.submit = Some($crate::block::macros::plain_text($value));
+        $crate::__view_fields!($view; $($($rest)*)?);
+    };
+    ($view:ident; close: $value:expr $(, $($rest:tt)*)?) => {
+        $view.close = Some($crate::block::macros::plain_text($value));
+        $crate::__view_fields!($view; $($($rest)*)?);
+    };
+    ($view:ident; callback_id: $value:expr $(, $($rest:tt)*)?) => {
+        $view.callback_id = Some(::std::string::ToString::to_string(&$value));
+        $crate::__view_fields!($view; $($($rest)*)?);
+    };
+    ($view:ident; private_metadata: $value:expr $(, $($rest:tt)*)?) => {
+        $view.private_metadata = Some(::std::string::ToString::to_string(&$value));
+        $crate::__view_fields!($view; $($($rest)*)?);
+    };
+    ($view:ident; external_id: $value:expr $(, $($rest:tt)*)?) => {
+        $view.external_id = Some(::std::string::ToString::to_string(&$value));
+        $crate::__view_fields!($view; $($($rest)*)?);
+    };
+    ($view:ident; clear_on_close: $value:expr $(, $($rest:tt)*)?) => {
+        $view.clear_on_close = Some($value);
+        $crate::__view_fields!($view; $($($rest)*)?);
+    };
+    ($view:ident; notify_on_close: $value:expr $(, $($rest:tt)*)?) => {
+        $view.notify_on_close = Some($value);
+        $crate::__view_fields!($view; $($($rest)*)?);
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::block::block_actions::ActionBlock;
+    use crate::block::block_elements::{BlockElement, ButtonElement, PlainTextInputBlockElement};
+    use crate::block::block_header::HeaderBlock;
+    use crate::block::block_input::InputBlock;
+    use crate::block::block_section::SectionBlock;
+    use crate::block::blocks::Block;
+    use crate::block::macros::{mrkdwn, plain_text};
+    use crate::views::view::{View, ViewType};
+
+    #[test]
+    fn build_blocks() {
+        let approve = ButtonElement::builder(plain_text("Approve"), "approve".to_string())
+            .style("primary".to_string())
+            .build();
+        let name = "world";
+
+        assert_eq!(
+            crate::blocks![
+                header(format!("Hello {}", name)),
+                section("*Hi*", accessory: button("Open", "open")),
+                actions(BlockElement::ButtonElement(approve), button("Deny", "deny")),
+            ],
+            vec![
+                Block::HeaderBlock(HeaderBlock::builder(plain_text("Hello world")).build()),
+                Block::SectionBlock(
+                    SectionBlock::builder()
+                        .text(mrkdwn("*Hi*"))
+                        .accessory(BlockElement::ButtonElement(
+                            ButtonElement::builder(plain_text("Open"), "open".to_string()).build()
+                        ))
+                        .build()
+                ),
+                Block::ActionBlock(
+                    ActionBlock::builder(vec![
+                        BlockElement::ButtonElement(
+                            ButtonElement::builder(plain_text("Approve"), "approve".to_string())
+                                .style("primary".to_string())
+                                .build()
+                        ),
+                        BlockElement::ButtonElement(
+                            ButtonElement::builder(plain_text("Deny"), "deny".to_string()).build()
+                        ),
+                    ])
+                    .build()
+                ),
+            ]
+        );
+        assert!(crate::blocks![].is_empty());
+    }
+
+    #[test]
+    fn build_view() {
+        assert_eq!(
+            crate::view!(modal {
+                title: "Title",
+                close: "Cancel",
+                callback_id: "callback",
+                clear_on_close: true,
+                blocks: [input("Reason", plain_text_input("reason"))],
+            }),
+            View {
+                type_filed: Some(ViewType::Modal),
+                title: Some(plain_text("Title")),
+                close: Some(plain_text("Cancel")),
+                callback_id: Some("callback".to_string()),
+                clear_on_close: Some(true),
+                blocks: Some(vec![Block::InputBlock(
+                    InputBlock::builder(
+                        plain_text("Reason"),
+                        BlockElement::PlainTextInputBlockElement(
+                            PlainTextInputBlockElement::builder("reason".to_string()).build()
+                        )
+                    )
+                    .build()
+                )]),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            crate::view!(home {}),
+            View {
+                type_filed: Some(ViewType::Home),
+                ..Default::default()
+            }
+        );
+    }
+}
@@ -0,0 +1,704 @@
+//! Converts CommonMark into Block Kit blocks.
+//!
+//! ```
+//! use slack_rust::block::blocks::Block;
+//! use slack_rust::block::markdown::markdown_to_blocks;
+//!
+//! let blocks = markdown_to_blocks("# Release 1.2\n\nFixes **two** bugs.\n\n---\n\n- one\n- two");
+//! assert_eq!(blocks.len(), 4);
+//! assert!(matches!(blocks[0], Block::HeaderBlock(_)));
+//! assert!(matches!(blocks[2], Block::DividerBlock(_)));
+//! ```
+
+use crate::block::block_divider::DividerBlock;
+use crate::block::block_header::HeaderBlock;
+use crate::block::block_image::ImageBlock;
+use crate::block::block_object::{TextBlockObject, TextBlockType};
+use crate::block::block_section::SectionBlock;
+use crate::block::blocks::Block;
+use crate::block::validation::{
+    MAX_HEADER_TEXT_LENGTH, MAX_IMAGE_ALT_TEXT_LENGTH, MAX_MESSAGE_BLOCKS, MAX_SECTION_TEXT_LENGTH,
+};
+use crate::mrkdwn::format::{code, code_block, escape, link, quote, Mrkdwn};
+use pulldown_cmark::{Event, LinkType, Options, Parser, Tag};
+
+/// Converts CommonMark into blocks.
+///
+/// Headings become header blocks, thematic breaks divider blocks and paragraphs made up only of
+/// images image blocks. Everything else is rendered as `mrkdwn` and packed into section blocks,
+/// split so that no section exceeds the text limit.
+pub fn markdown_to_blocks(markdown: &str) -> Vec<Block> {
+    let mut converter = Converter::default();
+    let options = Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    for event in Parser::new_ext(markdown, options) {
+        converter.event(event);
+    }
+    converter.finish()
+}
+
+/// Converts CommonMark into blocks, split into messages of at most 50 blocks each.
+pub fn markdown_to_messages(markdown: &str) -> Vec<Vec<Block>> {
+    let mut messages = Vec::new();
+    let mut message = Vec::new();
+    for block in markdown_to_blocks(markdown) {
+        if message.len() == MAX_MESSAGE_BLOCKS {
+            messages.push(std::mem::take(&mut message));
+        }
+        message.push(block);
+    }
+    if !message.is_empty() {
+        messages.push(message);
+    }
+    messages
+}
+
+/// A place where the current paragraph may be split, with the spans open there.
+struct Break {
+    at: usize,
+    /// The length of the space or newline dropped when splitting here.
+    len: usize,
+    spans: Vec<&'static str>,
+}
+
+#[derive(Default)]
+struct Converter {
+    blocks: Vec<Block>,
+    /// Rendered paragraphs, lists and code blocks waiting to be packed into sections.
+    segments: Vec<String>,
+    /// The paragraph or list currently being rendered.
+    text: String,
+    /// Where `text` may be split.
+    breaks: Vec<Break>,
+    /// The formatting characters of the spans open at the end of `text`.
+    spans: Vec<&'static str>,
+    /// Whether `text` contains anything other than images and whitespace.
+    has_content: bool,
+    /// Images of the current paragraph, in case it consists of nothing else.
+    images: Vec<ImageBlock>,
+    quote_depth: usize,
+    /// The next number of each open list, `None` for bulleted lists.
+    lists: Vec<Option<u64>>,
+    item_start: bool,
+    heading: Option<String>,
+    code: Option<String>,
+    link: Option<(String, usize)>,
+    image: Option<(String, String, String)>,
+}
+
+impl Converter {
+    fn event(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => {
+                if let Some(code) = self.code.as_mut() {
+                    code.push_str(&text);
+                } else if let Some((_, alt, _)) = self.image.as_mut() {
+                    alt.push_str(&text);
+                } else if let Some(heading) = self.heading.as_mut() {
+                    heading.push_str(&text);
+                } else {
+                    self.push_text(&escape(&text));
+                }
+            }
+            Event::Code(text) => {
+                if let Some((_, alt, _)) = self.image.as_mut() {
+                    alt.push_str(&text);
+                } else if let Some(heading) = self.heading.as_mut() {
+                    heading.push_str(&text);
+                } else {
+                    self.push(code(text.as_ref()).as_str());
+                }
+            }
+            Event::Html(html) => {
+                if self.heading.is_none() {
+                    self.push(&escape(html.trim_end_matches('\n')));
+                }
+            }
+            Event::FootnoteReference(name) => self.push(&escape(&format!("[^{}]", name))),
+            Event::SoftBreak => {
+                self.add_break();
+                self.push_markup(" ");
+            }
+            Event::HardBreak => {
+                self.add_break();
+                self.push_markup("\n");
+            }
+            Event::Rule => {
+                self.flush_sections();
+                self.blocks
+                    .push(Block::DividerBlock(DividerBlock::default()));
+            }
+            Event::TaskListMarker(checked) => self.push(if checked { "☑ " } else { "☐ " }),
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph => {
+                if self.lists.is_empty() {
+                    self.has_content = false;
+                    self.images.clear();
+                } else if !self.item_start {
+                    self.add_break();
+                    self.text.push('\n');
+                    self.text.push_str(&self.indent());
+                }
+            }
+            Tag::Heading(..) => self.heading = Some(String::new()),
+            Tag::BlockQuote => self.quote_depth += 1,
+            Tag::CodeBlock(_) => {
+                self.finish_text();
+                self.code = Some(String::new());
+            }
+            Tag::List(start) => self.lists.push(start),
+            Tag::Item => {
+                if !self.text.is_empty() {
+                    self.add_break();
+                    self.text.push('\n');
+                }
+                let indent = self.indent();
+                let marker = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}. ", *number - 1)
+                    }
+                    _ => "• ".to_string(),
+                };
+                self.text.push_str(&indent);
+                self.text.push_str(&marker);
+                self.item_start = true;
+            }
+            Tag::Emphasis => self.open_span("_"),
+            Tag::Strong => self.open_span("*"),
+            Tag::Strikethrough => self.open_span("~"),
+            Tag::Link(link_type, url, _) => {
+                let url = match link_type {
+                    LinkType::Email => format!("mailto:{}", url),
+                    _ => url.to_string(),
+                };
+                self.link = Some((url, self.text.len()));
+            }
+            Tag::Image(_, url, title) => {
+                self.image = Some((url.to_string(), String::new(), title.to_string()))
+            }
+            Tag::FootnoteDefinition(_)
+            | Tag::Table(_)
+            | Tag::TableHead
+            | Tag::TableRow
+            | Tag::TableCell => {}
+        }
+    }
+
+    fn end(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph => {
+                if !self.lists.is_empty() {
+                    return;
+                }
+                if !self.has_content && !self.images.is_empty() && self.quote_depth == 0 {
+                    let images = std::mem::take(&mut self.images);
+                    self.text.clear();
+                    self.breaks.clear();
+                    self.flush_sections();
+                    self.blocks
+                        .extend(images.into_iter().map(Block::ImageBlock));
+                } else {
+                    self.finish_text();
+                }
+            }
+            Tag::Heading(..) => {
+                let heading = self.heading.take().unwrap_or_default();
+                let heading = heading.trim();
+                if !heading.is_empty() {
+                    self.flush_sections();
+                    let text = TextBlockObject::builder(
+                        TextBlockType::PlainText,
+                        truncate(heading, MAX_HEADER_TEXT_LENGTH),
+                    )
+                    .build();
+                    self.blocks
+                        .push(Block::HeaderBlock(HeaderBlock::builder(text).build()));
+                }
+            }
+            Tag::BlockQuote => {
+                self.finish_text();
+                self.quote_depth -= 1;
+            }
+            Tag::CodeBlock(_) => {
+                let code = self.code.take().unwrap_or_default();
+                let code = escape(code.trim_end_matches('\n'));
+                let breaks: Vec<Break> = code
+                    .match_indices('\n')
+                    .map(|(at, _)| Break {
+                        at,
+                        len: 1,
+                        spans: vec![],
+                    })
+                    .collect();
+                // Every piece gets its own fences, so no section ends inside a code block.
+                let quoted = self.quote_depth > 0;
+                let fits = |piece: &str| {
+                    section_length(piece, quoted) + if quoted { 12 } else { 8 }
+                        <= MAX_SECTION_TEXT_LENGTH
+                };
+                if !code.is_empty() {
+                    for piece in split(&code, 0, &breaks, fits) {
+                        self.push_segment(code_block(Mrkdwn::raw(piece)).into_string());
+                    }
+                }
+            }
+            Tag::List(_) => {
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.finish_text();
+                }
+            }
+            Tag::Emphasis => self.close_span("_"),
+            Tag::Strong => self.close_span("*"),
+            Tag::Strikethrough => self.close_span("~"),
+            Tag::Link(..) => {
+                if let Some((url, start)) = self.link.take() {
+                    if self.heading.is_none() {
+                        let label = self.text.split_off(start);
+                        // The link is pushed whole, so it is never split.
+                        self.breaks.retain(|b| b.at < start);
+                        let label = if label == escape(&url) {
+                            String::new()
+                        } else {
+                            label
+                        };
+                        self.push(link(&url, Mrkdwn::raw(label)).as_str());
+                    }
+                }
+            }
+            Tag::Image(..) => {
+                if let Some((url, alt, title)) = self.image.take() {
+                    if let Some(heading) = self.heading.as_mut() {
+                        heading.push_str(&alt);
+                        return;
+                    }
+                    self.push_markup(link(&url, alt.as_str()).as_str());
+
+                    let alt_text = if alt.trim().is_empty() {
+                        "image".to_string()
+                    } else {
+                        truncate(alt.trim(), MAX_IMAGE_ALT_TEXT_LENGTH)
+                    };
+                    let mut image = ImageBlock::builder(url, alt_text);
+                    if !title.is_empty() {
+                        image = image.title(
+                            TextBlockObject::builder(TextBlockType::PlainText, title).build(),
+                        );
+                    }
+                    self.images.push(image.build());
+                }
+            }
+            Tag::Item
+            | Tag::FootnoteDefinition(_)
+            | Tag::Table(_)
+            | Tag::TableHead
+            | Tag::TableRow
+            | Tag::TableCell => {}
+        }
+    }
+
+    fn indent(&self) -> String {
+        "    ".repeat(self.lists.len().saturating_sub(1))
+    }
+
+    /// Appends rendered content to the current paragraph.
+    fn push(&mut self, mrkdwn: &str) {
+        if let Some(heading) = self.heading.as_mut() {
+            heading.push_str(mrkdwn);
+            return;
+        }
+        if !mrkdwn.trim().is_empty() {
+            self.has_content = true;
+            self.item_start = false;
+        }
+        self.text.push_str(mrkdwn);
+    }
+
+    /// Appends text, which may be split at its spaces.
+    fn push_text(&mut self, text: &str) {
+        if self.heading.is_none() {
+            for (i, _) in text.match_indices(' ') {
+                self.breaks.push(Break {
+                    at: self.text.len() + i,
+                    len: 1,
+                    spans: self.spans.clone(),
+                });
+            }
+        }
+        self.push(text);
+    }
+
+    /// Marks the end of the current paragraph as a place to split it at, dropping the space or
+    /// newline appended next.
+    fn add_break(&mut self) {
+        if self.heading.is_none() {
+            self.breaks.push(Break {
+                at: self.text.len(),
+                len: 1,
+                spans: self.spans.clone(),
+            });
+        }
+    }
+
+    fn open_span(&mut self, markup: &'static str) {
+        self.push_markup(markup);
+        if self.heading.is_none() {
+            self.spans.push(markup);
+        }
+    }
+
+    fn close_span(&mut self, markup: &'static str) {
+        if self.heading.is_none() {
+            self.spans.pop();
+        }
+        self.push_markup(markup);
+    }
+
+    /// Appends formatting characters that do not count as content on their own.
+    fn push_markup(&mut self, markup: &str) {
+        match self.heading.as_mut() {
+            Some(heading) if markup.trim().is_empty() => heading.push_str(markup),
+            Some(_) => {}
+            None => self.text.push_str(markup),
+        }
+    }
+
+    /// Ends the current paragraph or list, split into segments that each fit in a section.
+    fn finish_text(&mut self) {
+        let text = std::mem::take(&mut self.text);
+        let breaks = std::mem::take(&mut self.breaks);
+        self.spans.clear();
+        let start = text.len() - text.trim_start().len();
+        let text = text.trim_end();
+        if start < text.len() {
+            let quoted = self.quote_depth > 0;
+            let fits = |piece: &str| section_length(piece, quoted) <= MAX_SECTION_TEXT_LENGTH;
+            for piece in split(text, start, &breaks, fits) {
+                self.push_segment(piece);
+            }
+        }
+        self.has_content = false;
+        self.images.clear();
+    }
+
+    fn push_segment(&mut self, segment: String) {
+        if self.quote_depth > 0 {
+            self.segments
+                .push(quote(Mrkdwn::raw(segment)).into_string());
+        } else {
+            self.segments.push(segment);
+        }
+    }
+
+    /// Packs the pending segments into as few section blocks as the text limit allows.
+    fn flush_sections(&mut self) {
+        self.finish_text();
+
+        let mut section = String::new();
+        for segment in std::mem::take(&mut self.segments) {
+            if !section.is_empty()
+                && section.chars().count() + 2 + segment.chars().count() > MAX_SECTION_TEXT_LENGTH
+            {
+                self.push_section(std::mem::take(&mut section));
+            }
+            if !section.is_empty() {
+                section.push_str("\n\n");
+            }
+            section.push_str(&segment);
+        }
+        if !section.is_empty() {
+            self.push_section(section);
+        }
+    }
+
+    fn push_section(&mut self, text: String) {
+        let text = TextBlockObject::builder(TextBlockType::Mrkdwn, text).build();
+        self.blocks.push(Block::SectionBlock(
+            SectionBlock::builder().text(text).build(),
+        ));
+    }
+
+    fn finish(mut self) -> Vec<Block> {
+        self.flush_sections();
+        self.blocks
+    }
+}
+
+/// Truncates `text` to at most `max` characters, marking the cut with an ellipsis.
+fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let mut truncated: String = text.chars().take(max - 1).collect();
+    truncated.push('…');
+    truncated
+}
+
+/// The length of `text` in a section, counting the `> ` a quote adds to every line.
+fn section_length(text: &str, quoted: bool) -> usize {
+    let length = text.chars().count();
+    if quoted {
+        length + 2 * (text.matches('\n').count() + 1)
+    } else {
+        length
+    }
+}
+
+/// Splits `text[start..]` into pieces that each `fits`, at the last break that allows it.
+///
+/// Spans open at a break are closed at the end of one piece and reopened at the start of the next.
+/// Text without a suitable break is cut anywhere but inside a link, mention or entity.
+fn split(
+    text: &str,
+    mut start: usize,
+    breaks: &[Break],
+    fits: impl Fn(&str) -> bool,
+) -> Vec<String> {
+    let mut pieces = Vec::new();
+    let mut open: &[&str] = &[];
+    loop {
+        let reopen = open.concat();
+        let rest = format!("{}{}", reopen, &text[start..]);
+        if fits(&rest) {
+            pieces.push(rest);
+            return pieces;
+        }
+
+        let mut best = None;
+        for b in breaks.iter().filter(|b| b.at > start && b.at < text.len()) {
+            let piece = format!(
+                "{}{}{}",
+                reopen,
+                text[start..b.at].trim_end(),
+                closing(&b.spans)
+            );
+            if !fits(&piece) {
+                break;
+            }
+            best = Some((b.at + b.len, b.spans.as_slice(), piece));
+        }
+        let (next, spans, piece) = best.unwrap_or_else(|| {
+            let close = closing(open);
+            let end = cut(text, start, |end| {
+                fits(&format!("{}{}{}", reopen, &text[start..end], close))
+            });
+            (
+                end,
+                open,
+                format!("{}{}{}", reopen, &text[start..end], close),
+            )
+        });
+        pieces.push(piece);
+
+        start = next + text[next..].len() - text[next..].trim_start_matches(' ').len();
+        open = spans;
+        if start >= text.len() {
+            return pieces;
+        }
+    }
+}
+
+fn closing(spans: &[&str]) -> String {
+    spans.iter().rev().copied().collect()
+}
+
+/// Returns the longest prefix end of `text[start..]` that `fits`, short of a link, mention or
+/// entity it would cut in two. At least one character is taken.
+fn cut(text: &str, start: usize, fits: impl Fn(usize) -> bool) -> usize {
+    let mut end = start;
+    for (i, c) in text[start..].char_indices() {
+        let next = start + i + c.len_utf8();
+        if !fits(next) {
+            break;
+        }
+        end = next;
+    }
+    for (open, close) in [('<', '>'), ('&', ';')] {
+        let head = &text[start..end];
+        if let Some(i) = head.rfind(open) {
+            if i > 0 && !head[i..].contains(close) {
+                end = start + i;
+            }
+        }
+    }
+    if end == start {
+        end += text[start..].chars().next().map_or(0, char::len_utf8);
+    }
+    end
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn section(text: &str) -> Block {
+        Block::SectionBlock(
+            SectionBlock::builder()
+                .text(TextBlockObject::builder(TextBlockType::Mrkdwn, text.to_string()).build())
+                .build(),
+        )
+    }
+
+    #[test]
+    fn convert_markdown() {
+        let markdown = r#"# Release *1.2*
+
+Fixes **two** bugs in _parsing_ & ~~rendering~~, see [the docs](https://example.com/a|b) or <https://example.com>.
+Wrapped `a<b` line.
+
+![Screenshot](https://example.com/shot.png "Dashboard")
+
+> Quoted
+> text
+
+1. first
+2. second
+   - nested
+   - [x] done
+
+```rust
+let a = b && c;
+```
+***
+"#;
+
+        assert_eq!(
+            markdown_to_blocks(markdown),
+            vec![
+                Block::HeaderBlock(
+                    HeaderBlock::builder(
+                        TextBlockObject::builder(
+                            TextBlockType::PlainText,
+                            "Release 1.2".to_string()
+                        )
+                        .build()
+                    )
+                    .build()
+                ),
+                section("Fixes *two* bugs in _parsing_ &amp; ~rendering~, see <https://example.com/a%7Cb|the docs> or <https://example.com>. Wrapped `a&lt;b` line."),
+                Block::ImageBlock(
+                    ImageBlock::builder(
+                        "https://example.com/shot.png".to_string(),
+                        "Screenshot".to_string()
+                    )
+                    .title(
+                        TextBlockObject::builder(TextBlockType::PlainText, "Dashboard".to_string())
+                            .build()
+                    )
+                    .build()
+                ),
+                section("> Quoted text\n\n1. first\n2. second\n    • nested\n    • ☑ done\n\n```\nlet a = b &amp;&amp; c;\n```"),
+                Block::DividerBlock(DividerBlock::default()),
+            ]
+        );
+    }
+
+    #[test]
+    fn split_long_markdown() {
+        let paragraph = "word ".repeat(500);
+        let markdown = [paragraph.trim(); 4].join("\n\n");
+        let blocks = markdown_to_blocks(&markdown);
+        assert_eq!(blocks.len(), 4);
+        for block in &blocks {
+            match block {
+                Block::SectionBlock(section) => {
+                    let text = &section.text.as_ref().unwrap().text;
+                    assert!(text.chars().count() <= MAX_SECTION_TEXT_LENGTH);
+                }
+                _ => panic!("expected a section block"),
+            }
+        }
+
+        let long_word = "x".repeat(MAX_SECTION_TEXT_LENGTH + 10);
+        let blocks = markdown_to_blocks(&long_word);
+        assert_eq!(
+            blocks,
+            vec![
+                section(&"x".repeat(MAX_SECTION_TEXT_LENGTH)),
+                section(&"x".repeat(10)),
+            ]
+        );
+
+        let markdown = "---\n\n".repeat(120);
+        let messages = markdown_to_messages(&markdown);
+        assert_eq!(
+            messages.iter().map(|m| m.len()).collect::<Vec<usize>>(),
+            vec![50, 50, 20]
+        );
+    }
+
+    fn section_texts(markdown: &str) -> Vec<String> {
+        markdown_to_blocks(markdown)
+            .into_iter()
+            .map(|block| match block {
+                Block::SectionBlock(section) => {
+                    let text = section.text.unwrap().text;
+                    assert!(text.chars().count() <= MAX_SECTION_TEXT_LENGTH);
+                    text
+                }
+                _ => panic!("expected a section block"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn keep_links_whole() {
+        let url = format!("https://example.com/{}", "a".repeat(100));
+        let markdown = format!(
+            "{} [a link with a long label]({}) {}",
+            "word ".repeat(590),
+            url,
+            "word ".repeat(100)
+        );
+        let texts = section_texts(&markdown);
+        assert_eq!(texts.len(), 2);
+        let link = format!("<{}|a link with a long label>", url);
+        assert!(texts.iter().any(|text| text.contains(&link)));
+        for text in &texts {
+            assert_eq!(text.matches('<').count(), text.matches('>').count());
+        }
+    }
+
+    #[test]
+    fn reopen_spans() {
+        let markdown = format!("**{}** and `a b`", "bold ".repeat(700).trim());
+        let texts = section_texts(&markdown);
+        assert_eq!(texts.len(), 2);
+        assert!(texts[0].starts_with("*bold") && texts[0].ends_with("bold*"));
+        assert!(texts[1].starts_with("*bold") && texts[1].ends_with("* and `a b`"));
+    }
+
+    #[test]
+    fn split_long_code_block() {
+        let markdown = format!("```\n{}```", "let a = b && c;\n".repeat(200));
+        let texts = section_texts(&markdown);
+        assert_eq!(texts.len(), 2);
+        for text in &texts {
+            assert!(text.starts_with("```\nlet a = b &amp;&amp; c;\n"));
+            assert!(text.ends_with("\nlet a = b &amp;&amp; c;\n```"));
+        }
+
+        let markdown = format!("> ```\n> {}```", "let a = b && c;\n> ".repeat(300));
+        for text in section_texts(&markdown) {
+            assert!(text.starts_with("> ```\n"));
+            assert!(text.ends_with("\n> ```"));
+            assert!(text.lines().all(|line| line.starts_with("> ")));
+        }
+    }
+
+    #[test]
+    fn split_long_quote() {
+        let markdown = format!("> {}", "quoted words ".repeat(400));
+        let texts = section_texts(&markdown);
+        assert_eq!(texts.len(), 2);
+        for text in &texts {
+            assert!(text.starts_with("> quoted ") || text.starts_with("> words "));
+        }
+    }
+}
@@ -9,5 +9,12 @@ pub mod block_header;
 pub mod block_image;
 pub mod block_input;
 pub mod block_object;
+pub mod block_rich_text;
 pub mod block_section;
+pub mod block_video;
 pub mod blocks;
+pub mod macros;
+pub mod markdown;
+pub mod preview;
+pub mod template;
+pub mod validation;
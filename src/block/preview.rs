@@ -0,0 +1,1145 @@
+//! Renders blocks, attachments and views offline as approximate HTML or plain text.
+//!
+//! The output is meant for local previews and snapshot tests; it does not try to match Slack's
+//! styling.
+//!
+//! ```
+//! use slack_rust::block::preview::blocks_to_text;
+//! use slack_rust::blocks;
+//!
+//! let blocks = blocks![
+//!     header("Deploy"),
+//!     section("*Status:* done", accessory: button("Logs", "logs")),
+//!     divider,
+//! ];
+//! assert_eq!(
+//!     blocks_to_text(&blocks),
+//!     "# Deploy\nStatus: done\n[ Logs ]\n────────────────────"
+//! );
+//! ```
+
+use crate::attachment::attachment::Attachment;
+use crate::block::block_elements::{BlockElement, MixedElement};
+use crate::block::block_object::{
+    OptionBlockObject, OptionGroupBlockObject, TextBlockObject, TextBlockType,
+};
+use crate::block::block_rich_text::{RichTextElement, RichTextSectionElement, RichTextStyle};
+use crate::block::blocks::Block;
+use crate::mrkdwn::parser::{parse, Node};
+use crate::mrkdwn::render::{escape_html, is_safe_link, to_html, to_plain_text, NoopResolver};
+use crate::views::view::{View, ViewType};
+
+/// Renders blocks as an HTML fragment.
+pub fn blocks_to_html(blocks: &[Block]) -> String {
+    let mut out = String::new();
+    for block in blocks {
+        block_html(block, &mut out);
+    }
+    out
+}
+
+/// Renders blocks as plain text, one or more lines per block.
+pub fn blocks_to_text(blocks: &[Block]) -> String {
+    blocks
+        .iter()
+        .map(block_text)
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Renders legacy attachments as an HTML fragment.
+pub fn attachments_to_html(attachments: &[Attachment]) -> String {
+    let mut out = String::new();
+    for attachment in attachments {
+        attachment_html(attachment, &mut out);
+    }
+    out
+}
+
+/// Renders legacy attachments as plain text, marking each with a bar on the left.
+pub fn attachments_to_text(attachments: &[Attachment]) -> String {
+    attachments
+        .iter()
+        .map(attachment_text)
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Renders a modal or home tab as an HTML fragment.
+pub fn view_to_html(view: &View) -> String {
+    let class = match view.type_filed {
+        Some(ViewType::Home) => "home",
+        _ => "modal",
+    };
+    let mut out = format!("<div class=\"view view-{}\">\n", class);
+    if let Some(title) = &view.title {
+        out.push_str(&format!(
+            "<div class=\"view-title\">{}</div>\n",
+            text_html(title)
+        ));
+    }
+    out.push_str(&blocks_to_html(view.blocks.as_deref().unwrap_or_default()));
+    if view.close.is_some() || view.submit.is_some() {
+        out.push_str("<div class=\"view-footer\">");
+        if let Some(close) = &view.close {
+            out.push_str(&format!(
+                "<button class=\"button\">{}</button>",
+                text_html(close)
+            ));
+        }
+        if let Some(submit) = &view.submit {
+            out.push_str(&format!(
+                "<button class=\"button button-primary\">{}</button>",
+                text_html(submit)
+            ));
+        }
+        out.push_str("</div>\n");
+    }
+    out.push_str("</div>\n");
+    out
+}
+
+/// Renders a modal or home tab as plain text.
+pub fn view_to_text(view: &View) -> String {
+    let mut lines = Vec::new();
+    if let Some(title) = &view.title {
+        let title = text_plain(title);
+        lines.push(format!("{}\n{}", title, "=".repeat(title.chars().count())));
+    }
+    let blocks = blocks_to_text(view.blocks.as_deref().unwrap_or_default());
+    if !blocks.is_empty() {
+        lines.push(blocks);
+    }
+    let buttons = [&view.close, &view.submit]
+        .iter()
+        .filter_map(|text| {
+            text.as_ref()
+                .map(|text| format!("[ {} ]", text_plain(text)))
+        })
+        .collect::<Vec<String>>();
+    if !buttons.is_empty() {
+        lines.push(buttons.join(" "));
+    }
+    lines.join("\n")
+}
+
+/// An interactive element reduced to what is needed to draw it.
+enum Control {
+    Button {
+        label: String,
+        style: Option<String>,
+        url: Option<String>,
+    },
+    Select {
+        placeholder: String,
+        options: Vec<String>,
+        selected: Vec<String>,
+        multiple: bool,
+    },
+    Choices {
+        options: Vec<String>,
+        selected: Vec<String>,
+        multiple: bool,
+    },
+    Input {
+        kind: &'static str,
+        placeholder: String,
+        value: Option<String>,
+        multiline: bool,
+    },
+    Image {
+        url: String,
+        alt_text: String,
+    },
+}
+
+fn text_nodes(text: &TextBlockObject) -> Vec<Node> {
+    match text.type_filed {
+        TextBlockType::Mrkdwn => parse(&text.text),
+        _ => vec![Node::Text(text.text.clone())],
+    }
+}
+
+fn text_plain(text: &TextBlockObject) -> String {
+    to_plain_text(&text_nodes(text), &NoopResolver)
+}
+
+fn text_html(text: &TextBlockObject) -> String {
+    to_html(&text_nodes(text), &NoopResolver)
+}
+
+fn mrkdwn_plain(text: &str) -> String {
+    to_plain_text(&parse(text), &NoopResolver)
+}
+
+fn mrkdwn_html(text: &str) -> String {
+    to_html(&parse(text), &NoopResolver)
+}
+
+fn option_labels(options: &[OptionBlockObject]) -> Vec<String> {
+    options
+        .iter()
+        .map(|option| text_plain(&option.text))
+        .collect()
+}
+
+fn all_options(
+    options: &[OptionBlockObject],
+    option_groups: &Option<Vec<OptionGroupBlockObject>>,
+) -> Vec<String> {
+    let mut labels = option_labels(options);
+    for group in option_groups.iter().flatten() {
+        labels.extend(option_labels(group.options.as_deref().unwrap_or_default()));
+    }
+    labels
+}
+
+fn placeholder(placeholder: &Option<TextBlockObject>, default: &str) -> String {
+    placeholder
+        .as_ref()
+        .map_or_else(|| default.to_string(), text_plain)
+}
+
+fn control(element: &BlockElement) -> Option<Control> {
+    let control = match element {
+        BlockElement::ButtonElement(e) => Control::Button {
+            label: text_plain(&e.text),
+            style: e.style.clone(),
+            url: e.url.clone(),
+        },
+        BlockElement::WorkflowButtonElement(e) => Control::Button {
+            label: text_plain(&e.text),
+            style: e.style.clone(),
+            url: None,
+        },
+        BlockElement::CheckboxGroupsBlockElement(e) => Control::Choices {
+            options: option_labels(&e.options),
+            selected: option_labels(e.initial_options.as_deref().unwrap_or_default()),
+            multiple: true,
+        },
+        BlockElement::RadioButtonsBlockElement(e) => Control::Choices {
+            options: option_labels(&e.options),
+            selected: option_labels(e.initial_option.as_slice()),
+            multiple: false,
+        },
+        BlockElement::SelectBlockElement(e) => Control::Select {
+            placeholder: text_plain(&e.placeholder),
+            options: all_options(&e.options, &e.option_groups),
+            selected: option_labels(e.initial_option.as_slice()),
+            multiple: false,
+        },
+        BlockElement::MultiSelectBlockElement(e) => Control::Select {
+            placeholder: text_plain(&e.placeholder),
+            options: all_options(&e.options, &e.option_groups),
+            selected: option_labels(e.initial_option.as_slice()),
+            multiple: true,
+        },
+        BlockElement::OverflowBlockElement(e) => Control::Select {
+            placeholder: "⋯".to_string(),
+            options: option_labels(&e.options),
+            selected: Vec::new(),
+            multiple: false,
+        },
+        BlockElement::DatePickerBlockElement(e) => Control::Input {
+            kind: "date",
+            placeholder: placeholder(&e.placeholder, "Select a date"),
+            value: e.initial_date.clone(),
+            multiline: false,
+        },
+        BlockElement::TimePickerBlockElement(e) => Control::Input {
+            kind: "time",
+            placeholder: placeholder(&e.placeholder, "Select time"),
+            value: e.initial_time.clone(),
+            multiline: false,
+        },
+        BlockElement::DateTimePickerBlockElement(e) => Control::Input {
+            kind: "datetime-local",
+            placeholder: "Select date and time".to_string(),
+            value: e.initial_date_time.map(|ts| ts.to_string()),
+            multiline: false,
+        },
+        BlockElement::PlainTextInputBlockElement(e) => Control::Input {
+            kind: "text",
+            placeholder: placeholder(&e.placeholder, ""),
+            value: e.initial_value.clone(),
+            multiline: e.multiline.unwrap_or(false),
+        },
+        BlockElement::EmailTextInputBlockElement(e) => Control::Input {
+            kind: "email",
+            placeholder: placeholder(&e.placeholder, ""),
+            value: e.initial_value.clone(),
+            multiline: false,
+        },
+        BlockElement::UrlTextInputBlockElement(e) => Control::Input {
+            kind: "url",
+            placeholder: placeholder(&e.placeholder, ""),
+            value: e.initial_value.clone(),
+            multiline: false,
+        },
+        BlockElement::NumberInputBlockElement(e) => Control::Input {
+            kind: "number",
+            placeholder: placeholder(&e.placeholder, ""),
+            value: e.initial_value.clone(),
+            multiline: false,
+        },
+        BlockElement::RichTextInputBlockElement(e) => Control::Input {
+            kind: "text",
+            placeholder: placeholder(&e.placeholder, ""),
+            value: e
+                .initial_value
+                .as_ref()
+                .map(|value| to_plain_text(&rich_text_nodes(&value.elements), &NoopResolver)),
+            multiline: true,
+        },
+        BlockElement::FileInputBlockElement(_) => Control::Input {
+            kind: "file",
+            placeholder: "Upload files".to_string(),
+            value: None,
+            multiline: false,
+        },
+        BlockElement::ImageBlockElement(e) => Control::Image {
+            url: e.image_url.clone(),
+            alt_text: e.alt_text.clone(),
+        },
+        BlockElement::None | BlockElement::Unknown(_) => return None,
+    };
+    Some(control)
+}
+
+fn control_text(control: &Control) -> String {
+    match control {
+        Control::Button { label, url, .. } => match url {
+            Some(_) => format!("[ {} ↗ ]", label),
+            None => format!("[ {} ]", label),
+        },
+        Control::Select {
+            placeholder,
+            selected,
+            ..
+        } => {
+            let shown = if selected.is_empty() {
+                placeholder.clone()
+            } else {
+                selected.join(", ")
+            };
+            format!("[ {} ▾ ]", shown)
+        }
+        Control::Choices {
+            options,
+            selected,
+            multiple,
+        } => options
+            .iter()
+            .map(|option| {
+                let mark = match (*multiple, selected.contains(option)) {
+                    (true, true) => "☑",
+                    (true, false) => "☐",
+                    (false, true) => "◉",
+                    (false, false) => "○",
+                };
+                format!("{} {}", mark, option)
+            })
+            .collect::<Vec<String>>()
+            .join("\n"),
+        Control::Input {
+            placeholder, value, ..
+        } => format!("[ {} ]", value.as_ref().unwrap_or(placeholder)),
+        Control::Image { alt_text, .. } => format!("[image: {}]", alt_text),
+    }
+}
+
+fn control_html(control: &Control) -> String {
+    match control {
+        Control::Button { label, style, url } => {
+            let class = match style {
+                Some(style) => format!("button button-{}", escape_html(style)),
+                None => "button".to_string(),
+            };
+            match url.as_ref().filter(|url| is_safe_link(url)) {
+                Some(url) => format!(
+                    "<a class=\"{}\" href=\"{}\">{}</a>",
+                    class,
+                    escape_html(url),
+                    escape_html(label)
+                ),
+                None => format!(
+                    "<button class=\"{}\">{}</button>",
+                    class,
+                    escape_html(label)
+                ),
+            }
+        }
+        Control::Select {
+            placeholder,
+            options,
+            selected,
+            multiple,
+        } => {
+            let mut html = if *multiple {
+                "<select multiple>".to_string()
+            } else {
+                "<select>".to_string()
+            };
+            html.push_str(&format!(
+                "<option disabled{}>{}</option>",
+                if selected.is_empty() { " selected" } else { "" },
+                escape_html(placeholder)
+            ));
+            for option in options {
+                html.push_str(&format!(
+                    "<option{}>{}</option>",
+                    if selected.contains(option) {
+                        " selected"
+                    } else {
+                        ""
+                    },
+                    escape_html(option)
+                ));
+            }
+            html.push_str("</select>");
+            html
+        }
+        Control::Choices {
+            options,
+            selected,
+            multiple,
+        } => {
+            let kind = if *multiple { "checkbox" } else { "radio" };
+            let mut html = format!("<div class=\"{}\">", kind);
+            for option in options {
+                html.push_str(&format!(
+                    "<label><input type=\"{}\"{}> {}</label>",
+                    kind,
+                    if selected.contains(option) {
+                        " checked"
+                    } else {
+                        ""
+                    },
+                    escape_html(option)
+                ));
+            }
+            html.push_str("</div>");
+            html
+        }
+        Control::Input {
+            kind,
+            placeholder,
+            value,
+            multiline,
+        } => {
+            if *multiline {
+                format!(
+                    "<textarea placeholder=\"{}\">{}</textarea>",
+                    escape_html(placeholder),
+                    escape_html(value.as_deref().unwrap_or_default())
+                )
+            } else {
+                let value = value
+                    .as_ref()
+                    .map(|value| format!(" value=\"{}\"", escape_html(value)))
+                    .unwrap_or_default();
+                format!(
+                    "<input type=\"{}\" placeholder=\"{}\"{}>",
+                    kind,
+                    escape_html(placeholder),
+                    value
+                )
+            }
+        }
+        Control::Image { url, alt_text } => format!(
+            "<img class=\"element-image\" src=\"{}\" alt=\"{}\">",
+            escape_html(url),
+            escape_html(alt_text)
+        ),
+    }
+}
+
+fn block_text(block: &Block) -> String {
+    let mut lines = Vec::new();
+    match block {
+        Block::HeaderBlock(b) => lines.push(format!("# {}", text_plain(&b.text))),
+        Block::SectionBlock(b) => {
+            if let Some(text) = &b.text {
+                lines.push(text_plain(text));
+            }
+            for field in b.fields.iter().flatten() {
+                lines.push(text_plain(field));
+            }
+            if let Some(control) = b.accessory.as_ref().and_then(control) {
+                lines.push(control_text(&control));
+            }
+        }
+        Block::DividerBlock(_) => lines.push("─".repeat(20)),
+        Block::ImageBlock(b) => {
+            if let Some(title) = &b.title {
+                lines.push(text_plain(title));
+            }
+            lines.push(format!("[image: {}]", b.alt_text));
+        }
+        Block::ContextBlock(b) => lines.push(
+            b.elements
+                .iter()
+                .filter_map(|element| match element {
+                    MixedElement::ImageBlockElement(e) => Some(format!("[image: {}]", e.alt_text)),
+                    MixedElement::PlainTextBlockObject { text, .. } => Some(text.clone()),
+                    MixedElement::MarkdownBlockObject { text, .. } => Some(mrkdwn_plain(text)),
+                    MixedElement::None => None,
+                })
+                .collect::<Vec<String>>()
+                .join(" · "),
+        ),
+        Block::ActionBlock(b) => lines.push(
+            b.elements
+                .iter()
+                .filter_map(control)
+                .map(|control| control_text(&control))
+                .collect::<Vec<String>>()
+                .join(" "),
+        ),
+        Block::InputBlock(b) => {
+            let mut label = text_plain(&b.label);
+            if b.optional == Some(true) {
+                label.push_str(" (optional)");
+            }
+            lines.push(label);
+            if let Some(control) = control(&b.element) {
+                lines.push(control_text(&control));
+            }
+            if let Some(hint) = &b.hint {
+                lines.push(text_plain(hint));
+            }
+        }
+        Block::RichTextBlock(b) => {
+            lines.push(to_plain_text(&rich_text_nodes(&b.elements), &NoopResolver))
+        }
+        Block::FileBlock(b) => lines.push(format!("[file: {}]", b.external_id)),
+        Block::VideoBlock(b) => {
+            lines.push(format!("[video: {}] {}", text_plain(&b.title), b.video_url))
+        }
+        Block::None | Block::Unknown(_) => {}
+    }
+    lines.join("\n")
+}
+
+fn block_html(block: &Block, out: &mut String) {
+    match block {
+        Block::HeaderBlock(b) => out.push_str(&format!(
+            "<h3 class=\"block-header\">{}</h3>\n",
+            text_html(&b.text)
+        )),
+        Block::SectionBlock(b) => {
+            out.push_str("<div class=\"block-section\">");
+            if let Some(text) = &b.text {
+                out.push_str(&format!(
+                    "<div class=\"section-text\">{}</div>",
+                    text_html(text)
+                ));
+            }
+            if let Some(fields) = &b.fields {
+                out.push_str("<div class=\"section-fields\">");
+                for field in fields {
+                    out.push_str(&format!(
+                        "<div class=\"section-field\">{}</div>",
+                        text_html(field)
+                    ));
+                }
+                out.push_str("</div>");
+            }
+            if let Some(control) = b.accessory.as_ref().and_then(control) {
+                out.push_str(&format!(
+                    "<div class=\"section-accessory\">{}</div>",
+                    control_html(&control)
+                ));
+            }
+            out.push_str("</div>\n");
+        }
+        Block::DividerBlock(_) => out.push_str("<hr class=\"block-divider\">\n"),
+        Block::ImageBlock(b) => {
+            out.push_str("<figure class=\"block-image\">");
+            if let Some(title) = &b.title {
+                out.push_str(&format!("<figcaption>{}</figcaption>", text_html(title)));
+            }
+            out.push_str(&format!(
+                "<img src=\"{}\" alt=\"{}\"></figure>\n",
+                escape_html(&b.image_url),
+                escape_html(&b.alt_text)
+            ));
+        }
+        Block::ContextBlock(b) => {
+            out.push_str("<div class=\"block-context\">");
+            for element in &b.elements {
+                match element {
+                    MixedElement::ImageBlockElement(e) => out.push_str(&format!(
+                        "<img class=\"context-image\" src=\"{}\" alt=\"{}\">",
+                        escape_html(&e.image_url),
+                        escape_html(&e.alt_text)
+                    )),
+                    MixedElement::PlainTextBlockObject { text, .. } => {
+                        out.push_str(&format!("<span>{}</span>", escape_html(text)))
+                    }
+                    MixedElement::MarkdownBlockObject { text, .. } => {
+                        out.push_str(&format!("<span>{}</span>", mrkdwn_html(text)))
+                    }
+                    MixedElement::None => {}
+                }
+            }
+            out.push_str("</div>\n");
+        }
+        Block::ActionBlock(b) => {
+            out.push_str("<div class=\"block-actions\">");
+            for control in b.elements.iter().filter_map(control) {
+                out.push_str(&control_html(&control));
+            }
+            out.push_str("</div>\n");
+        }
+        Block::InputBlock(b) => {
+            out.push_str(&format!(
+                "<div class=\"block-input\"><label>{}{}</label>",
+                text_html(&b.label),
+                if b.optional == Some(true) {
+                    "<span class=\"optional\"> (optional)</span>"
+                } else {
+                    ""
+                }
+            ));
+            if let Some(control) = control(&b.element) {
+                out.push_str(&control_html(&control));
+            }
+            if let Some(hint) = &b.hint {
+                out.push_str(&format!(
+                    "<div class=\"input-hint\">{}</div>",
+                    text_html(hint)
+                ));
+            }
+            out.push_str("</div>\n");
+        }
+        Block::RichTextBlock(b) => out.push_str(&format!(
+            "<div class=\"block-rich-text\">{}</div>\n",
+            to_html(&rich_text_nodes(&b.elements), &NoopResolver)
+        )),
+        Block::FileBlock(b) => out.push_str(&format!(
+            "<div class=\"block-file\">{}</div>\n",
+            escape_html(&b.external_id)
+        )),
+        Block::VideoBlock(b) => {
+            let thumbnail = format!(
+                "<img src=\"{}\" alt=\"{}\">",
+                escape_html(&b.thumbnail_url),
+                escape_html(&b.alt_text)
+            );
+            let thumbnail = if is_safe_link(&b.video_url) {
+                format!(
+                    "<a href=\"{}\">{}</a>",
+                    escape_html(&b.video_url),
+                    thumbnail
+                )
+            } else {
+                thumbnail
+            };
+            out.push_str(&format!(
+                "<div class=\"block-video\">{}<div>{}</div></div>\n",
+                thumbnail,
+                text_html(&b.title)
+            ))
+        }
+        Block::None | Block::Unknown(_) => {}
+    }
+}
+
+fn rich_text_nodes(elements: &[RichTextElement]) -> Vec<Node> {
+    let mut nodes = Vec::new();
+    for element in elements {
+        let element_nodes = match element {
+            RichTextElement::RichTextSection(section) => section_nodes(&section.elements),
+            RichTextElement::RichTextList(list) => {
+                let indent = "    ".repeat(list.indent.unwrap_or(0).max(0) as usize);
+                let mut list_nodes = Vec::new();
+                for (i, item) in list.elements.iter().enumerate() {
+                    if !list_nodes.is_empty() {
+                        list_nodes.push(Node::LineBreak);
+                    }
+                    let marker = if list.style == "ordered" {
+                        format!("{}. ", i as i32 + 1 + list.offset.unwrap_or(0))
+                    } else {
+                        "• ".to_string()
+                    };
+                    list_nodes.push(Node::Text(format!("{}{}", indent, marker)));
+                    list_nodes.extend(rich_text_nodes(std::slice::from_ref(item)));
+                }
+                list_nodes
+            }
+            RichTextElement::RichTextPreformatted(preformatted) => {
+                vec![Node::CodeBlock(to_plain_text(
+                    &section_nodes(&preformatted.elements),
+                    &NoopResolver,
+                ))]
+            }
+            RichTextElement::RichTextQuote(quote) => {
+                vec![Node::Quote(section_nodes(&quote.elements))]
+            }
+            RichTextElement::None => Vec::new(),
+        };
+        if element_nodes.is_empty() {
+            continue;
+        }
+        if !nodes.is_empty() && !matches!(element_nodes[0], Node::CodeBlock(_) | Node::Quote(_)) {
+            nodes.push(Node::LineBreak);
+        }
+        nodes.extend(element_nodes);
+    }
+    nodes
+}
+
+fn styled(node: Node, style: &Option<RichTextStyle>) -> Node {
+    let style = match style {
+        Some(style) => style,
+        None => return node,
+    };
+    let mut node = node;
+    if style.code == Some(true) {
+        if let Node::Text(text) = node {
+            node = Node::Code(text);
+        }
+    }
+    if style.strike == Some(true) {
+        node = Node::Strike(vec![node]);
+    }
+    if style.italic == Some(true) {
+        node = Node::Italic(vec![node]);
+    }
+    if style.bold == Some(true) {
+        node = Node::Bold(vec![node]);
+    }
+    node
+}
+
+fn section_nodes(elements: &[RichTextSectionElement]) -> Vec<Node> {
+    let mut nodes = Vec::new();
+    for element in elements {
+        match element {
+            RichTextSectionElement::RichTextTextElement(e) => {
+                for (i, line) in e.text.split('\n').enumerate() {
+                    if i > 0 {
+                        nodes.push(Node::LineBreak);
+                    }
+                    if !line.is_empty() {
+                        nodes.push(styled(Node::Text(line.to_string()), &e.style));
+                    }
+                }
+            }
+            RichTextSectionElement::RichTextLinkElement(e) => nodes.push(styled(
+                Node::Link {
+                    url: e.url.clone(),
+                    label: e.text.clone(),
+                },
+                &e.style,
+            )),
+            RichTextSectionElement::RichTextUserElement(e) => nodes.push(Node::User {
+                id: e.user_id.to_string(),
+                label: None,
+            }),
+            RichTextSectionElement::RichTextChannelElement(e) => nodes.push(Node::Channel {
+                id: e.channel_id.to_string(),
+                label: None,
+            }),
+            RichTextSectionElement::RichTextUsergroupElement(e) => nodes.push(Node::Usergroup {
+                id: e.usergroup_id.to_string(),
+                label: None,
+            }),
+            RichTextSectionElement::RichTextEmojiElement(e) => {
+                let unicode = e.unicode.as_ref().and_then(|unicode| {
+                    unicode
+                        .split('-')
+                        .map(|code| u32::from_str_radix(code, 16).ok().and_then(char::from_u32))
+                        .collect::<Option<String>>()
+                });
+                nodes.push(match unicode {
+                    Some(unicode) => Node::Text(unicode),
+                    None => Node::Emoji(e.name.clone()),
+                });
+            }
+            RichTextSectionElement::RichTextBroadcastElement(e) => {
+                nodes.push(Node::SpecialMention(e.range.clone()))
+            }
+            RichTextSectionElement::RichTextDateElement(e) => nodes.push(Node::Date {
+                timestamp: e.timestamp,
+                format: e.format.clone(),
+                link: e.url.clone(),
+                fallback: e
+                    .fallback
+                    .clone()
+                    .unwrap_or_else(|| e.timestamp.to_string()),
+            }),
+            RichTextSectionElement::RichTextColorElement(e) => {
+                nodes.push(Node::Text(e.value.clone()))
+            }
+            RichTextSectionElement::None | RichTextSectionElement::Unknown(_) => {}
+        }
+    }
+    nodes
+}
+
+fn attachment_color(attachment: &Attachment) -> String {
+    match attachment.color.as_deref() {
+        Some("good") => "#2eb67d".to_string(),
+        Some("warning") => "#ecb22e".to_string(),
+        Some("danger") => "#e01e5a".to_string(),
+        Some(color) if is_hex_color(color.trim_start_matches('#')) => {
+            format!("#{}", color.trim_start_matches('#'))
+        }
+        _ => "#dddddd".to_string(),
+    }
+}
+
+/// Whether `color` is 3 or 6 hex digits, so that it can't add other CSS to a style attribute.
+fn is_hex_color(color: &str) -> bool {
+    matches!(color.len(), 3 | 6) && color.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+fn attachment_text(attachment: &Attachment) -> String {
+    let mut lines = Vec::new();
+    if let Some(author_name) = &attachment.author_name {
+        lines.push(author_name.clone());
+    }
+    if let Some(title) = &attachment.title {
+        lines.push(title.clone());
+    }
+    if let Some(text) = &attachment.text {
+        lines.push(mrkdwn_plain(text));
+    }
+    for field in attachment.fields.iter().flatten() {
+        lines.push(format!(
+            "{}: {}",
+            field.title.as_deref().unwrap_or_default(),
+            mrkdwn_plain(field.value.as_deref().unwrap_or_default())
+        ));
+    }
+    if let Some(blocks) = &attachment.blocks {
+        lines.push(blocks_to_text(blocks));
+    }
+    if let Some(image_url) = &attachment.image_url {
+        lines.push(format!("[image: {}]", image_url));
+    }
+    if let Some(footer) = &attachment.footer {
+        lines.push(mrkdwn_plain(footer));
+    }
+
+    lines.retain(|line| !line.is_empty());
+
+    let mut text = String::new();
+    if let Some(pretext) = &attachment.pretext {
+        text.push_str(&mrkdwn_plain(pretext));
+        text.push('\n');
+    }
+    let body = lines
+        .join("\n")
+        .split('\n')
+        .map(|line| format!("▌ {}", line))
+        .collect::<Vec<String>>()
+        .join("\n");
+    text.push_str(&body);
+    text
+}
+
+fn attachment_html(attachment: &Attachment, out: &mut String) {
+    if let Some(pretext) = &attachment.pretext {
+        out.push_str(&format!(
+            "<div class=\"attachment-pretext\">{}</div>\n",
+            mrkdwn_html(pretext)
+        ));
+    }
+    out.push_str(&format!(
+        "<div class=\"attachment\" style=\"border-left: 4px solid {}\">\n",
+        escape_html(&attachment_color(attachment))
+    ));
+    if let Some(author_name) = &attachment.author_name {
+        out.push_str(&format!(
+            "<div class=\"attachment-author\">{}</div>\n",
+            escape_html(author_name)
+        ));
+    }
+    if let Some(title) = &attachment.title {
+        let title = match attachment
+            .title_link
+            .as_ref()
+            .filter(|link| is_safe_link(link))
+        {
+            Some(link) => format!(
+                "<a href=\"{}\">{}</a>",
+                escape_html(link),
+                escape_html(title)
+            ),
+            None => escape_html(title),
+        };
+        out.push_str(&format!(
+            "<div class=\"attachment-title\">{}</div>\n",
+            title
+        ));
+    }
+    if let Some(text) = attachment.text.as_ref().filter(|text| !text.is_empty()) {
+        out.push_str(&format!(
+            "<div class=\"attachment-text\">{}</div>\n",
+            mrkdwn_html(text)
+        ));
+    }
+    if let Some(fields) = &attachment.fields {
+        out.push_str("<div class=\"attachment-fields\">");
+        for field in fields {
+            out.push_str(&format!(
+                "<div class=\"attachment-field{}\"><strong>{}</strong><div>{}</div></div>",
+                if field.short == Some(true) {
+                    " short"
+                } else {
+                    ""
+                },
+                escape_html(field.title.as_deref().unwrap_or_default()),
+                mrkdwn_html(field.value.as_deref().unwrap_or_default())
+            ));
+        }
+        out.push_str("</div>\n");
+    }
+    if let Some(blocks) = &attachment.blocks {
+        out.push_str(&blocks_to_html(blocks));
+    }
+    if let Some(image_url) = &attachment.image_url {
+        out.push_str(&format!(
+            "<img class=\"attachment-image\" src=\"{}\">\n",
+            escape_html(image_url)
+        ));
+    }
+    if let Some(footer) = &attachment.footer {
+        out.push_str(&format!(
+            "<div class=\"attachment-footer\">{}</div>\n",
+            mrkdwn_html(footer)
+        ));
+    }
+    out.push_str("</div>\n");
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::attachment::attachment::AttachmentField;
+    use crate::block::block_elements::{CheckboxGroupsBlockElement, SelectBlockElement};
+    use crate::block::block_object::OptionBlockObject;
+    use crate::block::block_rich_text::{
+        RichTextBlock, RichTextList, RichTextSection, RichTextTextElement,
+    };
+    use crate::block::macros::{mrkdwn, plain_text};
+
+    fn option(text: &str) -> OptionBlockObject {
+        OptionBlockObject::builder(plain_text(text)).build()
+    }
+
+    fn message() -> Vec<Block> {
+        crate::blocks![
+            header("Deploy <prod>"),
+            section("*Status:* done", accessory: button("Logs", "logs")),
+            fields("*Env*\nprod", "*By*\n<@U1>"),
+            context("Today", "<https://x.com|build>"),
+            actions(
+                button("Approve", "approve"),
+                BlockElement::SelectBlockElement(
+                    SelectBlockElement::builder(
+                        plain_text("Pick"),
+                        "pick".to_string(),
+                        vec![option("a"), option("b")]
+                    )
+                    .initial_option(option("b"))
+                    .build()
+                ),
+            ),
+            input(
+                "Choose",
+                BlockElement::CheckboxGroupsBlockElement(
+                    CheckboxGroupsBlockElement::builder(
+                        "choose".to_string(),
+                        vec![option("x"), option("y")]
+                    )
+                    .initial_options(vec![option("y")])
+                    .build()
+                )
+            ),
+            image("https://x.com/a.png", "graph"),
+            Block::RichTextBlock(
+                RichTextBlock::builder(vec![RichTextElement::RichTextList(
+                    RichTextList::builder(
+                        "ordered".to_string(),
+                        vec![
+                            RichTextElement::RichTextSection(RichTextSection::new(vec![
+                                RichTextSectionElement::RichTextTextElement(
+                                    RichTextTextElement::builder("one".to_string()).build()
+                                )
+                            ])),
+                            RichTextElement::RichTextSection(RichTextSection::new(vec![
+                                RichTextSectionElement::RichTextTextElement(
+                                    RichTextTextElement::builder("two".to_string())
+                                        .style(RichTextStyle::builder().bold(true).build())
+                                        .build()
+                                )
+                            ])),
+                        ]
+                    )
+                    .build()
+                )])
+                .build()
+            ),
+            divider,
+        ]
+    }
+
+    #[test]
+    fn render_blocks_text() {
+        assert_eq!(
+            blocks_to_text(&message()),
+            r#"# Deploy <prod>
+Status: done
+[ Logs ]
+Env
+prod
+By
+@U1
+Today · build
+[ Approve ] [ b ▾ ]
+Choose
+☐ x
+☑ y
+[image: graph]
+1. one
+2. two
+────────────────────"#
+        );
+    }
+
+    #[test]
+    fn render_blocks_html() {
+        assert_eq!(
+            blocks_to_html(&message()),
+            r#"<h3 class="block-header">Deploy &lt;prod&gt;</h3>
+<div class="block-section"><div class="section-text"><strong>Status:</strong> done</div><div class="section-accessory"><button class="button">Logs</button></div></div>
+<div class="block-section"><div class="section-fields"><div class="section-field"><strong>Env</strong><br>prod</div><div class="section-field"><strong>By</strong><br><span class="mention">@U1</span></div></div></div>
+<div class="block-context"><span>Today</span><span><a href="https://x.com">build</a></span></div>
+<div class="block-actions"><button class="button">Approve</button><select><option disabled>Pick</option><option>a</option><option selected>b</option></select></div>
+<div class="block-input"><label>Choose</label><div class="checkbox"><label><input type="checkbox"> x</label><label><input type="checkbox" checked> y</label></div></div>
+<figure class="block-image"><img src="https://x.com/a.png" alt="graph"></figure>
+<div class="block-rich-text">1. one<br>2. <strong>two</strong></div>
+<hr class="block-divider">
+"#
+        );
+    }
+
+    #[test]
+    fn render_view_and_attachments() {
+        let view = crate::view!(modal {
+            title: "Feedback",
+            submit: "Send",
+            close: "Cancel",
+            blocks: [input("Comment", plain_text_input("comment"))],
+        });
+        assert_eq!(
+            view_to_text(&view),
+            "Feedback\n========\nComment\n[  ]\n[ Cancel ] [ Send ]"
+        );
+        assert_eq!(
+            view_to_html(&view),
+            r#"<div class="view view-modal">
+<div class="view-title">Feedback</div>
+<div class="block-input"><label>Comment</label><input type="text" placeholder=""></div>
+<div class="view-footer"><button class="button">Cancel</button><button class="button button-primary">Send</button></div>
+</div>
+"#
+        );
+
+        let attachments = vec![Attachment::builder()
+            .color("good".to_string())
+            .pretext("Build *finished*".to_string())
+            .title("Pipeline".to_string())
+            .fields(vec![AttachmentField::builder()
+                .title("Duration".to_string())
+                .value("5m".to_string())
+                .build()])
+            .build()];
+        assert_eq!(
+            attachments_to_text(&attachments),
+            "Build finished\n▌ Pipeline\n▌ Duration: 5m"
+        );
+        assert_eq!(
+            attachments_to_html(&attachments),
+            r#"<div class="attachment-pretext">Build <strong>finished</strong></div>
+<div class="attachment" style="border-left: 4px solid #2eb67d">
+<div class="attachment-title">Pipeline</div>
+<div class="attachment-fields"><div class="attachment-field"><strong>Duration</strong><div>5m</div></div></div>
+</div>
+"#
+        );
+        assert_eq!(
+            blocks_to_text(&[Block::SectionBlock(
+                crate::block::block_section::SectionBlock::builder()
+                    .text(mrkdwn("&lt;ok&gt;"))
+                    .build()
+            )]),
+            "<ok>"
+        );
+    }
+
+    #[test]
+    fn render_unsafe_links_as_text() {
+        use crate::block::block_elements::ButtonElement;
+        use crate::block::block_video::VideoBlock;
+
+        let blocks = crate::blocks![
+            actions(
+                BlockElement::ButtonElement(
+                    ButtonElement::builder(plain_text("Open"), "open".to_string())
+                        .url("javascript:alert(1)".to_string())
+                        .build()
+                ),
+                BlockElement::ButtonElement(
+                    ButtonElement::builder(plain_text("Docs"), "docs".to_string())
+                        .url("https://x.com/docs".to_string())
+                        .build()
+                ),
+            ),
+            Block::VideoBlock(
+                VideoBlock::builder(
+                    "demo".to_string(),
+                    plain_text("Demo"),
+                    "https://x.com/t.png".to_string(),
+                    "javascript:alert(1)".to_string()
+                )
+                .build()
+            ),
+        ];
+        assert_eq!(
+            blocks_to_html(&blocks),
+            r#"<div class="block-actions"><button class="button">Open</button><a class="button" href="https://x.com/docs">Docs</a></div>
+<div class="block-video"><img src="https://x.com/t.png" alt="demo"><div>Demo</div></div>
+"#
+        );
+
+        let attachments = vec![Attachment::builder()
+            .color("red; background: url(https://x.com)".to_string())
+            .title("Pipeline".to_string())
+            .title_link("javascript:alert(1)".to_string())
+            .build()];
+        assert_eq!(
+            attachments_to_html(&attachments),
+            r#"<div class="attachment" style="border-left: 4px solid #dddddd">
+<div class="attachment-title">Pipeline</div>
+</div>
+"#
+        );
+    }
+
+    #[test]
+    fn accept_only_hex_attachment_colors() {
+        let color =
+            |color: &str| attachment_color(&Attachment::builder().color(color.to_string()).build());
+        assert_eq!(color("danger"), "#e01e5a");
+        assert_eq!(color("#abc"), "#abc");
+        assert_eq!(color("439FE0"), "#439FE0");
+        assert_eq!(color("#439FE"), "#dddddd");
+        assert_eq!(color("blue"), "#dddddd");
+    }
+}
@@ -0,0 +1,325 @@
+//! Loads [Block Kit Builder](https://app.slack.com/block-kit-builder) JSON as a template.
+//!
+//! String values may contain `{{placeholder}}`s, which are substituted when the template is
+//! rendered. The template is checked against the block types when it is loaded, so structural
+//! mistakes are reported before any values are supplied.
+//!
+//! ```
+//! use slack_rust::block::template::BlockTemplate;
+//! use std::collections::HashMap;
+//!
+//! let template = BlockTemplate::from_json(
+//!     r#"{"blocks": [{"type": "section", "text": {"type": "mrkdwn", "text": "Hello {{ name }}"}}]}"#,
+//! )
+//! .unwrap();
+//! assert_eq!(template.placeholders(), ["name"]);
+//!
+//! let mut values = HashMap::new();
+//! values.insert("name".to_string(), "world".to_string());
+//! let blocks = template.render_blocks(&values).unwrap();
+//! assert_eq!(blocks.len(), 1);
+//! ```
+
+use crate::block::blocks::Block;
+use crate::views::view::View;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum TemplateError {
+    /// The template is neither a list of blocks nor an object with a `blocks` list.
+    InvalidTemplate,
+    /// A `{{` is not closed, or the name between the braces is empty.
+    InvalidPlaceholder(String),
+    /// No value was given for a placeholder.
+    MissingValue(String),
+    SerdeJsonError(serde_json::Error),
+}
+
+impl From<serde_json::Error> for TemplateError {
+    fn from(err: serde_json::Error) -> TemplateError {
+        TemplateError::SerdeJsonError(err)
+    }
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            TemplateError::InvalidTemplate => write!(f, "Invalid Template Error"),
+            TemplateError::InvalidPlaceholder(ref s) => {
+                write!(f, "Invalid Placeholder Error: {}", s)
+            }
+            TemplateError::MissingValue(ref s) => write!(f, "Missing Value Error: {}", s),
+            TemplateError::SerdeJsonError(ref e) => write!(f, "Serde Json Error: {}", e),
+        }
+    }
+}
+
+/// Block Kit Builder JSON with `{{placeholder}}` substitution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockTemplate {
+    template: Value,
+    placeholders: Vec<String>,
+}
+
+impl BlockTemplate {
+    /// Loads a template from a list of blocks, a message (`{"blocks": [...]}`) or a view.
+    pub fn from_json(json: &str) -> Result<BlockTemplate, TemplateError> {
+        let template: Value = serde_json::from_str(json)?;
+        if blocks_value(&template).is_none() {
+            return Err(TemplateError::InvalidTemplate);
+        }
+
+        let mut placeholders = Vec::new();
+        collect_placeholders(&template, &mut placeholders)?;
+        let template = BlockTemplate {
+            template,
+            placeholders,
+        };
+
+        let empty: HashMap<String, String> = template
+            .placeholders
+            .iter()
+            .map(|name| (name.clone(), String::new()))
+            .collect();
+        template.render_blocks(&empty)?;
+        if template.template.get("type").is_some() {
+            template.render_view(&empty)?;
+        }
+
+        Ok(template)
+    }
+
+    /// Names of the placeholders in the template, in order of first appearance.
+    pub fn placeholders(&self) -> &[String] {
+        &self.placeholders
+    }
+
+    /// Substitutes `values` and returns the blocks of the template.
+    pub fn render_blocks(
+        &self,
+        values: &HashMap<String, String>,
+    ) -> Result<Vec<Block>, TemplateError> {
+        let blocks = blocks_value(&self.template).ok_or(TemplateError::InvalidTemplate)?;
+        Ok(serde_json::from_value(substitute(blocks, values)?)?)
+    }
+
+    /// Substitutes `values` and returns the template as a view.
+    ///
+    /// A template that is only a list of blocks becomes a view with just those blocks.
+    pub fn render_view(&self, values: &HashMap<String, String>) -> Result<View, TemplateError> {
+        match &self.template {
+            Value::Array(_) => Ok(View {
+                blocks: Some(self.render_blocks(values)?),
+                ..Default::default()
+            }),
+            template => Ok(serde_json::from_value(substitute(template, values)?)?),
+        }
+    }
+}
+
+fn blocks_value(template: &Value) -> Option<&Value> {
+    match template {
+        Value::Array(_) => Some(template),
+        Value::Object(object) => object.get("blocks").filter(|blocks| blocks.is_array()),
+        _ => None,
+    }
+}
+
+fn collect_placeholders(value: &Value, names: &mut Vec<String>) -> Result<(), TemplateError> {
+    match value {
+        Value::String(s) => {
+            replace_placeholders(s, |name| {
+                if !names.iter().any(|n| n == name) {
+                    names.push(name.to_string());
+                }
+                Ok(String::new())
+            })?;
+        }
+        Value::Array(values) => {
+            for value in values {
+                collect_placeholders(value, names)?;
+            }
+        }
+        Value::Object(object) => {
+            for value in object.values() {
+                collect_placeholders(value, names)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn substitute(value: &Value, values: &HashMap<String, String>) -> Result<Value, TemplateError> {
+    Ok(match value {
+        Value::String(s) => Value::String(replace_placeholders(s, |name| {
+            values
+                .get(name)
+                .cloned()
+                .ok_or_else(|| TemplateError::MissingValue(name.to_string()))
+        })?),
+        Value::Array(array) => Value::Array(
+            array
+                .iter()
+                .map(|value| substitute(value, values))
+                .collect::<Result<Vec<Value>, TemplateError>>()?,
+        ),
+        Value::Object(object) => Value::Object(
+            object
+                .iter()
+                .map(|(key, value)| Ok((key.clone(), substitute(value, values)?)))
+                .collect::<Result<serde_json::Map<String, Value>, TemplateError>>()?,
+        ),
+        value => value.clone(),
+    })
+}
+
+/// Replaces every `{{name}}` in `text` with the result of `replace(name)`.
+fn replace_placeholders<F>(text: &str, mut replace: F) -> Result<String, TemplateError>
+where
+    F: FnMut(&str) -> Result<String, TemplateError>,
+{
+    let mut replaced = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let end = rest[start..]
+            .find("}}")
+            .ok_or_else(|| TemplateError::InvalidPlaceholder(text.to_string()))?;
+        let name = rest[start + 2..start + end].trim();
+        if name.is_empty() {
+            return Err(TemplateError::InvalidPlaceholder(text.to_string()));
+        }
+        replaced.push_str(&rest[..start]);
+        replaced.push_str(&replace(name)?);
+        rest = &rest[start + end + 2..];
+    }
+    replaced.push_str(rest);
+    Ok(replaced)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::block::block_header::HeaderBlock;
+    use crate::block::block_object::{TextBlockObject, TextBlockType};
+    use crate::block::block_section::SectionBlock;
+    use crate::views::view::ViewType;
+
+    fn values(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn render_blocks() {
+        let json = r##"
+{
+  "blocks": [
+    {
+      "type": "header",
+      "text": {
+        "type": "plain_text",
+        "text": "{{title}}"
+      }
+    },
+    {
+      "type": "section",
+      "text": {
+        "type": "mrkdwn",
+        "text": "Hi {{ user }}, \"{{title}}\" is {{status}}"
+      }
+    }
+  ]
+}
+"##;
+        let template = BlockTemplate::from_json(json).unwrap();
+        assert_eq!(template.placeholders(), ["title", "user", "status"]);
+
+        let blocks = template
+            .render_blocks(&values(&[
+                ("title", "Deploy"),
+                ("user", "<@U1>"),
+                ("status", "done"),
+            ]))
+            .unwrap();
+        assert_eq!(
+            blocks,
+            vec![
+                Block::HeaderBlock(
+                    HeaderBlock::builder(
+                        TextBlockObject::builder(TextBlockType::PlainText, "Deploy".to_string())
+                            .build()
+                    )
+                    .build()
+                ),
+                Block::SectionBlock(
+                    SectionBlock::builder()
+                        .text(
+                            TextBlockObject::builder(
+                                TextBlockType::Mrkdwn,
+                                "Hi <@U1>, \"Deploy\" is done".to_string()
+                            )
+                            .build()
+                        )
+                        .build()
+                ),
+            ]
+        );
+
+        match template.render_blocks(&values(&[("title", "Deploy")])) {
+            Err(TemplateError::MissingValue(name)) => assert_eq!(name, "user"),
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn render_view() {
+        let json = r##"
+{
+  "type": "modal",
+  "title": {
+    "type": "plain_text",
+    "text": "{{title}}"
+  },
+  "blocks": [
+    {
+      "type": "divider"
+    }
+  ]
+}
+"##;
+        let view = BlockTemplate::from_json(json)
+            .unwrap()
+            .render_view(&values(&[("title", "App")]))
+            .unwrap();
+        assert_eq!(view.type_filed, Some(ViewType::Modal));
+        assert_eq!(view.title.unwrap().text, "App");
+        assert_eq!(view.blocks.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn load_errors() {
+        assert!(matches!(
+            BlockTemplate::from_json(r#"{"text": "no blocks"}"#),
+            Err(TemplateError::InvalidTemplate)
+        ));
+        assert!(matches!(
+            BlockTemplate::from_json(r#"[{"type": "divider", "block_id": "{{id"}]"#),
+            Err(TemplateError::InvalidPlaceholder(_))
+        ));
+        // Blocks this crate doesn't model are passed through to Slack as is.
+        let unknown = BlockTemplate::from_json(r#"[{"type": "no_such_block"}]"#).unwrap();
+        assert!(matches!(
+            unknown.render_blocks(&HashMap::new()).unwrap().as_slice(),
+            [Block::Unknown(_)]
+        ));
+        assert!(matches!(
+            BlockTemplate::from_json(r#"[{"type": "header"}]"#),
+            Err(TemplateError::SerdeJsonError(_))
+        ));
+    }
+}
@@ -0,0 +1,857 @@
+//! Validation of Block Kit payloads against the limits enforced by Slack.
+
+use crate::block::block_actions::ActionBlock;
+use crate::block::block_context::ContextBlock;
+use crate::block::block_elements::{
+    BlockElement, ButtonElement, CheckboxGroupsBlockElement, DatePickerBlockElement,
+    EmailTextInputBlockElement, ImageBlockElement, MixedElement, MultiSelectBlockElement,
+    NumberInputBlockElement, OverflowBlockElement, PlainTextInputBlockElement,
+    RadioButtonsBlockElement, RichTextInputBlockElement, SelectBlockElement,
+    TimePickerBlockElement, UrlTextInputBlockElement, WorkflowButtonElement,
+};
+use crate::block::block_header::HeaderBlock;
+use crate::block::block_image::ImageBlock;
+use crate::block::block_input::InputBlock;
+use crate::block::block_object::{
+    ConfirmationBlockObject, OptionBlockObject, OptionGroupBlockObject, TextBlockObject,
+    TextBlockType,
+};
+use crate::block::block_section::SectionBlock;
+use crate::block::block_video::VideoBlock;
+use crate::block::blocks::Block;
+use std::fmt;
+
+/// Maximum number of blocks in a message.
+pub const MAX_MESSAGE_BLOCKS: usize = 50;
+/// Maximum number of blocks in a modal or home tab view.
+pub const MAX_VIEW_BLOCKS: usize = 100;
+/// Maximum length of the text of a section block.
+pub const MAX_SECTION_TEXT_LENGTH: usize = 3000;
+/// Maximum length of the text of a header block.
+pub const MAX_HEADER_TEXT_LENGTH: usize = 150;
+/// Maximum length of the alt text of an image block.
+pub const MAX_IMAGE_ALT_TEXT_LENGTH: usize = 2000;
+
+const MAX_BLOCK_ID_LENGTH: usize = 255;
+const MAX_ACTION_ID_LENGTH: usize = 255;
+const MAX_SECTION_FIELDS: usize = 10;
+const MAX_SECTION_FIELD_LENGTH: usize = 2000;
+const MAX_ACTIONS_ELEMENTS: usize = 25;
+const MAX_CONTEXT_ELEMENTS: usize = 10;
+const MAX_INPUT_LABEL_LENGTH: usize = 2000;
+const MAX_INPUT_HINT_LENGTH: usize = 2000;
+const MAX_IMAGE_URL_LENGTH: usize = 3000;
+const MAX_IMAGE_TITLE_LENGTH: usize = 2000;
+const MAX_VIDEO_TITLE_LENGTH: usize = 200;
+const MAX_BUTTON_TEXT_LENGTH: usize = 75;
+const MAX_BUTTON_VALUE_LENGTH: usize = 2000;
+const MAX_URL_LENGTH: usize = 3000;
+const MAX_PLACEHOLDER_LENGTH: usize = 150;
+const MAX_SELECT_OPTIONS: usize = 100;
+const MAX_CHECKBOX_OPTIONS: usize = 10;
+const MAX_RADIO_OPTIONS: usize = 10;
+const MIN_OVERFLOW_OPTIONS: usize = 1;
+const MAX_OVERFLOW_OPTIONS: usize = 5;
+const MAX_PLAIN_TEXT_INPUT_LENGTH: i32 = 3000;
+const MAX_OPTION_TEXT_LENGTH: usize = 75;
+const MAX_OPTION_VALUE_LENGTH: usize = 150;
+const MAX_OPTION_DESCRIPTION_LENGTH: usize = 75;
+const MAX_OPTION_GROUP_LABEL_LENGTH: usize = 75;
+const MAX_CONFIRM_TITLE_LENGTH: usize = 100;
+const MAX_CONFIRM_TEXT_LENGTH: usize = 300;
+const MAX_CONFIRM_BUTTON_LENGTH: usize = 30;
+
+/// A single violation of a Block Kit limit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    /// JSON-pointer-like path to the offending value, e.g. `/blocks/0/text/text`.
+    pub path: String,
+    pub message: String,
+}
+
+impl ValidationError {
+    pub fn new(path: String, message: String) -> ValidationError {
+        ValidationError { path, message }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Checks a payload against the limits Slack enforces before it is sent.
+pub trait Validate {
+    /// Pushes every violation found under `path` into `errors`.
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>);
+
+    /// Returns every violation found, or `Ok(())` when the payload is valid.
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+        self.validate_at("", &mut errors);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Validates a list of blocks, including the maximum number of blocks allowed on the surface.
+pub fn validate_blocks(
+    blocks: &[Block],
+    max_blocks: usize,
+    path: &str,
+    errors: &mut Vec<ValidationError>,
+) {
+    if blocks.len() > max_blocks {
+        errors.push(ValidationError::new(
+            path.to_string(),
+            format!(
+                "must contain at most {} blocks, got {}",
+                max_blocks,
+                blocks.len()
+            ),
+        ));
+    }
+    for (i, block) in blocks.iter().enumerate() {
+        block.validate_at(&format!("{}/{}", path, i), errors);
+    }
+}
+
+/// Checks that `value` is at most `max` characters long.
+pub fn check_max_length(value: &str, max: usize, path: &str, errors: &mut Vec<ValidationError>) {
+    let length = value.chars().count();
+    if length > max {
+        errors.push(ValidationError::new(
+            path.to_string(),
+            format!("must be at most {} characters, got {}", max, length),
+        ));
+    }
+}
+
+fn check_max_items<T>(items: &[T], max: usize, path: &str, errors: &mut Vec<ValidationError>) {
+    if items.len() > max {
+        errors.push(ValidationError::new(
+            path.to_string(),
+            format!("must contain at most {} items, got {}", max, items.len()),
+        ));
+    }
+}
+
+fn check_text(
+    text: &TextBlockObject,
+    max: usize,
+    plain_text_only: bool,
+    path: &str,
+    errors: &mut Vec<ValidationError>,
+) {
+    if plain_text_only && text.type_filed != TextBlockType::PlainText {
+        errors.push(ValidationError::new(
+            format!("{}/type", path),
+            "must be plain_text".to_string(),
+        ));
+    }
+    if text.text.is_empty() {
+        errors.push(ValidationError::new(
+            format!("{}/text", path),
+            "must not be empty".to_string(),
+        ));
+    }
+    check_max_length(&text.text, max, &format!("{}/text", path), errors);
+}
+
+fn check_block_id(block_id: &Option<String>, path: &str, errors: &mut Vec<ValidationError>) {
+    if let Some(block_id) = block_id {
+        check_max_length(
+            block_id,
+            MAX_BLOCK_ID_LENGTH,
+            &format!("{}/block_id", path),
+            errors,
+        );
+    }
+}
+
+fn check_action_id(action_id: &str, path: &str, errors: &mut Vec<ValidationError>) {
+    check_max_length(
+        action_id,
+        MAX_ACTION_ID_LENGTH,
+        &format!("{}/action_id", path),
+        errors,
+    );
+}
+
+fn check_placeholder(
+    placeholder: Option<&TextBlockObject>,
+    path: &str,
+    errors: &mut Vec<ValidationError>,
+) {
+    if let Some(placeholder) = placeholder {
+        check_text(
+            placeholder,
+            MAX_PLACEHOLDER_LENGTH,
+            true,
+            &format!("{}/placeholder", path),
+            errors,
+        );
+    }
+}
+
+fn check_confirm(
+    confirm: &Option<ConfirmationBlockObject>,
+    path: &str,
+    errors: &mut Vec<ValidationError>,
+) {
+    if let Some(confirm) = confirm {
+        confirm.validate_at(&format!("{}/confirm", path), errors);
+    }
+}
+
+fn check_options(
+    options: &[OptionBlockObject],
+    max: usize,
+    path: &str,
+    errors: &mut Vec<ValidationError>,
+) {
+    check_max_items(options, max, path, errors);
+    for (i, option) in options.iter().enumerate() {
+        option.validate_at(&format!("{}/{}", path, i), errors);
+    }
+}
+
+impl Validate for Block {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        match self {
+            Block::ActionBlock(block) => block.validate_at(path, errors),
+            Block::ContextBlock(block) => block.validate_at(path, errors),
+            Block::DividerBlock(block) => check_block_id(&block.block_id, path, errors),
+            Block::FileBlock(block) => check_block_id(&block.block_id, path, errors),
+            Block::HeaderBlock(block) => block.validate_at(path, errors),
+            Block::ImageBlock(block) => block.validate_at(path, errors),
+            Block::InputBlock(block) => block.validate_at(path, errors),
+            Block::RichTextBlock(block) => check_block_id(&block.block_id, path, errors),
+            Block::SectionBlock(block) => block.validate_at(path, errors),
+            Block::VideoBlock(block) => block.validate_at(path, errors),
+            Block::None => errors.push(ValidationError::new(
+                path.to_string(),
+                "must not be an empty block".to_string(),
+            )),
+            // Slack checks blocks this crate doesn't model.
+            Block::Unknown(_) => {}
+        }
+    }
+}
+
+impl Validate for ActionBlock {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_block_id(&self.block_id, path, errors);
+        let elements_path = format!("{}/elements", path);
+        check_max_items(&self.elements, MAX_ACTIONS_ELEMENTS, &elements_path, errors);
+        for (i, element) in self.elements.iter().enumerate() {
+            element.validate_at(&format!("{}/{}", elements_path, i), errors);
+        }
+    }
+}
+
+impl Validate for ContextBlock {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_block_id(&self.block_id, path, errors);
+        let elements_path = format!("{}/elements", path);
+        check_max_items(&self.elements, MAX_CONTEXT_ELEMENTS, &elements_path, errors);
+        for (i, element) in self.elements.iter().enumerate() {
+            let element_path = format!("{}/{}", elements_path, i);
+            match element {
+                MixedElement::ImageBlockElement(image) => image.validate_at(&element_path, errors),
+                MixedElement::PlainTextBlockObject { text, .. }
+                | MixedElement::MarkdownBlockObject { text, .. } => {
+                    if text.is_empty() {
+                        errors.push(ValidationError::new(
+                            format!("{}/text", element_path),
+                            "must not be empty".to_string(),
+                        ));
+                    }
+                }
+                MixedElement::None => errors.push(ValidationError::new(
+                    element_path,
+                    "must not be an empty element".to_string(),
+                )),
+            }
+        }
+    }
+}
+
+impl Validate for HeaderBlock {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_block_id(&self.block_id, path, errors);
+        check_text(
+            &self.text,
+            MAX_HEADER_TEXT_LENGTH,
+            true,
+            &format!("{}/text", path),
+            errors,
+        );
+    }
+}
+
+impl Validate for ImageBlock {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_block_id(&self.block_id, path, errors);
+        check_max_length(
+            &self.image_url,
+            MAX_IMAGE_URL_LENGTH,
+            &format!("{}/image_url", path),
+            errors,
+        );
+        check_max_length(
+            &self.alt_text,
+            MAX_IMAGE_ALT_TEXT_LENGTH,
+            &format!("{}/alt_text", path),
+            errors,
+        );
+        if let Some(title) = &self.title {
+            check_text(
+                title,
+                MAX_IMAGE_TITLE_LENGTH,
+                true,
+                &format!("{}/title", path),
+                errors,
+            );
+        }
+    }
+}
+
+impl Validate for InputBlock {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_block_id(&self.block_id, path, errors);
+        check_text(
+            &self.label,
+            MAX_INPUT_LABEL_LENGTH,
+            true,
+            &format!("{}/label", path),
+            errors,
+        );
+        if let Some(hint) = &self.hint {
+            check_text(
+                hint,
+                MAX_INPUT_HINT_LENGTH,
+                true,
+                &format!("{}/hint", path),
+                errors,
+            );
+        }
+        self.element
+            .validate_at(&format!("{}/element", path), errors);
+    }
+}
+
+impl Validate for SectionBlock {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_block_id(&self.block_id, path, errors);
+        if self.text.is_none() && self.fields.is_none() {
+            errors.push(ValidationError::new(
+                path.to_string(),
+                "must have either text or fields".to_string(),
+            ));
+        }
+        if let Some(text) = &self.text {
+            check_text(
+                text,
+                MAX_SECTION_TEXT_LENGTH,
+                false,
+                &format!("{}/text", path),
+                errors,
+            );
+        }
+        if let Some(fields) = &self.fields {
+            let fields_path = format!("{}/fields", path);
+            check_max_items(fields, MAX_SECTION_FIELDS, &fields_path, errors);
+            for (i, field) in fields.iter().enumerate() {
+                check_text(
+                    field,
+                    MAX_SECTION_FIELD_LENGTH,
+                    false,
+                    &format!("{}/{}", fields_path, i),
+                    errors,
+                );
+            }
+        }
+        if let Some(accessory) = &self.accessory {
+            accessory.validate_at(&format!("{}/accessory", path), errors);
+        }
+    }
+}
+
+impl Validate for VideoBlock {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_block_id(&self.block_id, path, errors);
+        check_text(
+            &self.title,
+            MAX_VIDEO_TITLE_LENGTH,
+            true,
+            &format!("{}/title", path),
+            errors,
+        );
+        check_max_length(
+            &self.thumbnail_url,
+            MAX_URL_LENGTH,
+            &format!("{}/thumbnail_url", path),
+            errors,
+        );
+        check_max_length(
+            &self.video_url,
+            MAX_URL_LENGTH,
+            &format!("{}/video_url", path),
+            errors,
+        );
+    }
+}
+
+impl Validate for BlockElement {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        match self {
+            BlockElement::ButtonElement(element) => element.validate_at(path, errors),
+            BlockElement::CheckboxGroupsBlockElement(element) => element.validate_at(path, errors),
+            BlockElement::DatePickerBlockElement(element) => element.validate_at(path, errors),
+            BlockElement::DateTimePickerBlockElement(element) => {
+                check_action_id(&element.action_id, path, errors);
+                check_confirm(&element.confirm, path, errors);
+            }
+            BlockElement::EmailTextInputBlockElement(element) => element.validate_at(path, errors),
+            BlockElement::FileInputBlockElement(element) => {
+                check_action_id(&element.action_id, path, errors)
+            }
+            BlockElement::ImageBlockElement(element) => element.validate_at(path, errors),
+            BlockElement::MultiSelectBlockElement(element) => element.validate_at(path, errors),
+            BlockElement::NumberInputBlockElement(element) => element.validate_at(path, errors),
+            BlockElement::OverflowBlockElement(element) => element.validate_at(path, errors),
+            BlockElement::PlainTextInputBlockElement(element) => element.validate_at(path, errors),
+            BlockElement::RadioButtonsBlockElement(element) => element.validate_at(path, errors),
+            BlockElement::RichTextInputBlockElement(element) => element.validate_at(path, errors),
+            BlockElement::SelectBlockElement(element) => element.validate_at(path, errors),
+            BlockElement::TimePickerBlockElement(element) => element.validate_at(path, errors),
+            BlockElement::UrlTextInputBlockElement(element) => element.validate_at(path, errors),
+            BlockElement::WorkflowButtonElement(element) => element.validate_at(path, errors),
+            BlockElement::None => errors.push(ValidationError::new(
+                path.to_string(),
+                "must not be an empty element".to_string(),
+            )),
+            // Slack checks elements this crate doesn't model.
+            BlockElement::Unknown(_) => {}
+        }
+    }
+}
+
+impl Validate for ButtonElement {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_action_id(&self.action_id, path, errors);
+        check_text(
+            &self.text,
+            MAX_BUTTON_TEXT_LENGTH,
+            true,
+            &format!("{}/text", path),
+            errors,
+        );
+        if let Some(url) = &self.url {
+            check_max_length(url, MAX_URL_LENGTH, &format!("{}/url", path), errors);
+        }
+        if let Some(value) = &self.value {
+            check_max_length(
+                value,
+                MAX_BUTTON_VALUE_LENGTH,
+                &format!("{}/value", path),
+                errors,
+            );
+        }
+        check_confirm(&self.confirm, path, errors);
+    }
+}
+
+impl Validate for CheckboxGroupsBlockElement {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_action_id(&self.action_id, path, errors);
+        check_options(
+            &self.options,
+            MAX_CHECKBOX_OPTIONS,
+            &format!("{}/options", path),
+            errors,
+        );
+        check_confirm(&self.confirm, path, errors);
+    }
+}
+
+impl Validate for DatePickerBlockElement {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_action_id(&self.action_id, path, errors);
+        check_placeholder(self.placeholder.as_ref(), path, errors);
+        check_confirm(&self.confirm, path, errors);
+    }
+}
+
+impl Validate for EmailTextInputBlockElement {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_action_id(&self.action_id, path, errors);
+        check_placeholder(self.placeholder.as_ref(), path, errors);
+    }
+}
+
+impl Validate for ImageBlockElement {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_max_length(
+            &self.image_url,
+            MAX_IMAGE_URL_LENGTH,
+            &format!("{}/image_url", path),
+            errors,
+        );
+        check_max_length(
+            &self.alt_text,
+            MAX_IMAGE_ALT_TEXT_LENGTH,
+            &format!("{}/alt_text", path),
+            errors,
+        );
+    }
+}
+
+impl Validate for MultiSelectBlockElement {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_action_id(&self.action_id, path, errors);
+        check_placeholder(Some(&self.placeholder), path, errors);
+        check_options(
+            &self.options,
+            MAX_SELECT_OPTIONS,
+            &format!("{}/options", path),
+            errors,
+        );
+        check_option_groups(&self.option_groups, path, errors);
+        check_confirm(&self.confirm, path, errors);
+    }
+}
+
+impl Validate for NumberInputBlockElement {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_action_id(&self.action_id, path, errors);
+        check_placeholder(self.placeholder.as_ref(), path, errors);
+    }
+}
+
+impl Validate for OverflowBlockElement {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_action_id(&self.action_id, path, errors);
+        let options_path = format!("{}/options", path);
+        if self.options.len() < MIN_OVERFLOW_OPTIONS {
+            errors.push(ValidationError::new(
+                options_path.clone(),
+                format!(
+                    "must contain at least {} items, got {}",
+                    MIN_OVERFLOW_OPTIONS,
+                    self.options.len()
+                ),
+            ));
+        }
+        check_options(&self.options, MAX_OVERFLOW_OPTIONS, &options_path, errors);
+        check_confirm(&self.confirm, path, errors);
+    }
+}
+
+impl Validate for PlainTextInputBlockElement {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_action_id(&self.action_id, path, errors);
+        check_placeholder(self.placeholder.as_ref(), path, errors);
+        if let Some(min_length) = self.min_length {
+            if !(0..=MAX_PLAIN_TEXT_INPUT_LENGTH).contains(&min_length) {
+                errors.push(ValidationError::new(
+                    format!("{}/min_length", path),
+                    format!(
+                        "must be between 0 and {}, got {}",
+                        MAX_PLAIN_TEXT_INPUT_LENGTH, min_length
+                    ),
+                ));
+            }
+        }
+        if let Some(max_length) = self.max_length {
+            if !(1..=MAX_PLAIN_TEXT_INPUT_LENGTH).contains(&max_length) {
+                errors.push(ValidationError::new(
+                    format!("{}/max_length", path),
+                    format!(
+                        "must be between 1 and {}, got {}",
+                        MAX_PLAIN_TEXT_INPUT_LENGTH, max_length
+                    ),
+                ));
+            }
+        }
+    }
+}
+
+impl Validate for RadioButtonsBlockElement {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_action_id(&self.action_id, path, errors);
+        check_options(
+            &self.options,
+            MAX_RADIO_OPTIONS,
+            &format!("{}/options", path),
+            errors,
+        );
+        check_confirm(&self.confirm, path, errors);
+    }
+}
+
+impl Validate for RichTextInputBlockElement {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_action_id(&self.action_id, path, errors);
+        check_placeholder(self.placeholder.as_ref(), path, errors);
+    }
+}
+
+impl Validate for SelectBlockElement {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_action_id(&self.action_id, path, errors);
+        check_placeholder(Some(&self.placeholder), path, errors);
+        check_options(
+            &self.options,
+            MAX_SELECT_OPTIONS,
+            &format!("{}/options", path),
+            errors,
+        );
+        check_option_groups(&self.option_groups, path, errors);
+        check_confirm(&self.confirm, path, errors);
+    }
+}
+
+impl Validate for TimePickerBlockElement {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_action_id(&self.action_id, path, errors);
+        check_placeholder(self.placeholder.as_ref(), path, errors);
+        check_confirm(&self.confirm, path, errors);
+    }
+}
+
+impl Validate for UrlTextInputBlockElement {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_action_id(&self.action_id, path, errors);
+        check_placeholder(self.placeholder.as_ref(), path, errors);
+    }
+}
+
+impl Validate for WorkflowButtonElement {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_action_id(&self.action_id, path, errors);
+        check_text(
+            &self.text,
+            MAX_BUTTON_TEXT_LENGTH,
+            true,
+            &format!("{}/text", path),
+            errors,
+        );
+        check_max_length(
+            &self.workflow.trigger.url,
+            MAX_URL_LENGTH,
+            &format!("{}/workflow/trigger/url", path),
+            errors,
+        );
+    }
+}
+
+impl Validate for OptionBlockObject {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_text(
+            &self.text,
+            MAX_OPTION_TEXT_LENGTH,
+            false,
+            &format!("{}/text", path),
+            errors,
+        );
+        if let Some(value) = &self.value {
+            check_max_length(
+                value,
+                MAX_OPTION_VALUE_LENGTH,
+                &format!("{}/value", path),
+                errors,
+            );
+        }
+        if let Some(description) = &self.description {
+            check_text(
+                description,
+                MAX_OPTION_DESCRIPTION_LENGTH,
+                false,
+                &format!("{}/description", path),
+                errors,
+            );
+        }
+        if let Some(url) = &self.url {
+            check_max_length(url, MAX_URL_LENGTH, &format!("{}/url", path), errors);
+        }
+    }
+}
+
+impl Validate for OptionGroupBlockObject {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if let Some(label) = &self.label {
+            check_text(
+                label,
+                MAX_OPTION_GROUP_LABEL_LENGTH,
+                true,
+                &format!("{}/label", path),
+                errors,
+            );
+        }
+        if let Some(options) = &self.options {
+            check_options(
+                options,
+                MAX_SELECT_OPTIONS,
+                &format!("{}/options", path),
+                errors,
+            );
+        }
+    }
+}
+
+impl Validate for ConfirmationBlockObject {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        check_text(
+            &self.title,
+            MAX_CONFIRM_TITLE_LENGTH,
+            true,
+            &format!("{}/title", path),
+            errors,
+        );
+        check_text(
+            &self.text,
+            MAX_CONFIRM_TEXT_LENGTH,
+            false,
+            &format!("{}/text", path),
+            errors,
+        );
+        check_text(
+            &self.confirm,
+            MAX_CONFIRM_BUTTON_LENGTH,
+            true,
+            &format!("{}/confirm", path),
+            errors,
+        );
+        check_text(
+            &self.deny,
+            MAX_CONFIRM_BUTTON_LENGTH,
+            true,
+            &format!("{}/deny", path),
+            errors,
+        );
+    }
+}
+
+fn check_option_groups(
+    option_groups: &Option<Vec<OptionGroupBlockObject>>,
+    path: &str,
+    errors: &mut Vec<ValidationError>,
+) {
+    if let Some(option_groups) = option_groups {
+        let groups_path = format!("{}/option_groups", path);
+        check_max_items(option_groups, MAX_SELECT_OPTIONS, &groups_path, errors);
+        for (i, group) in option_groups.iter().enumerate() {
+            group.validate_at(&format!("{}/{}", groups_path, i), errors);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::block::block_elements::{ButtonElement, OverflowBlockElement};
+
+    fn plain_text(text: &str) -> TextBlockObject {
+        TextBlockObject::builder(TextBlockType::PlainText, text.to_string()).build()
+    }
+
+    #[test]
+    fn validate_valid_blocks() {
+        let block = Block::SectionBlock(
+            SectionBlock::builder()
+                .text(
+                    TextBlockObject::builder(TextBlockType::Mrkdwn, "*Hello*".to_string()).build(),
+                )
+                .accessory(BlockElement::ButtonElement(
+                    ButtonElement::builder(plain_text("Click"), "click".to_string()).build(),
+                ))
+                .build(),
+        );
+
+        assert_eq!(block.validate(), Ok(()));
+    }
+
+    #[test]
+    fn validate_collects_every_violation() {
+        let block = Block::ActionBlock(ActionBlock {
+            elements: vec![
+                BlockElement::ButtonElement(
+                    ButtonElement::builder(plain_text(&"a".repeat(76)), "b".repeat(256)).build(),
+                ),
+                BlockElement::OverflowBlockElement(
+                    OverflowBlockElement::builder("overflow".to_string(), vec![]).build(),
+                ),
+            ],
+            block_id: Some("actions".to_string()),
+        });
+
+        let errors = block.validate().unwrap_err();
+        let paths: Vec<&str> = errors.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "/elements/0/action_id",
+                "/elements/0/text/text",
+                "/elements/1/options"
+            ]
+        );
+        assert_eq!(
+            errors[1].to_string(),
+            "/elements/0/text/text: must be at most 75 characters, got 76"
+        );
+    }
+
+    #[test]
+    fn validate_section_limits() {
+        let block = Block::SectionBlock(SectionBlock {
+            fields: Some((0..11).map(|i| plain_text(&i.to_string())).collect()),
+            text: Some(plain_text(&"a".repeat(3001))),
+            ..Default::default()
+        });
+
+        let errors = block.validate().unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].path, "/text/text");
+        assert_eq!(errors[1].path, "/fields");
+    }
+
+    #[test]
+    fn validate_header_requires_plain_text() {
+        let block = Block::HeaderBlock(HeaderBlock {
+            text: TextBlockObject::builder(TextBlockType::Mrkdwn, "*Header*".to_string()).build(),
+            ..Default::default()
+        });
+
+        let errors = block.validate().unwrap_err();
+        assert_eq!(
+            errors,
+            vec![ValidationError::new(
+                "/text/type".to_string(),
+                "must be plain_text".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn validate_blocks_count() {
+        let blocks: Vec<Block> = (0..51)
+            .map(|_| Block::DividerBlock(Default::default()))
+            .collect();
+        let mut errors = Vec::new();
+        validate_blocks(&blocks, MAX_MESSAGE_BLOCKS, "/blocks", &mut errors);
+
+        assert_eq!(
+            errors,
+            vec![ValidationError::new(
+                "/blocks".to_string(),
+                "must contain at most 50 blocks, got 51".to_string()
+            )]
+        );
+    }
+}
@@ -1,15 +1,18 @@
 use crate::attachment::attachment::Attachment;
+use crate::id::{BotId, ChannelId, UserId};
+use crate::ts::Ts;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use std::collections::HashMap;
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct Channel {
-    pub id: Option<String>,
+    pub id: Option<ChannelId>,
     pub name: Option<String>,
     pub is_channel: Option<bool>,
-    pub created: Option<i32>,
-    pub creator: Option<String>,
+    pub created: Option<i64>,
+    pub creator: Option<UserId>,
     pub is_archived: Option<bool>,
     pub is_general: Option<bool>,
     pub name_normalized: Option<String>,
@@ -18,7 +21,7 @@ pub struct Channel {
     pub is_member: Option<bool>,
     pub is_private: Option<bool>,
     pub is_mpim: Option<bool>,
-    pub last_read: Option<String>,
+    pub last_read: Option<Ts>,
     pub latest: Option<Latest>,
     pub unread_count: Option<i32>,
     pub unread_count_display: Option<i32>,
@@ -27,6 +30,8 @@ pub struct Channel {
     pub purpose: Option<Purpose>,
     pub previous_names: Option<Vec<String>>,
     pub priority: Option<i32>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 #[skip_serializing_none]
@@ -34,26 +39,26 @@ pub struct Channel {
 pub struct Latest {
     pub text: Option<String>,
     pub username: Option<String>,
-    pub bot_id: Option<String>,
+    pub bot_id: Option<BotId>,
     pub attachments: Option<Vec<Attachment>>,
     #[serde(rename = "type")]
     pub type_filed: Option<String>,
     pub subtype: Option<String>,
-    pub ts: Option<String>,
+    pub ts: Option<Ts>,
 }
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct Topic {
     pub value: Option<String>,
-    pub creator: Option<String>,
-    pub last_set: Option<i32>,
+    pub creator: Option<UserId>,
+    pub last_set: Option<i64>,
 }
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct Purpose {
     pub value: Option<String>,
-    pub creator: Option<String>,
-    pub last_set: Option<i32>,
+    pub creator: Option<UserId>,
+    pub last_set: Option<i64>,
 }
@@ -1,15 +1,17 @@
 //! Deletes a message.  
 
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::ChannelId;
+use crate::ts::Ts;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct DeleteRequest {
-    pub channel: String,
-    pub ts: String,
+    pub channel: ChannelId,
+    pub ts: Ts,
     pub as_user: Option<bool>,
 }
 
@@ -19,8 +21,8 @@ pub struct DeleteResponse {
     pub ok: bool,
     pub error: Option<String>,
     pub response_metadata: Option<ResponseMetadata>,
-    pub channel: Option<String>,
-    pub ts: Option<String>,
+    pub channel: Option<ChannelId>,
+    pub ts: Option<Ts>,
 }
 
 /// Deletes a message.
@@ -33,11 +35,10 @@ pub async fn delete<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("chat.delete");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("chat.delete", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<DeleteResponse>(&result).map_err(Error::SerdeJsonError)
@@ -52,8 +53,8 @@ mod test {
     #[test]
     fn convert_request() {
         let request = DeleteRequest {
-            channel: "C1234567890".to_string(),
-            ts: "1405894322.002768".to_string(),
+            channel: "C1234567890".into(),
+            ts: "1405894322.002768".into(),
             as_user: Some(true),
         };
         let json = r##"{
@@ -73,8 +74,8 @@ mod test {
     fn convert_response() {
         let response = DeleteResponse {
             ok: true,
-            channel: Some("C1234567890".to_string()),
-            ts: Some("1405894322.002768".to_string()),
+            channel: Some("C1234567890".into()),
+            ts: Some("1405894322.002768".into()),
             ..Default::default()
         };
         let json = r##"{
@@ -93,13 +94,13 @@ mod test {
     #[async_std::test]
     async fn test_delete() {
         let param = DeleteRequest {
-            channel: "C1234567890".to_string(),
-            ts: "1405894322.002768".to_string(),
+            channel: "C1234567890".into(),
+            ts: "1405894322.002768".into(),
             as_user: Some(true),
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "channel": "C1234567890",
@@ -113,8 +114,8 @@ mod test {
             .unwrap();
         let expect = DeleteResponse {
             ok: true,
-            channel: Some("C1234567890".to_string()),
-            ts: Some("1405894322.002768".to_string()),
+            channel: Some("C1234567890".into()),
+            ts: Some("1405894322.002768".into()),
             ..Default::default()
         };
 
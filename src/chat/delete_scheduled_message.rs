@@ -1,14 +1,15 @@
 //! Deletes a pending scheduled message from the queue.
 
 use crate::error::Error;
-use crate::http_client::{get_slack_url, DefaultResponse, SlackWebAPIClient};
+use crate::http_client::{DefaultResponse, SlackWebAPIClient};
+use crate::id::ChannelId;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct DeleteScheduledMessageRequest {
-    pub channel: String,
+    pub channel: ChannelId,
     pub scheduled_message_id: String,
     pub as_user: Option<bool>,
 }
@@ -23,11 +24,10 @@ pub async fn delete_scheduled_message<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("chat.deleteScheduledMessage");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("chat.deleteScheduledMessage", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<DefaultResponse>(&result).map_err(Error::SerdeJsonError)
@@ -42,7 +42,7 @@ mod test {
     #[test]
     fn convert_request() {
         let request = DeleteScheduledMessageRequest {
-            channel: "C123456789".to_string(),
+            channel: "C123456789".into(),
             scheduled_message_id: "Q1234ABCD".to_string(),
             as_user: Some(true),
         };
@@ -62,13 +62,13 @@ mod test {
     #[async_std::test]
     async fn test_delete_scheduled_message() {
         let param = DeleteScheduledMessageRequest {
-            channel: "C123456789".to_string(),
+            channel: "C123456789".into(),
             scheduled_message_id: "Q1234ABCD".to_string(),
             as_user: Some(true),
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true
 }"##
@@ -1,14 +1,16 @@
 //! Retrieve a permalink URL for a specific extant message.  
 
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::ChannelId;
+use crate::ts::Ts;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct GetPermalinkRequest {
-    pub channel: String,
-    pub message_ts: String,
+    pub channel: ChannelId,
+    pub message_ts: Ts,
 }
 
 #[skip_serializing_none]
@@ -30,11 +32,10 @@ pub async fn get_permalink<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("chat.getPermalink");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("chat.getPermalink", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<GetPermalinkResponse>(&result).map_err(Error::SerdeJsonError)
@@ -49,8 +50,8 @@ mod test {
     #[test]
     fn convert_request() {
         let request = GetPermalinkRequest {
-            channel: "53072".to_string(),
-            message_ts: "1234567890.123456".to_string(),
+            channel: "53072".into(),
+            message_ts: "1234567890.123456".into(),
         };
         let json = r##"{
   "channel": "53072",
@@ -88,12 +89,12 @@ mod test {
     #[async_std::test]
     async fn test_get_permalink() {
         let param = GetPermalinkRequest {
-            channel: "53072".to_string(),
-            message_ts: "1234567890.123456".to_string(),
+            channel: "53072".into(),
+            message_ts: "1234567890.123456".into(),
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "permalink": "https://ghostbusters.slack.com/archives/C1H9RESGA/p135854651500008"
@@ -1,13 +1,15 @@
 //! Share a me message into a channel.  
 
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::ChannelId;
+use crate::ts::Ts;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct MeMessageRequest {
-    pub channel: String,
+    pub channel: ChannelId,
     pub text: String,
 }
 
@@ -17,8 +19,8 @@ pub struct MeMessageResponse {
     pub ok: bool,
     pub error: Option<String>,
     pub response_metadata: Option<ResponseMetadata>,
-    pub channel: Option<String>,
-    pub ts: Option<String>,
+    pub channel: Option<ChannelId>,
+    pub ts: Option<Ts>,
 }
 
 /// Share a me message into a channel.  
@@ -31,11 +33,10 @@ pub async fn me_message<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("chat.meMessage");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("chat.meMessage", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<MeMessageResponse>(&result).map_err(Error::SerdeJsonError)
@@ -50,7 +51,7 @@ mod test {
     #[test]
     fn convert_request() {
         let request = MeMessageRequest {
-            channel: "C1234567890".to_string(),
+            channel: "C1234567890".into(),
             text: "Hello world".to_string(),
         };
         let json = r##"{
@@ -69,8 +70,8 @@ mod test {
     fn convert_response() {
         let response = MeMessageResponse {
             ok: true,
-            channel: Some("C024BE7LR".to_string()),
-            ts: Some("1417671948.000006".to_string()),
+            channel: Some("C024BE7LR".into()),
+            ts: Some("1417671948.000006".into()),
             ..Default::default()
         };
         let json = r##"{
@@ -89,12 +90,12 @@ mod test {
     #[async_std::test]
     async fn test_me_message() {
         let param = MeMessageRequest {
-            channel: "C1234567890".to_string(),
+            channel: "C1234567890".into(),
             text: "Hello world".to_string(),
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "channel": "C1234567890",
@@ -108,8 +109,8 @@ mod test {
             .unwrap();
         let expect = MeMessageResponse {
             ok: true,
-            channel: Some("C1234567890".to_string()),
-            ts: Some("1417671948.000006".to_string()),
+            channel: Some("C1234567890".into()),
+            ts: Some("1417671948.000006".into()),
             ..Default::default()
         };
 
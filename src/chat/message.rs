@@ -1,24 +1,174 @@
 //! Slack Message Object.
 
 use crate::attachment::attachment::Attachment;
+#[cfg(feature = "block-kit")]
 use crate::block::blocks::Block;
+use crate::files::file::File;
+use crate::id::{AppId, BotId, TeamId, UserId};
 use crate::reactions::reaction::Reaction;
-use serde::{Deserialize, Serialize};
+use crate::ts::Ts;
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_with::skip_serializing_none;
+use std::collections::HashMap;
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+#[serde(remote = "Self")]
 pub struct Message {
-    pub bot_id: Option<String>,
+    pub bot_id: Option<BotId>,
     #[serde(rename = "type")]
     pub type_file: Option<String>,
     pub text: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<UserId>,
     pub username: Option<String>,
-    pub ts: Option<String>,
-    pub team: Option<String>,
+    pub ts: Option<Ts>,
+    pub thread_ts: Option<Ts>,
+    pub team: Option<TeamId>,
+    pub edited: Option<Edited>,
     pub attachments: Option<Vec<Attachment>>,
+    #[cfg(feature = "block-kit")]
     pub blocks: Option<Vec<Block>>,
-    pub subtype: Option<String>,
+    /// `None` for plain messages.
+    #[serde(flatten, serialize_with = "serialize_subtype", skip_deserializing)]
+    pub subtype: Option<MessageSubtype>,
     pub reactions: Option<Vec<Reaction>>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl<'de> Deserialize<'de> for Message {
+    fn deserialize<D>(deserializer: D) -> Result<Message, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut message = Message::deserialize(deserializer)?;
+        // The subtype's fields land in `extra`; only drop them once the typed subtype has parsed.
+        let name = match message.extra.get("subtype").and_then(|v| v.as_str()) {
+            Some(name) => name.to_string(),
+            None => return Ok(message),
+        };
+        let fields = serde_json::Value::Object(message.extra.clone().into_iter().collect());
+        let subtype = match MessageSubtype::deserialize(fields) {
+            Ok(subtype) => subtype,
+            Err(e) => {
+                if MESSAGE_SUBTYPES.contains(&name.as_str()) {
+                    log::warn!("failed to parse message subtype {}: {}", name, e);
+                }
+                MessageSubtype::Other(name)
+            }
+        };
+        let consumed = match &subtype {
+            MessageSubtype::Other(_) => vec!["subtype".to_string()],
+            subtype => match serde_json::to_value(subtype) {
+                Ok(serde_json::Value::Object(fields)) => {
+                    fields.into_iter().map(|(k, _)| k).collect()
+                }
+                _ => vec![],
+            },
+        };
+        for name in consumed {
+            message.extra.remove(&name);
+        }
+        message.subtype = Some(subtype);
+        Ok(message)
+    }
+}
+
+fn serialize_subtype<S>(subtype: &Option<MessageSubtype>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match subtype {
+        Some(MessageSubtype::Other(name)) => {
+            let mut map = serializer.serialize_map(Some(1))?;
+            map.serialize_entry("subtype", name)?;
+            map.end()
+        }
+        subtype => subtype.serialize(serializer),
+    }
+}
+
+impl Serialize for Message {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Message::serialize(self, serializer)
+    }
+}
+
+/// The `subtype` tags of the [`MessageSubtype`] variants other than [`MessageSubtype::Other`].
+pub(crate) const MESSAGE_SUBTYPES: &[&str] = &[
+    "bot_message",
+    "channel_join",
+    "channel_leave",
+    "file_share",
+    "message_changed",
+    "message_deleted",
+    "message_replied",
+    "thread_broadcast",
+];
+
+/// See: <https://api.slack.com/events/message#subtypes>
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, PartialEq)]
+#[serde(rename_all = "snake_case", tag = "subtype")]
+pub enum MessageSubtype {
+    /// A message was posted by an integration
+    BotMessage {
+        bot_profile: Option<BotProfile>,
+        icons: Option<HashMap<String, String>>,
+    },
+    /// A member joined a channel
+    ChannelJoin { inviter: Option<String> },
+    /// A member left a channel
+    ChannelLeave,
+    /// A file was shared into a channel
+    FileShare {
+        files: Option<Vec<File>>,
+        upload: Option<bool>,
+    },
+    /// A message was changed
+    MessageChanged {
+        message: Box<Message>,
+        previous_message: Option<Box<Message>>,
+        hidden: Option<bool>,
+    },
+    /// A message was deleted
+    MessageDeleted {
+        deleted_ts: Ts,
+        previous_message: Option<Box<Message>>,
+        hidden: Option<bool>,
+    },
+    /// A message thread received a reply
+    MessageReplied {
+        message: Box<Message>,
+        hidden: Option<bool>,
+    },
+    /// A message thread's reply was broadcast to a channel
+    ThreadBroadcast { root: Option<Box<Message>> },
+    /// A subtype not modelled above, or one whose payload could not be parsed.
+    /// Its fields are kept in [`Message::extra`].
+    #[serde(skip)]
+    Other(String),
+}
+
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct Edited {
+    pub user: Option<UserId>,
+    pub ts: Option<Ts>,
+}
+
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct BotProfile {
+    pub id: Option<BotId>,
+    pub app_id: Option<AppId>,
+    pub name: Option<String>,
+    pub icons: Option<HashMap<String, String>>,
+    pub deleted: Option<bool>,
+    pub updated: Option<i64>,
+    pub team_id: Option<TeamId>,
 }
@@ -1,12 +1,23 @@
 //! Slack WEB API Client for [chat](https://api.slack.com/methods?filter=chat) methods.
 
+#[cfg(feature = "web-api-chat")]
 pub mod delete;
+#[cfg(feature = "web-api-chat")]
 pub mod delete_scheduled_message;
+#[cfg(feature = "web-api-chat")]
 pub mod get_permalink;
+#[cfg(feature = "web-api-chat")]
 pub mod me_message;
 pub mod message;
+#[cfg(feature = "web-api-chat")]
 pub mod post_ephemeral;
+#[cfg(feature = "web-api-chat")]
 pub mod post_message;
+#[cfg(feature = "web-api-chat")]
 pub mod schedule_message;
+#[cfg(feature = "web-api-chat")]
 pub mod scheduled_messages_list;
+#[cfg(feature = "web-api-chat")]
+pub mod unfurl;
+#[cfg(feature = "web-api-chat")]
 pub mod update;
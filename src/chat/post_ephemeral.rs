@@ -3,16 +3,18 @@
 use crate::attachment::attachment::Attachment;
 use crate::block::blocks::Block;
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::{ChannelId, UserId};
+use crate::ts::Ts;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct PostEphemeralRequest {
-    pub channel: String,
+    pub channel: ChannelId,
     pub text: String,
-    pub user: String,
+    pub user: UserId,
     pub as_user: Option<bool>,
     pub attachments: Option<Vec<Attachment>>,
     pub blocks: Option<Vec<Block>>,
@@ -20,7 +22,7 @@ pub struct PostEphemeralRequest {
     pub icon_url: Option<String>,
     pub link_names: Option<bool>,
     pub parse: Option<String>,
-    pub thread_ts: Option<String>,
+    pub thread_ts: Option<Ts>,
     pub username: Option<String>,
 }
 
@@ -30,7 +32,7 @@ pub struct PostEphemeralResponse {
     pub ok: bool,
     pub error: Option<String>,
     pub response_metadata: Option<ResponseMetadata>,
-    pub message_ts: Option<String>,
+    pub message_ts: Option<Ts>,
 }
 
 /// Sends an ephemeral message to a user in a channel.  
@@ -43,11 +45,10 @@ pub async fn post_ephemeral<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("chat.postEphemeral");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("chat.postEphemeral", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<PostEphemeralResponse>(&result).map_err(Error::SerdeJsonError)
@@ -64,9 +65,9 @@ mod test {
     #[test]
     fn convert_request() {
         let request = PostEphemeralRequest {
-            channel: "C1234567890".to_string(),
+            channel: "C1234567890".into(),
             text: "Hello world".to_string(),
-            user: "U0BPQUNTA".to_string(),
+            user: "U0BPQUNTA".into(),
             as_user: Some(true),
             attachments: Some(vec![Attachment {
                 pretext: Some("pre-hello".to_string()),
@@ -85,7 +86,7 @@ mod test {
             icon_url: Some("http://lorempixel.com/48/48".to_string()),
             link_names: Some(true),
             parse: Some("full".to_string()),
-            thread_ts: Some("1234567890.123456".to_string()),
+            thread_ts: Some("1234567890.123456".into()),
             username: Some("My Bot".to_string()),
         };
         let json = r##"{
@@ -127,7 +128,7 @@ mod test {
     fn convert_response() {
         let response = PostEphemeralResponse {
             ok: true,
-            message_ts: Some("1502210682.580145".to_string()),
+            message_ts: Some("1502210682.580145".into()),
             ..Default::default()
         };
         let json = r##"{
@@ -144,9 +145,9 @@ mod test {
     #[async_std::test]
     async fn test_post_ephemeral() {
         let param = PostEphemeralRequest {
-            channel: "C1234567890".to_string(),
+            channel: "C1234567890".into(),
             text: "Hello world".to_string(),
-            user: "U0BPQUNTA".to_string(),
+            user: "U0BPQUNTA".into(),
             as_user: Some(true),
             attachments: Some(vec![Attachment {
                 pretext: Some("pre-hello".to_string()),
@@ -165,12 +166,12 @@ mod test {
             icon_url: Some("http://lorempixel.com/48/48".to_string()),
             link_names: Some(true),
             parse: Some("full".to_string()),
-            thread_ts: Some("1234567890.123456".to_string()),
+            thread_ts: Some("1234567890.123456".into()),
             username: Some("My Bot".to_string()),
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "message_ts": "1502210682.580145"
@@ -183,7 +184,7 @@ mod test {
             .unwrap();
         let expect = PostEphemeralResponse {
             ok: true,
-            message_ts: Some("1502210682.580145".to_string()),
+            message_ts: Some("1502210682.580145".into()),
             ..Default::default()
         };
 
@@ -2,16 +2,19 @@
 
 use crate::attachment::attachment::Attachment;
 use crate::block::blocks::Block;
+use crate::block::validation::{validate_blocks, Validate, ValidationError, MAX_MESSAGE_BLOCKS};
 use crate::chat::message::Message;
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::ChannelId;
+use crate::ts::Ts;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct PostMessageRequest {
-    pub channel: String,
+    pub channel: ChannelId,
     pub attachments: Option<Vec<Attachment>>,
     pub blocks: Option<Vec<Block>>,
     pub text: Option<String>,
@@ -21,21 +24,21 @@ pub struct PostMessageRequest {
     pub mrkdwn: Option<bool>,
     pub parse: Option<String>,
     pub reply_broadcast: Option<bool>,
-    pub thread_ts: Option<String>,
+    pub thread_ts: Option<Ts>,
     pub unfurl_links: Option<bool>,
     pub unfurl_media: Option<bool>,
     pub username: Option<String>,
 }
 
 impl PostMessageRequest {
-    pub fn builder(channel: String) -> PostMessageRequestBuilder {
+    pub fn builder(channel: ChannelId) -> PostMessageRequestBuilder {
         PostMessageRequestBuilder::new(channel)
     }
 }
 
 #[derive(Debug, Default)]
 pub struct PostMessageRequestBuilder {
-    pub channel: String,
+    pub channel: ChannelId,
     pub attachments: Option<Vec<Attachment>>,
     pub blocks: Option<Vec<Block>>,
     pub text: Option<String>,
@@ -45,14 +48,14 @@ pub struct PostMessageRequestBuilder {
     pub mrkdwn: Option<bool>,
     pub parse: Option<String>,
     pub reply_broadcast: Option<bool>,
-    pub thread_ts: Option<String>,
+    pub thread_ts: Option<Ts>,
     pub unfurl_links: Option<bool>,
     pub unfurl_media: Option<bool>,
     pub username: Option<String>,
 }
 
 impl PostMessageRequestBuilder {
-    pub fn new(channel: String) -> PostMessageRequestBuilder {
+    pub fn new(channel: ChannelId) -> PostMessageRequestBuilder {
         PostMessageRequestBuilder {
             channel,
             ..Default::default()
@@ -94,7 +97,7 @@ impl PostMessageRequestBuilder {
         self.reply_broadcast = Some(reply_broadcast);
         self
     }
-    pub fn thread_ts(mut self, thread_ts: String) -> PostMessageRequestBuilder {
+    pub fn thread_ts(mut self, thread_ts: Ts) -> PostMessageRequestBuilder {
         self.thread_ts = Some(thread_ts);
         self
     }
@@ -130,14 +133,39 @@ impl PostMessageRequestBuilder {
     }
 }
 
+impl Validate for PostMessageRequest {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if let Some(blocks) = &self.blocks {
+            validate_blocks(
+                blocks,
+                MAX_MESSAGE_BLOCKS,
+                &format!("{}/blocks", path),
+                errors,
+            );
+        }
+        if let Some(attachments) = &self.attachments {
+            for (i, attachment) in attachments.iter().enumerate() {
+                if let Some(blocks) = &attachment.blocks {
+                    validate_blocks(
+                        blocks,
+                        MAX_MESSAGE_BLOCKS,
+                        &format!("{}/attachments/{}/blocks", path, i),
+                        errors,
+                    );
+                }
+            }
+        }
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct PostMessageResponse {
     pub ok: bool,
     pub error: Option<String>,
     pub response_metadata: Option<ResponseMetadata>,
-    pub channel: Option<String>,
-    pub ts: Option<String>,
+    pub channel: Option<ChannelId>,
+    pub ts: Option<Ts>,
     pub message: Option<Message>,
 }
 
@@ -151,11 +179,10 @@ pub async fn post_message<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("chat.postMessage");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("chat.postMessage", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<PostMessageResponse>(&result).map_err(Error::SerdeJsonError)
@@ -175,7 +202,7 @@ mod test {
     #[test]
     fn convert_request() {
         let request = PostMessageRequest {
-            channel: "test".to_string(),
+            channel: "test".into(),
             text: Some("Hello world".to_string()),
             attachments: Some(vec![Attachment {
                 color: Some("#36a64f".to_string()),
@@ -324,15 +351,15 @@ mod test {
     fn convert_response() {
         let response = PostMessageResponse {
             ok: true,
-            channel: Some("C02H7UK23GB".to_string()),
-            ts: Some("1640258472.000200".to_string()),
+            channel: Some("C02H7UK23GB".into()),
+            ts: Some("1640258472.000200".into()),
             message: Some(Message {
-                bot_id: Some("B02H2MCBRL6".to_string()),
+                bot_id: Some("B02H2MCBRL6".into()),
                 type_file: Some("message".to_string()),
                 text: Some("Hello world".to_string()),
-                user: Some("U02GUNSESDD".to_string()),
-                ts: Some("1640258472.000200".to_string()),
-                team: Some("T02H7RHQNL9".to_string()),
+                user: Some("U02GUNSESDD".into()),
+                ts: Some("1640258472.000200".into()),
+                team: Some("T02H7RHQNL9".into()),
                 blocks: Some(vec![Block::ActionBlock(ActionBlock {
                     block_id: Some("Zf2/".to_string()),
                     elements: vec![
@@ -421,16 +448,33 @@ mod test {
         assert_eq!(response, s);
     }
 
+    #[test]
+    fn validate_request() {
+        let request = PostMessageRequest {
+            channel: "test".into(),
+            blocks: Some(vec![Block::SectionBlock(Default::default())]),
+            attachments: Some(vec![Attachment {
+                blocks: Some(vec![Block::None]),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+
+        let errors = request.validate().unwrap_err();
+        let paths: Vec<&str> = errors.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, vec!["/blocks/0", "/attachments/0/blocks/0"]);
+    }
+
     #[async_std::test]
     async fn test_post_message() {
         let param = PostMessageRequest {
-            channel: "test".to_string(),
+            channel: "test".into(),
             text: Some("test".to_string()),
             ..Default::default()
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "channel": "test",
@@ -446,7 +490,7 @@ mod test {
             .unwrap();
         let expect = PostMessageResponse {
             ok: true,
-            channel: Some("test".to_string()),
+            channel: Some("test".into()),
             message: Some(Message {
                 text: Some("test".to_string()),
                 ..Default::default()
@@ -4,15 +4,17 @@ use crate::attachment::attachment::Attachment;
 use crate::block::blocks::Block;
 use crate::chat::message::Message;
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::ChannelId;
+use crate::ts::Ts;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct ScheduledMessageRequest {
-    pub channel: String,
-    pub post_at: i32,
+    pub channel: ChannelId,
+    pub post_at: i64,
     pub text: String,
     pub as_user: Option<bool>,
     pub attachments: Option<Vec<Attachment>>,
@@ -20,7 +22,7 @@ pub struct ScheduledMessageRequest {
     pub link_names: Option<bool>,
     pub parse: Option<String>,
     pub reply_broadcast: Option<bool>,
-    pub thread_ts: Option<String>,
+    pub thread_ts: Option<Ts>,
     pub unfurl_links: Option<bool>,
     pub unfurl_media: Option<bool>,
 }
@@ -31,7 +33,7 @@ pub struct ScheduledMessageResponse {
     pub ok: bool,
     pub error: Option<String>,
     pub response_metadata: Option<ResponseMetadata>,
-    pub channel: Option<String>,
+    pub channel: Option<ChannelId>,
     pub scheduled_message_id: Option<String>,
     pub post_at: Option<String>,
     pub message: Option<Message>,
@@ -47,11 +49,10 @@ pub async fn scheduled_message<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("chat.scheduleMessage");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("chat.scheduleMessage", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<ScheduledMessageResponse>(&result).map_err(Error::SerdeJsonError)
@@ -63,12 +64,13 @@ mod test {
     use super::*;
     use crate::block::block_object::{TextBlockObject, TextBlockType};
     use crate::block::block_section::SectionBlock;
+    use crate::chat::message::MessageSubtype;
     use crate::http_client::MockSlackWebAPIClient;
 
     #[test]
     fn convert_request() {
         let request = ScheduledMessageRequest {
-            channel: "C1234567890".to_string(),
+            channel: "C1234567890".into(),
             post_at: 299876400,
             text: "Hello world".to_string(),
             as_user: Some(true),
@@ -88,7 +90,7 @@ mod test {
             link_names: Some(true),
             parse: Some("full".to_string()),
             reply_broadcast: Some(true),
-            thread_ts: Some("1234567890.123456".to_string()),
+            thread_ts: Some("1234567890.123456".into()),
             unfurl_links: Some(true),
             unfurl_media: Some(true),
         };
@@ -131,21 +133,24 @@ mod test {
     fn convert_response() {
         let response = ScheduledMessageResponse {
             ok: true,
-            channel: Some("C1H9RESGL".to_string()),
+            channel: Some("C1H9RESGL".into()),
             scheduled_message_id: Some("Q1298393284".to_string()),
             post_at: Some("1562180400".to_string()),
             message: Some(Message {
-                bot_id: Some("B19LU7CSY".to_string()),
+                bot_id: Some("B19LU7CSY".into()),
                 type_file: Some("delayed_message".to_string()),
                 text: Some("Here's a message for you in the future".to_string()),
-                user: Some("ecto1".to_string()),
+                user: Some("ecto1".into()),
                 attachments: Some(vec![Attachment {
                     fallback: Some("This is an attachment's fallback".to_string()),
                     id: Some(1),
                     text: Some("This is an attachment".to_string()),
                     ..Default::default()
                 }]),
-                subtype: Some("bot_message".to_string()),
+                subtype: Some(MessageSubtype::BotMessage {
+                    bot_profile: None,
+                    icons: None,
+                }),
                 ..Default::default()
             }),
             ..Default::default()
@@ -181,7 +186,7 @@ mod test {
     #[async_std::test]
     async fn test_scheduled_message() {
         let param = ScheduledMessageRequest {
-            channel: "C1234567890".to_string(),
+            channel: "C1234567890".into(),
             post_at: 299876400,
             text: "Hello world".to_string(),
             as_user: Some(true),
@@ -201,13 +206,13 @@ mod test {
             link_names: Some(true),
             parse: Some("full".to_string()),
             reply_broadcast: Some(true),
-            thread_ts: Some("1234567890.123456".to_string()),
+            thread_ts: Some("1234567890.123456".into()),
             unfurl_links: Some(true),
             unfurl_media: Some(true),
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "channel": "C1H9RESGL",
@@ -236,21 +241,24 @@ mod test {
             .unwrap();
         let expect = ScheduledMessageResponse {
             ok: true,
-            channel: Some("C1H9RESGL".to_string()),
+            channel: Some("C1H9RESGL".into()),
             scheduled_message_id: Some("Q1298393284".to_string()),
             post_at: Some("1562180400".to_string()),
             message: Some(Message {
-                bot_id: Some("B19LU7CSY".to_string()),
+                bot_id: Some("B19LU7CSY".into()),
                 type_file: Some("delayed_message".to_string()),
                 text: Some("Here's a message for you in the future".to_string()),
-                user: Some("ecto1".to_string()),
+                user: Some("ecto1".into()),
                 attachments: Some(vec![Attachment {
                     fallback: Some("This is an attachment's fallback".to_string()),
                     id: Some(1),
                     text: Some("This is an attachment".to_string()),
                     ..Default::default()
                 }]),
-                subtype: Some("bot_message".to_string()),
+                subtype: Some(MessageSubtype::BotMessage {
+                    bot_profile: None,
+                    icons: None,
+                }),
                 ..Default::default()
             }),
             ..Default::default()
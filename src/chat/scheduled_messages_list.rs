@@ -1,19 +1,21 @@
 //! Returns a list of scheduled messages.  
 
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::{ChannelId, TeamId};
+use crate::ts::Ts;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct ScheduledMessagesListRequest {
-    pub channel: Option<String>,
+    pub channel: Option<ChannelId>,
     pub cursor: Option<String>,
-    pub latest: Option<String>,
+    pub latest: Option<Ts>,
     pub limit: Option<i32>,
-    pub oldest: Option<String>,
-    pub team_id: Option<String>,
+    pub oldest: Option<Ts>,
+    pub team_id: Option<TeamId>,
 }
 
 #[skip_serializing_none]
@@ -29,9 +31,9 @@ pub struct ScheduledMessagesListResponse {
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct ScheduledMessage {
     pub id: Option<i32>,
-    pub channel_id: Option<String>,
-    pub post_at: Option<i32>,
-    pub date_created: Option<i32>,
+    pub channel_id: Option<ChannelId>,
+    pub post_at: Option<i64>,
+    pub date_created: Option<i64>,
     pub text: Option<String>,
 }
 
@@ -45,11 +47,10 @@ pub async fn scheduled_messages_list<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("chat.scheduledMessages.list");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("chat.scheduledMessages.list", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<ScheduledMessagesListResponse>(&result)
@@ -66,12 +67,12 @@ mod test {
     #[test]
     fn convert_request() {
         let request = ScheduledMessagesListRequest {
-            channel: Some("C123456789".to_string()),
+            channel: Some("C123456789".into()),
             cursor: Some("dXNlcjpVMDYxTkZUVDI=".to_string()),
-            latest: Some("1562137200".to_string()),
+            latest: Some("1562137200".into()),
             limit: Some(100),
-            oldest: Some("1562137200".to_string()),
-            team_id: Some("T1234567890".to_string()),
+            oldest: Some("1562137200".into()),
+            team_id: Some("T1234567890".into()),
         };
         let json = r##"{
   "channel": "C123456789",
@@ -95,7 +96,7 @@ mod test {
             ok: true,
             scheduled_messages: Some(vec![ScheduledMessage {
                 id: Some(1298393284),
-                channel_id: Some("C1H9RESGL".to_string()),
+                channel_id: Some("C1H9RESGL".into()),
                 post_at: Some(1551991428),
                 date_created: Some(1551891734),
                 text: Some("Here's a message for you in the future".to_string()),
@@ -125,16 +126,16 @@ mod test {
     #[async_std::test]
     async fn test_scheduled_messages_list() {
         let param = ScheduledMessagesListRequest {
-            channel: Some("C123456789".to_string()),
+            channel: Some("C123456789".into()),
             cursor: Some("dXNlcjpVMDYxTkZUVDI=".to_string()),
-            latest: Some("1562137200".to_string()),
+            latest: Some("1562137200".into()),
             limit: Some(100),
-            oldest: Some("1562137200".to_string()),
-            team_id: Some("T1234567890".to_string()),
+            oldest: Some("1562137200".into()),
+            team_id: Some("T1234567890".into()),
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "scheduled_messages": [
@@ -157,7 +158,7 @@ mod test {
             ok: true,
             scheduled_messages: Some(vec![ScheduledMessage {
                 id: Some(1298393284),
-                channel_id: Some("C123456789".to_string()),
+                channel_id: Some("C123456789".into()),
                 post_at: Some(1551991428),
                 date_created: Some(1551891734),
                 text: Some("Here's a message for you in the future".to_string()),
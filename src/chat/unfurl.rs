@@ -0,0 +1,349 @@
+//! Provide custom unfurl behavior for user-posted URLs.
+
+use crate::attachment::attachment::Attachment;
+use crate::block::blocks::Block;
+use crate::error::Error;
+#[cfg(feature = "events-api")]
+use crate::event_api::event::EventCallback;
+#[cfg(feature = "events-api")]
+use crate::event_api::link::SharedLink;
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::ChannelId;
+use crate::ts::Ts;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::collections::HashMap;
+
+/// Either `channel` and `ts`, or `unfurl_id` and `source`, identify the message to unfurl.
+///
+/// Each value in `unfurls` is an attachment; set only its `blocks` for a Block Kit unfurl.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct UnfurlRequest {
+    pub channel: Option<ChannelId>,
+    pub ts: Option<Ts>,
+    pub unfurls: Option<HashMap<String, Attachment>>,
+    pub source: Option<String>,
+    pub unfurl_id: Option<String>,
+    pub user_auth_blocks: Option<Vec<Block>>,
+    pub user_auth_message: Option<String>,
+    pub user_auth_required: Option<bool>,
+    pub user_auth_url: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct UnfurlResponse {
+    pub ok: bool,
+    pub error: Option<String>,
+    pub response_metadata: Option<ResponseMetadata>,
+}
+
+/// Provide custom unfurl behavior for user-posted URLs.
+/// See: <https://api.slack.com/methods/chat.unfurl>
+pub async fn unfurl<T>(
+    client: &T,
+    param: &UnfurlRequest,
+    bot_token: &str,
+) -> Result<UnfurlResponse, Error>
+where
+    T: SlackWebAPIClient,
+{
+    let json = serde_json::to_string(&param)?;
+
+    client
+        .call_json("chat.unfurl", &json, bot_token)
+        .await
+        .and_then(|result| {
+            serde_json::from_str::<UnfurlResponse>(&result).map_err(Error::SerdeJsonError)
+        })
+}
+
+type UnfurlGenerator = Box<dyn Fn(&str) -> Option<Attachment> + Send + Sync>;
+
+/// Maps URL patterns to unfurl generators and answers `link_shared` events.
+///
+/// Patterns are matched against the URL without its scheme, and `*` matches any run of
+/// characters. The first pattern that matches a link and whose generator returns an unfurl wins.
+///
+/// ```
+/// use slack_rust::attachment::attachment::Attachment;
+/// use slack_rust::chat::unfurl::Unfurler;
+///
+/// let unfurler = Unfurler::new().route("wiki.example.com/pages/*", |url| {
+///     Some(Attachment {
+///         title: Some(url.to_string()),
+///         ..Default::default()
+///     })
+/// });
+/// assert!(unfurler.unfurl("https://wiki.example.com/pages/42").is_some());
+/// assert!(unfurler.unfurl("https://example.com/").is_none());
+/// ```
+#[derive(Default)]
+pub struct Unfurler {
+    routes: Vec<(String, UnfurlGenerator)>,
+}
+
+impl Unfurler {
+    pub fn new() -> Unfurler {
+        Unfurler::default()
+    }
+    pub fn route<F>(mut self, pattern: &str, generator: F) -> Unfurler
+    where
+        F: Fn(&str) -> Option<Attachment> + Send + Sync + 'static,
+    {
+        self.routes.push((pattern.to_string(), Box::new(generator)));
+        self
+    }
+    /// Generates the unfurl for a single URL.
+    pub fn unfurl(&self, url: &str) -> Option<Attachment> {
+        let target = url.split_once("://").map_or(url, |(_, rest)| rest);
+        self.routes
+            .iter()
+            .filter(|(pattern, _)| matches_pattern(pattern, target))
+            .find_map(|(_, generator)| generator(url))
+    }
+    #[cfg(feature = "events-api")]
+    /// Generates unfurls for every link that has a matching route, keyed by URL.
+    pub fn unfurls(&self, links: &[SharedLink]) -> HashMap<String, Attachment> {
+        links
+            .iter()
+            .filter_map(|link| Some((link.url.clone(), self.unfurl(&link.url)?)))
+            .collect()
+    }
+    #[cfg(feature = "events-api")]
+    /// Builds the `chat.unfurl` request answering a `link_shared` event.
+    ///
+    /// Returns `None` for other events, or when no link has a matching route.
+    pub fn unfurl_request(&self, event: &EventCallback) -> Option<UnfurlRequest> {
+        match event {
+            EventCallback::LinkShared {
+                channel,
+                message_ts,
+                links,
+                unfurl_id,
+                source,
+                ..
+            } => {
+                let unfurls = self.unfurls(links);
+                if unfurls.is_empty() {
+                    return None;
+                }
+                let request = match (unfurl_id, source) {
+                    (Some(unfurl_id), Some(source)) => UnfurlRequest {
+                        unfurl_id: Some(unfurl_id.to_string()),
+                        source: Some(source.to_string()),
+                        ..Default::default()
+                    },
+                    _ => UnfurlRequest {
+                        channel: Some(channel.clone()),
+                        ts: Some(message_ts.clone()),
+                        ..Default::default()
+                    },
+                };
+                Some(UnfurlRequest {
+                    unfurls: Some(unfurls),
+                    ..request
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Matches `text` against `pattern`, where `*` matches any run of characters.
+fn matches_pattern(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let mut rest = match text.strip_prefix(first) {
+        Some(rest) => rest,
+        None => return false,
+    };
+    let parts: Vec<&str> = parts.collect();
+    let last = match parts.split_last() {
+        Some((last, middle)) => {
+            for part in middle {
+                match rest.find(part) {
+                    Some(index) => rest = &rest[index + part.len()..],
+                    None => return false,
+                }
+            }
+            last
+        }
+        None => return rest.is_empty(),
+    };
+    rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::block::block_object::{TextBlockObject, TextBlockType};
+    use crate::block::block_section::SectionBlock;
+    use crate::http_client::MockSlackWebAPIClient;
+
+    #[test]
+    fn convert_request() {
+        let mut unfurls = HashMap::new();
+        unfurls.insert(
+            "https://example.com".to_string(),
+            Attachment {
+                blocks: Some(vec![Block::SectionBlock(SectionBlock {
+                    text: Some(TextBlockObject {
+                        type_filed: TextBlockType::Mrkdwn,
+                        text: "Take a look at this carafe".to_string(),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                })]),
+                ..Default::default()
+            },
+        );
+        let request = UnfurlRequest {
+            channel: Some("C123456789".into()),
+            ts: Some("1234567890.123456".into()),
+            unfurls: Some(unfurls),
+            user_auth_message: Some("Please connect your account".to_string()),
+            user_auth_required: Some(true),
+            ..Default::default()
+        };
+        let json = r##"{
+  "channel": "C123456789",
+  "ts": "1234567890.123456",
+  "unfurls": {
+    "https://example.com": {
+      "blocks": [
+        {
+          "type": "section",
+          "text": {
+            "type": "mrkdwn",
+            "text": "Take a look at this carafe"
+          }
+        }
+      ]
+    }
+  },
+  "user_auth_message": "Please connect your account",
+  "user_auth_required": true
+}"##;
+
+        let j = serde_json::to_string_pretty(&request).unwrap();
+        assert_eq!(json, j);
+
+        let s = serde_json::from_str::<UnfurlRequest>(json).unwrap();
+        assert_eq!(request, s);
+    }
+
+    #[test]
+    fn convert_response() {
+        let response = UnfurlResponse {
+            ok: true,
+            ..Default::default()
+        };
+        let json = r##"{
+  "ok": true
+}"##;
+
+        let j = serde_json::to_string_pretty(&response).unwrap();
+        assert_eq!(json, j);
+
+        let s = serde_json::from_str::<UnfurlResponse>(json).unwrap();
+        assert_eq!(response, s);
+    }
+
+    #[async_std::test]
+    async fn test_unfurl() {
+        let param = UnfurlRequest {
+            unfurl_id: Some("C123456.123456789.987501".to_string()),
+            source: Some("composer".to_string()),
+            user_auth_required: Some(true),
+            user_auth_url: Some("https://example.com/onboarding?user_id=xxx".to_string()),
+            ..Default::default()
+        };
+
+        let mut mock = MockSlackWebAPIClient::new();
+        mock.expect_call_json().returning(|_, _, _| {
+            Ok(r##"{
+  "ok": true
+}"##
+            .to_string())
+        });
+
+        let response = unfurl(&mock, &param, "test_token").await.unwrap();
+        let expect = UnfurlResponse {
+            ok: true,
+            ..Default::default()
+        };
+
+        assert_eq!(expect, response);
+    }
+
+    #[test]
+    fn test_pattern() {
+        assert!(matches_pattern("example.com/*", "example.com/a/b"));
+        assert!(matches_pattern(
+            "*.example.com/*/edit",
+            "wiki.example.com/42/edit"
+        ));
+        assert!(matches_pattern("example.com", "example.com"));
+        assert!(!matches_pattern("example.com", "example.com/a"));
+        assert!(!matches_pattern(
+            "*.example.com/*/edit",
+            "wiki.example.com/42/view"
+        ));
+        assert!(!matches_pattern("example.com/*", "evil.com/example.com/"));
+    }
+
+    #[cfg(feature = "events-api")]
+    #[test]
+    fn test_unfurl_request() {
+        let unfurler = Unfurler::new()
+            .route("example.com/private/*", |_| None)
+            .route("example.com/*", |url| {
+                Some(Attachment {
+                    title: Some(url.to_string()),
+                    ..Default::default()
+                })
+            });
+        let event = EventCallback::LinkShared {
+            channel: "C123456789".into(),
+            user: None,
+            message_ts: "1234567890.123456".into(),
+            thread_ts: None,
+            links: vec![
+                SharedLink {
+                    domain: "example.com".to_string(),
+                    url: "https://example.com/private/1".to_string(),
+                    ..Default::default()
+                },
+                SharedLink {
+                    domain: "other.com".to_string(),
+                    url: "https://other.com/1".to_string(),
+                    ..Default::default()
+                },
+            ],
+            unfurl_id: None,
+            source: None,
+            is_bot_user_member: None,
+            event_ts: None,
+        };
+
+        let request = unfurler.unfurl_request(&event).unwrap();
+        assert_eq!(request.channel.unwrap(), "C123456789");
+        assert_eq!(request.ts.unwrap(), "1234567890.123456");
+        let unfurls = request.unfurls.unwrap();
+        assert_eq!(unfurls.len(), 1);
+        assert_eq!(
+            unfurls["https://example.com/private/1"]
+                .title
+                .as_ref()
+                .unwrap(),
+            "https://example.com/private/1"
+        );
+
+        assert_eq!(
+            unfurler.unfurl_request(&EventCallback::AppUninstalled),
+            None
+        );
+    }
+}
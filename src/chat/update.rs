@@ -4,19 +4,21 @@ use crate::attachment::attachment::Attachment;
 use crate::block::blocks::Block;
 use crate::chat::message::Message;
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::{ChannelId, FileId};
+use crate::ts::Ts;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct UpdateRequest {
-    pub channel: String,
-    pub ts: String,
+    pub channel: ChannelId,
+    pub ts: Ts,
     pub as_user: Option<bool>,
     pub attachments: Option<Vec<Attachment>>,
     pub blocks: Option<Vec<Block>>,
-    pub file_ids: Option<Vec<String>>,
+    pub file_ids: Option<Vec<FileId>>,
     pub link_names: Option<bool>,
     pub parse: Option<String>,
     pub reply_broadcast: Option<bool>,
@@ -29,8 +31,8 @@ pub struct UpdateResponse {
     pub ok: bool,
     pub error: Option<String>,
     pub response_metadata: Option<ResponseMetadata>,
-    pub channel: Option<String>,
-    pub ts: Option<String>,
+    pub channel: Option<ChannelId>,
+    pub ts: Option<Ts>,
     pub text: Option<String>,
     pub message: Option<Message>,
 }
@@ -45,11 +47,10 @@ pub async fn update<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("chat.update");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("chat.update", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<UpdateResponse>(&result).map_err(Error::SerdeJsonError)
@@ -66,8 +67,8 @@ mod test {
     #[test]
     fn convert_request() {
         let request = UpdateRequest {
-            channel: "C123456789".to_string(),
-            ts: "1405894322.002768".to_string(),
+            channel: "C123456789".into(),
+            ts: "1405894322.002768".into(),
             as_user: Some(true),
             attachments: Some(vec![Attachment {
                 pretext: Some("pre-hello".to_string()),
@@ -82,7 +83,7 @@ mod test {
                 }),
                 ..Default::default()
             })]),
-            file_ids: Some(vec!["F013GKY52QK".to_string(), "F013GL22D0T".to_string()]),
+            file_ids: Some(vec!["F013GKY52QK".into(), "F013GL22D0T".into()]),
             link_names: Some(true),
             parse: Some("none".to_string()),
             reply_broadcast: Some(true),
@@ -128,12 +129,12 @@ mod test {
     fn convert_response() {
         let response = UpdateResponse {
             ok: true,
-            channel: Some("C024BE91L".to_string()),
-            ts: Some("1401383885.000061".to_string()),
+            channel: Some("C024BE91L".into()),
+            ts: Some("1401383885.000061".into()),
             text: Some("Updated text you carefully authored".to_string()),
             message: Some(Message {
                 text: Some("Updated text you carefully authored".to_string()),
-                user: Some("U34567890".to_string()),
+                user: Some("U34567890".into()),
                 ..Default::default()
             }),
             ..Default::default()
@@ -159,8 +160,8 @@ mod test {
     #[async_std::test]
     async fn test_update() {
         let param = UpdateRequest {
-            channel: "C123456789".to_string(),
-            ts: "1405894322.002768".to_string(),
+            channel: "C123456789".into(),
+            ts: "1405894322.002768".into(),
             as_user: Some(true),
             attachments: Some(vec![Attachment {
                 pretext: Some("pre-hello".to_string()),
@@ -175,7 +176,7 @@ mod test {
                 }),
                 ..Default::default()
             })]),
-            file_ids: Some(vec!["F013GKY52QK".to_string(), "F013GL22D0T".to_string()]),
+            file_ids: Some(vec!["F013GKY52QK".into(), "F013GL22D0T".into()]),
             link_names: Some(true),
             parse: Some("none".to_string()),
             reply_broadcast: Some(true),
@@ -183,7 +184,7 @@ mod test {
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "channel": "C123456789",
@@ -202,12 +203,12 @@ mod test {
             .unwrap();
         let expect = UpdateResponse {
             ok: true,
-            channel: Some("C123456789".to_string()),
-            ts: Some("1401383885.000061".to_string()),
+            channel: Some("C123456789".into()),
+            ts: Some("1401383885.000061".into()),
             text: Some("Hello world".to_string()),
             message: Some(Message {
                 text: Some("text".to_string()),
-                user: Some("U34567890".to_string()),
+                user: Some("U34567890".into()),
                 ..Default::default()
             }),
             ..Default::default()
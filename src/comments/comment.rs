@@ -1,3 +1,4 @@
+use crate::id::UserId;
 use crate::reactions::reaction::Reaction;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -8,9 +9,9 @@ pub struct Comment {
     #[serde(rename = "type")]
     pub type_filed: Option<String>,
     pub comment: Option<String>,
-    pub created: Option<i32>,
+    pub created: Option<i64>,
     pub id: Option<String>,
     pub reactions: Option<Vec<Reaction>>,
-    pub timestamp: Option<i32>,
-    pub user: Option<String>,
+    pub timestamp: Option<i64>,
+    pub user: Option<UserId>,
 }
@@ -1,7 +1,8 @@
 //! Accepts an invitation to a Slack Connect channel.
 
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::{ChannelId, TeamId};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
@@ -9,11 +10,11 @@ use serde_with::skip_serializing_none;
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct AcceptSharedInviteRequest {
     pub channel_name: String,
-    pub channel_id: Option<String>,
+    pub channel_id: Option<ChannelId>,
     pub free_trial_accepted: Option<bool>,
     pub invite_id: Option<String>,
     pub is_private: Option<bool>,
-    pub team_id: Option<String>,
+    pub team_id: Option<TeamId>,
 }
 
 #[skip_serializing_none]
@@ -23,7 +24,7 @@ pub struct AcceptSharedInviteResponse {
     pub error: Option<String>,
     pub response_metadata: Option<ResponseMetadata>,
     pub implicit_approval: Option<bool>,
-    pub channel_id: Option<String>,
+    pub channel_id: Option<ChannelId>,
     pub invite_id: Option<String>,
 }
 
@@ -37,11 +38,10 @@ pub async fn accept_shared_invite<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("conversations.acceptSharedInvite");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("conversations.acceptSharedInvite", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<AcceptSharedInviteResponse>(&result)
@@ -58,11 +58,11 @@ mod test {
     fn convert_request() {
         let request = AcceptSharedInviteRequest {
             channel_name: "puppies-r-us".to_string(),
-            channel_id: Some("xxxxxxxxxxx".to_string()),
+            channel_id: Some("xxxxxxxxxxx".into()),
             free_trial_accepted: Some(true),
             invite_id: Some("xxxxxxxxxxx".to_string()),
             is_private: Some(true),
-            team_id: Some("T1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
         };
         let json = r##"{
   "channel_name": "puppies-r-us",
@@ -85,7 +85,7 @@ mod test {
         let response = AcceptSharedInviteResponse {
             ok: true,
             implicit_approval: Some(true),
-            channel_id: Some("C0001111".to_string()),
+            channel_id: Some("C0001111".into()),
             invite_id: Some("I00043221".to_string()),
             ..Default::default()
         };
@@ -107,15 +107,15 @@ mod test {
     async fn test_accept_shared_invite() {
         let param = AcceptSharedInviteRequest {
             channel_name: "puppies-r-us".to_string(),
-            channel_id: Some("xxxxxxxxxxx".to_string()),
+            channel_id: Some("xxxxxxxxxxx".into()),
             free_trial_accepted: Some(true),
             invite_id: Some("xxxxxxxxxxx".to_string()),
             is_private: Some(true),
-            team_id: Some("T1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "implicit_approval": true,
@@ -131,7 +131,7 @@ mod test {
         let expect = AcceptSharedInviteResponse {
             ok: true,
             implicit_approval: Some(true),
-            channel_id: Some("xxxxxxxxxxx".to_string()),
+            channel_id: Some("xxxxxxxxxxx".into()),
             invite_id: Some("xxxxxxxxxxx".to_string()),
             ..Default::default()
         };
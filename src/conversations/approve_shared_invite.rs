@@ -1,7 +1,7 @@
 //! Approves an invitation to a Slack Connect channel.
 
 use crate::error::Error;
-use crate::http_client::{get_slack_url, DefaultResponse, SlackWebAPIClient};
+use crate::http_client::{DefaultResponse, SlackWebAPIClient};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
@@ -22,11 +22,10 @@ pub async fn approve_shared_invite<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("conversations.approveSharedInvite");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("conversations.approveSharedInvite", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<DefaultResponse>(&result).map_err(Error::SerdeJsonError)
@@ -64,7 +63,7 @@ mod test {
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true
 }"##
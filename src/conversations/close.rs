@@ -1,13 +1,14 @@
 //! Closes a direct message or multi-person direct message.
 
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::ChannelId;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct CloseRequest {
-    pub channel: String,
+    pub channel: ChannelId,
 }
 
 #[skip_serializing_none]
@@ -30,11 +31,10 @@ pub async fn close<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("conversations.close");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("conversations.close", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<CloseResponse>(&result).map_err(Error::SerdeJsonError)
@@ -49,7 +49,7 @@ mod test {
     #[test]
     fn convert_request() {
         let request = CloseRequest {
-            channel: "G1234567890".to_string(),
+            channel: "G1234567890".into(),
         };
         let json = r##"{
   "channel": "G1234567890"
@@ -86,10 +86,10 @@ mod test {
     #[async_std::test]
     async fn test_close() {
         let param = CloseRequest {
-            channel: "G1234567890".to_string(),
+            channel: "G1234567890".into(),
         };
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "no_op": true,
@@ -1,7 +1,8 @@
 //! Initiates a public or private channel-based conversation.
 use crate::channels::channel::Channel;
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::TeamId;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
@@ -10,7 +11,7 @@ use serde_with::skip_serializing_none;
 pub struct CreateRequest {
     pub name: String,
     pub is_private: Option<bool>,
-    pub team_id: Option<String>,
+    pub team_id: Option<TeamId>,
 }
 
 #[skip_serializing_none]
@@ -32,11 +33,10 @@ pub async fn create<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("conversations.create");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("conversations.create", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<CreateResponse>(&result).map_err(Error::SerdeJsonError)
@@ -54,7 +54,7 @@ mod test {
         let request = CreateRequest {
             name: "mychannel".to_string(),
             is_private: Some(true),
-            team_id: Some("T1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
         };
         let json = r##"{
   "name": "mychannel",
@@ -74,11 +74,11 @@ mod test {
         let response = CreateResponse {
             ok: true,
             channel: Some(Channel {
-                id: Some("C0EAQDV4Z".to_string()),
+                id: Some("C0EAQDV4Z".into()),
                 name: Some("endeavor".to_string()),
                 is_channel: Some(true),
                 created: Some(1504554479),
-                creator: Some("U0123456".to_string()),
+                creator: Some("U0123456".into()),
                 is_archived: Some(false),
                 is_general: Some(false),
                 name_normalized: Some("endeavor".to_string()),
@@ -87,17 +87,17 @@ mod test {
                 is_member: Some(false),
                 is_private: Some(false),
                 is_mpim: Some(false),
-                last_read: Some("0000000000.000000".to_string()),
+                last_read: Some("0000000000.000000".into()),
                 unread_count: Some(0),
                 unread_count_display: Some(0),
                 topic: Some(Topic {
                     value: Some("".to_string()),
-                    creator: Some("".to_string()),
+                    creator: Some("".into()),
                     last_set: Some(0),
                 }),
                 purpose: Some(Purpose {
                     value: Some("".to_string()),
-                    creator: Some("".to_string()),
+                    creator: Some("".into()),
                     last_set: Some(0),
                 }),
                 previous_names: Some(vec![]),
@@ -152,11 +152,11 @@ mod test {
         let param = CreateRequest {
             name: "mychannel".to_string(),
             is_private: Some(true),
-            team_id: Some("T1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "channel": {
@@ -199,11 +199,11 @@ mod test {
         let expect = CreateResponse {
             ok: true,
             channel: Some(Channel {
-                id: Some("T1234567890".to_string()),
+                id: Some("T1234567890".into()),
                 name: Some("mychannel".to_string()),
                 is_channel: Some(true),
                 created: Some(1504554479),
-                creator: Some("U0123456".to_string()),
+                creator: Some("U0123456".into()),
                 is_archived: Some(false),
                 is_general: Some(false),
                 name_normalized: Some("endeavor".to_string()),
@@ -212,17 +212,17 @@ mod test {
                 is_member: Some(false),
                 is_private: Some(true),
                 is_mpim: Some(false),
-                last_read: Some("0000000000.000000".to_string()),
+                last_read: Some("0000000000.000000".into()),
                 unread_count: Some(0),
                 unread_count_display: Some(0),
                 topic: Some(Topic {
                     value: Some("".to_string()),
-                    creator: Some("".to_string()),
+                    creator: Some("".into()),
                     last_set: Some(0),
                 }),
                 purpose: Some(Purpose {
                     value: Some("".to_string()),
-                    creator: Some("".to_string()),
+                    creator: Some("".into()),
                     last_set: Some(0),
                 }),
                 previous_names: Some(vec![]),
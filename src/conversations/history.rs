@@ -1,19 +1,21 @@
 //! Fetches a conversation's history of messages and events.
 use crate::chat::message::Message;
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::ChannelId;
+use crate::ts::Ts;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct HistoryRequest {
-    pub channel: String,
+    pub channel: ChannelId,
     pub cursor: Option<String>,
     pub inclusive: Option<bool>,
-    pub latest: Option<String>,
+    pub latest: Option<Ts>,
     pub limit: Option<i32>,
-    pub oldest: Option<String>,
+    pub oldest: Option<Ts>,
 }
 
 #[skip_serializing_none]
@@ -37,11 +39,10 @@ pub async fn history<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("conversations.history");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("conversations.history", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<HistoryResponse>(&result).map_err(Error::SerdeJsonError)
@@ -56,12 +57,12 @@ mod test {
     #[test]
     fn convert_request() {
         let request = HistoryRequest {
-            channel: "C1234567890".to_string(),
+            channel: "C1234567890".into(),
             cursor: Some("dXNlcjpVMDYxTkZUVDI=".to_string()),
             inclusive: Some(true),
-            latest: Some("1234567890.123456".to_string()),
+            latest: Some("1234567890.123456".into()),
             limit: Some(20),
-            oldest: Some("1234567890.123456".to_string()),
+            oldest: Some("1234567890.123456".into()),
         };
         let json = r##"{
   "channel": "C1234567890",
@@ -89,15 +90,15 @@ mod test {
                     text: Some(
                         "I find you punny and would like to smell your nose letter".to_string(),
                     ),
-                    user: Some("U012AB3CDE".to_string()),
-                    ts: Some("1512085950.000216".to_string()),
+                    user: Some("U012AB3CDE".into()),
+                    ts: Some("1512085950.000216".into()),
                     ..Default::default()
                 },
                 Message {
                     type_file: Some("message".to_string()),
                     text: Some("What, you want to smell my shoes better?".to_string()),
-                    user: Some("U061F7AUR".to_string()),
-                    ts: Some("1512104434.000490".to_string()),
+                    user: Some("U061F7AUR".into()),
+                    ts: Some("1512104434.000490".into()),
                     ..Default::default()
                 },
             ]),
@@ -135,16 +136,16 @@ mod test {
     #[async_std::test]
     async fn test_history() {
         let param = HistoryRequest {
-            channel: "C1234567890".to_string(),
+            channel: "C1234567890".into(),
             cursor: Some("dXNlcjpVMDYxTkZUVDI=".to_string()),
             inclusive: Some(true),
-            latest: Some("1512104434.000490".to_string()),
+            latest: Some("1512104434.000490".into()),
             limit: Some(20),
-            oldest: Some("1512085950.000216".to_string()),
+            oldest: Some("1512085950.000216".into()),
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "messages": [
@@ -178,15 +179,15 @@ mod test {
                     text: Some(
                         "I find you punny and would like to smell your nose letter".to_string(),
                     ),
-                    user: Some("U012AB3CDE".to_string()),
-                    ts: Some("1512085950.000216".to_string()),
+                    user: Some("U012AB3CDE".into()),
+                    ts: Some("1512085950.000216".into()),
                     ..Default::default()
                 },
                 Message {
                     type_file: Some("message".to_string()),
                     text: Some("What, you want to smell my shoes better?".to_string()),
-                    user: Some("U061F7AUR".to_string()),
-                    ts: Some("1512104434.000490".to_string()),
+                    user: Some("U061F7AUR".into()),
+                    ts: Some("1512104434.000490".into()),
                     ..Default::default()
                 },
             ]),
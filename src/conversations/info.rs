@@ -2,14 +2,15 @@
 
 use crate::channels::channel::Channel;
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::ChannelId;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct InfoRequest {
-    pub channel: String,
+    pub channel: ChannelId,
     pub include_locale: Option<bool>,
     pub include_num_members: Option<bool>,
 }
@@ -33,11 +34,10 @@ pub async fn info<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("conversations.info");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("conversations.info", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<InfoResponse>(&result).map_err(Error::SerdeJsonError)
@@ -52,7 +52,7 @@ mod test {
     #[test]
     fn convert_request() {
         let request = InfoRequest {
-            channel: "C1234567890".to_string(),
+            channel: "C1234567890".into(),
             include_locale: Some(true),
             include_num_members: Some(true),
         };
@@ -74,11 +74,11 @@ mod test {
         let response = InfoResponse {
             ok: true,
             channel: Some(Channel {
-                id: Some("C0EAQDV4Z".to_string()),
+                id: Some("C0EAQDV4Z".into()),
                 name: Some("endeavor".to_string()),
                 is_channel: Some(true),
                 created: Some(1504554479),
-                creator: Some("U0123456".to_string()),
+                creator: Some("U0123456".into()),
                 is_archived: Some(false),
                 is_general: Some(false),
                 name_normalized: Some("endeavor".to_string()),
@@ -87,17 +87,17 @@ mod test {
                 is_member: Some(false),
                 is_private: Some(false),
                 is_mpim: Some(false),
-                last_read: Some("0000000000.000000".to_string()),
+                last_read: Some("0000000000.000000".into()),
                 unread_count: Some(0),
                 unread_count_display: Some(0),
                 topic: Some(Topic {
                     value: Some("".to_string()),
-                    creator: Some("".to_string()),
+                    creator: Some("".into()),
                     last_set: Some(0),
                 }),
                 purpose: Some(Purpose {
                     value: Some("".to_string()),
-                    creator: Some("".to_string()),
+                    creator: Some("".into()),
                     last_set: Some(0),
                 }),
                 previous_names: Some(vec![]),
@@ -150,13 +150,13 @@ mod test {
     #[async_std::test]
     async fn test_info() {
         let param = InfoRequest {
-            channel: "C1234567890".to_string(),
+            channel: "C1234567890".into(),
             include_locale: Some(true),
             include_num_members: Some(true),
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "channel": {
@@ -199,11 +199,11 @@ mod test {
         let expect = InfoResponse {
             ok: true,
             channel: Some(Channel {
-                id: Some("C1234567890".to_string()),
+                id: Some("C1234567890".into()),
                 name: Some("endeavor".to_string()),
                 is_channel: Some(true),
                 created: Some(1504554479),
-                creator: Some("U0123456".to_string()),
+                creator: Some("U0123456".into()),
                 is_archived: Some(false),
                 is_general: Some(false),
                 name_normalized: Some("endeavor".to_string()),
@@ -212,17 +212,17 @@ mod test {
                 is_member: Some(false),
                 is_private: Some(false),
                 is_mpim: Some(false),
-                last_read: Some("0000000000.000000".to_string()),
+                last_read: Some("0000000000.000000".into()),
                 unread_count: Some(0),
                 unread_count_display: Some(0),
                 topic: Some(Topic {
                     value: Some("".to_string()),
-                    creator: Some("".to_string()),
+                    creator: Some("".into()),
                     last_set: Some(0),
                 }),
                 purpose: Some(Purpose {
                     value: Some("".to_string()),
-                    creator: Some("".to_string()),
+                    creator: Some("".into()),
                     last_set: Some(0),
                 }),
                 previous_names: Some(vec![]),
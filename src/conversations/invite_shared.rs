@@ -1,14 +1,15 @@
 //! Sends an invitation to a Slack Connect channel.
 
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::ChannelId;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct InviteSharedRequest {
-    pub channel: String,
+    pub channel: ChannelId,
     pub emails: Option<Vec<String>>,
     pub external_limited: Option<bool>,
     pub user_ids: Option<Vec<String>>,
@@ -36,11 +37,10 @@ pub async fn invite_shared<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("conversations.inviteShared");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("conversations.inviteShared", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<InviteSharedResponse>(&result).map_err(Error::SerdeJsonError)
@@ -55,7 +55,7 @@ mod test {
     #[test]
     fn convert_request() {
         let request = InviteSharedRequest {
-            channel: "C1234567890".to_string(),
+            channel: "C1234567890".into(),
             emails: Some(vec!["xxxxxxxxxxx".to_string(), "xxxxxxxxxxx".to_string()]),
             external_limited: Some(true),
             user_ids: Some(vec!["xxxxxxxxxxx".to_string(), "xxxxxxxxxxx".to_string()]),
@@ -108,14 +108,14 @@ mod test {
     #[async_std::test]
     async fn test_invite_shared() {
         let param = InviteSharedRequest {
-            channel: "C1234567890".to_string(),
+            channel: "C1234567890".into(),
             emails: Some(vec!["xxxxxxxxxxx".to_string(), "xxxxxxxxxxx".to_string()]),
             external_limited: Some(true),
             user_ids: Some(vec!["xxxxxxxxxxx".to_string(), "xxxxxxxxxxx".to_string()]),
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "invite_id": "I011K7UESHG",
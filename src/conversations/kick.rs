@@ -1,13 +1,14 @@
 //! Removes a user from a conversation.
 
 use crate::error::Error;
-use crate::http_client::{get_slack_url, DefaultResponse, SlackWebAPIClient};
+use crate::http_client::{DefaultResponse, SlackWebAPIClient};
+use crate::id::{ChannelId, UserId};
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct KickRequest {
-    pub channel: String,
-    pub user: String,
+    pub channel: ChannelId,
+    pub user: UserId,
 }
 
 /// Removes a user from a conversation.  
@@ -20,11 +21,10 @@ pub async fn kick<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("conversations.kick");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("conversations.kick", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<DefaultResponse>(&result).map_err(Error::SerdeJsonError)
@@ -39,8 +39,8 @@ mod test {
     #[test]
     fn convert_request() {
         let request = KickRequest {
-            channel: "C1234567890".to_string(),
-            user: "W1234567890".to_string(),
+            channel: "C1234567890".into(),
+            user: "W1234567890".into(),
         };
         let json = r##"{
   "channel": "C1234567890",
@@ -57,12 +57,12 @@ mod test {
     #[async_std::test]
     async fn test_approve_shared_invite() {
         let param = KickRequest {
-            channel: "C1234567890".to_string(),
-            user: "W1234567890".to_string(),
+            channel: "C1234567890".into(),
+            user: "W1234567890".into(),
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true
 }"##
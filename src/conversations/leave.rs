@@ -1,13 +1,14 @@
 //! Leaves a conversation.
 
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::ChannelId;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct LeaveRequest {
-    pub channel: String,
+    pub channel: ChannelId,
 }
 
 #[skip_serializing_none]
@@ -29,11 +30,10 @@ pub async fn leave<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("conversations.leave");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("conversations.leave", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<LeaveResponse>(&result).map_err(Error::SerdeJsonError)
@@ -48,7 +48,7 @@ mod test {
     #[test]
     fn convert_request() {
         let request = LeaveRequest {
-            channel: "C1234567890".to_string(),
+            channel: "C1234567890".into(),
         };
         let json = r##"{
   "channel": "C1234567890"
@@ -83,11 +83,11 @@ mod test {
     #[async_std::test]
     async fn test_leave() {
         let param = LeaveRequest {
-            channel: "C1234567890".to_string(),
+            channel: "C1234567890".into(),
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "not_in_channel": true
@@ -2,7 +2,8 @@
 
 use crate::channels::channel::Channel;
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::TeamId;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
@@ -12,7 +13,7 @@ pub struct ListRequest {
     pub cursor: Option<String>,
     pub exclude_archived: Option<bool>,
     pub limit: Option<i32>,
-    pub team_id: Option<String>,
+    pub team_id: Option<TeamId>,
     pub types: Option<String>,
 }
 
@@ -35,11 +36,10 @@ pub async fn list<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("conversations.list");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("conversations.list", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<ListResponse>(&result).map_err(Error::SerdeJsonError)
@@ -58,7 +58,7 @@ mod test {
             cursor: Some("dXNlcjpVMDYxTkZUVDI=".to_string()),
             exclude_archived: Some(true),
             limit: Some(20),
-            team_id: Some("T1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
             types: Some("public_channel,private_channel".to_string()),
         };
         let json = r##"{
@@ -82,11 +82,11 @@ mod test {
             ok: true,
             channels: Some(vec![
                 Channel {
-                    id: Some("C0EAQDV4Z".to_string()),
+                    id: Some("C0EAQDV4Z".into()),
                     name: Some("endeavor".to_string()),
                     is_channel: Some(true),
                     created: Some(1504554479),
-                    creator: Some("U0123456".to_string()),
+                    creator: Some("U0123456".into()),
                     is_archived: Some(false),
                     is_general: Some(false),
                     name_normalized: Some("endeavor".to_string()),
@@ -95,17 +95,17 @@ mod test {
                     is_member: Some(false),
                     is_private: Some(false),
                     is_mpim: Some(false),
-                    last_read: Some("0000000000.000000".to_string()),
+                    last_read: Some("0000000000.000000".into()),
                     unread_count: Some(0),
                     unread_count_display: Some(0),
                     topic: Some(Topic {
                         value: Some("".to_string()),
-                        creator: Some("".to_string()),
+                        creator: Some("".into()),
                         last_set: Some(0),
                     }),
                     purpose: Some(Purpose {
                         value: Some("".to_string()),
-                        creator: Some("".to_string()),
+                        creator: Some("".into()),
                         last_set: Some(0),
                     }),
                     previous_names: Some(vec![]),
@@ -113,11 +113,11 @@ mod test {
                     ..Default::default()
                 },
                 Channel {
-                    id: Some("C0EAQDV4Z".to_string()),
+                    id: Some("C0EAQDV4Z".into()),
                     name: Some("endeavor".to_string()),
                     is_channel: Some(true),
                     created: Some(1504554479),
-                    creator: Some("U0123456".to_string()),
+                    creator: Some("U0123456".into()),
                     is_archived: Some(false),
                     is_general: Some(false),
                     name_normalized: Some("endeavor".to_string()),
@@ -126,17 +126,17 @@ mod test {
                     is_member: Some(false),
                     is_private: Some(false),
                     is_mpim: Some(false),
-                    last_read: Some("0000000000.000000".to_string()),
+                    last_read: Some("0000000000.000000".into()),
                     unread_count: Some(0),
                     unread_count_display: Some(0),
                     topic: Some(Topic {
                         value: Some("".to_string()),
-                        creator: Some("".to_string()),
+                        creator: Some("".into()),
                         last_set: Some(0),
                     }),
                     purpose: Some(Purpose {
                         value: Some("".to_string()),
-                        creator: Some("".to_string()),
+                        creator: Some("".into()),
                         last_set: Some(0),
                     }),
                     previous_names: Some(vec![]),
@@ -225,12 +225,12 @@ mod test {
             cursor: Some("dXNlcjpVMDYxTkZUVDI=".to_string()),
             exclude_archived: Some(true),
             limit: Some(20),
-            team_id: Some("T1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
             types: Some("public_channel,private_channel".to_string()),
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "channels": [
@@ -306,11 +306,11 @@ mod test {
             ok: true,
             channels: Some(vec![
                 Channel {
-                    id: Some("C0EAQDV4Z".to_string()),
+                    id: Some("C0EAQDV4Z".into()),
                     name: Some("endeavor".to_string()),
                     is_channel: Some(true),
                     created: Some(1504554479),
-                    creator: Some("U0123456".to_string()),
+                    creator: Some("U0123456".into()),
                     is_archived: Some(false),
                     is_general: Some(false),
                     name_normalized: Some("endeavor".to_string()),
@@ -319,17 +319,17 @@ mod test {
                     is_member: Some(false),
                     is_private: Some(false),
                     is_mpim: Some(false),
-                    last_read: Some("0000000000.000000".to_string()),
+                    last_read: Some("0000000000.000000".into()),
                     unread_count: Some(0),
                     unread_count_display: Some(0),
                     topic: Some(Topic {
                         value: Some("".to_string()),
-                        creator: Some("".to_string()),
+                        creator: Some("".into()),
                         last_set: Some(0),
                     }),
                     purpose: Some(Purpose {
                         value: Some("".to_string()),
-                        creator: Some("".to_string()),
+                        creator: Some("".into()),
                         last_set: Some(0),
                     }),
                     previous_names: Some(vec![]),
@@ -337,11 +337,11 @@ mod test {
                     ..Default::default()
                 },
                 Channel {
-                    id: Some("C0EAQDV4Z".to_string()),
+                    id: Some("C0EAQDV4Z".into()),
                     name: Some("endeavor".to_string()),
                     is_channel: Some(true),
                     created: Some(1504554479),
-                    creator: Some("U0123456".to_string()),
+                    creator: Some("U0123456".into()),
                     is_archived: Some(false),
                     is_general: Some(false),
                     name_normalized: Some("endeavor".to_string()),
@@ -350,17 +350,17 @@ mod test {
                     is_member: Some(false),
                     is_private: Some(false),
                     is_mpim: Some(false),
-                    last_read: Some("0000000000.000000".to_string()),
+                    last_read: Some("0000000000.000000".into()),
                     unread_count: Some(0),
                     unread_count_display: Some(0),
                     topic: Some(Topic {
                         value: Some("".to_string()),
-                        creator: Some("".to_string()),
+                        creator: Some("".into()),
                         last_set: Some(0),
                     }),
                     purpose: Some(Purpose {
                         value: Some("".to_string()),
-                        creator: Some("".to_string()),
+                        creator: Some("".into()),
                         last_set: Some(0),
                     }),
                     previous_names: Some(vec![]),
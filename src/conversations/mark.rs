@@ -1,13 +1,15 @@
 //! Sets the read cursor in a channel.
 
 use crate::error::Error;
-use crate::http_client::{get_slack_url, DefaultResponse, SlackWebAPIClient};
+use crate::http_client::{DefaultResponse, SlackWebAPIClient};
+use crate::id::ChannelId;
+use crate::ts::Ts;
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct MarkRequest {
-    pub channel: String,
-    pub ts: String,
+    pub channel: ChannelId,
+    pub ts: Ts,
 }
 
 /// Sets the read cursor in a channel.  
@@ -20,11 +22,10 @@ pub async fn mark<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("conversations.mark");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("conversations.mark", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<DefaultResponse>(&result).map_err(Error::SerdeJsonError)
@@ -39,8 +40,8 @@ mod test {
     #[test]
     fn convert_request() {
         let request = MarkRequest {
-            channel: "C012345678".to_string(),
-            ts: "1593473566.000200".to_string(),
+            channel: "C012345678".into(),
+            ts: "1593473566.000200".into(),
         };
         let json = r##"{
   "channel": "C012345678",
@@ -57,12 +58,12 @@ mod test {
     #[async_std::test]
     async fn test_mark() {
         let param = MarkRequest {
-            channel: "C012345678".to_string(),
-            ts: "1593473566.000200".to_string(),
+            channel: "C012345678".into(),
+            ts: "1593473566.000200".into(),
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true
 }"##
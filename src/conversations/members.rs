@@ -1,14 +1,15 @@
 //! Retrieve members of a conversation.
 
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::ChannelId;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct MembersRequest {
-    pub channel: String,
+    pub channel: ChannelId,
     pub cursor: Option<String>,
     pub limit: Option<i32>,
 }
@@ -32,11 +33,10 @@ pub async fn members<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("conversations.members");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("conversations.members", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<MembersResponse>(&result).map_err(Error::SerdeJsonError)
@@ -51,7 +51,7 @@ mod test {
     #[test]
     fn convert_request() {
         let request = MembersRequest {
-            channel: "C1234567890".to_string(),
+            channel: "C1234567890".into(),
             cursor: Some("dXNlcjpVMDYxTkZUVDI=".to_string()),
             limit: Some(20),
         };
@@ -98,13 +98,13 @@ mod test {
     #[async_std::test]
     async fn test_members() {
         let param = MembersRequest {
-            channel: "C1234567890".to_string(),
+            channel: "C1234567890".into(),
             cursor: Some("dXNlcjpVMDYxTkZUVDI=".to_string()),
             limit: Some(20),
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "members": [
@@ -2,14 +2,15 @@
 
 use crate::channels::channel::Channel;
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::ChannelId;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct OpenRequest {
-    pub channel: Option<String>,
+    pub channel: Option<ChannelId>,
     pub prevent_creation: Option<bool>,
     pub return_im: Option<bool>,
     pub users: Option<String>,
@@ -36,11 +37,10 @@ pub async fn open<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("conversations.open");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("conversations.open", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<OpenResponse>(&result).map_err(Error::SerdeJsonError)
@@ -56,7 +56,7 @@ mod test {
     #[test]
     fn convert_request() {
         let request = OpenRequest {
-            channel: Some("G1234567890".to_string()),
+            channel: Some("G1234567890".into()),
             prevent_creation: Some(true),
             return_im: Some(true),
             users: Some("W1234567890,U2345678901,U3456789012".to_string()),
@@ -80,11 +80,11 @@ mod test {
         let response = OpenResponse {
             ok: true,
             channel: Some(Channel {
-                id: Some("C0EAQDV4Z".to_string()),
+                id: Some("C0EAQDV4Z".into()),
                 name: Some("endeavor".to_string()),
                 is_channel: Some(true),
                 created: Some(1504554479),
-                creator: Some("U0123456".to_string()),
+                creator: Some("U0123456".into()),
                 is_archived: Some(false),
                 is_general: Some(false),
                 name_normalized: Some("endeavor".to_string()),
@@ -93,17 +93,17 @@ mod test {
                 is_member: Some(false),
                 is_private: Some(false),
                 is_mpim: Some(false),
-                last_read: Some("0000000000.000000".to_string()),
+                last_read: Some("0000000000.000000".into()),
                 unread_count: Some(0),
                 unread_count_display: Some(0),
                 topic: Some(Topic {
                     value: Some("".to_string()),
-                    creator: Some("".to_string()),
+                    creator: Some("".into()),
                     last_set: Some(0),
                 }),
                 purpose: Some(Purpose {
                     value: Some("".to_string()),
-                    creator: Some("".to_string()),
+                    creator: Some("".into()),
                     last_set: Some(0),
                 }),
                 previous_names: Some(vec![]),
@@ -156,14 +156,14 @@ mod test {
     #[async_std::test]
     async fn test_open() {
         let param = OpenRequest {
-            channel: Some("G1234567890".to_string()),
+            channel: Some("G1234567890".into()),
             prevent_creation: Some(true),
             return_im: Some(true),
             users: Some("W1234567890,U2345678901,U3456789012".to_string()),
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "channel": {
@@ -206,11 +206,11 @@ mod test {
         let expect = OpenResponse {
             ok: true,
             channel: Some(Channel {
-                id: Some("G1234567890".to_string()),
+                id: Some("G1234567890".into()),
                 name: Some("endeavor".to_string()),
                 is_channel: Some(true),
                 created: Some(1504554479),
-                creator: Some("U0123456".to_string()),
+                creator: Some("U0123456".into()),
                 is_archived: Some(false),
                 is_general: Some(false),
                 name_normalized: Some("endeavor".to_string()),
@@ -219,17 +219,17 @@ mod test {
                 is_member: Some(false),
                 is_private: Some(false),
                 is_mpim: Some(false),
-                last_read: Some("0000000000.000000".to_string()),
+                last_read: Some("0000000000.000000".into()),
                 unread_count: Some(0),
                 unread_count_display: Some(0),
                 topic: Some(Topic {
                     value: Some("".to_string()),
-                    creator: Some("".to_string()),
+                    creator: Some("".into()),
                     last_set: Some(0),
                 }),
                 purpose: Some(Purpose {
                     value: Some("".to_string()),
-                    creator: Some("".to_string()),
+                    creator: Some("".into()),
                     last_set: Some(0),
                 }),
                 previous_names: Some(vec![]),
@@ -2,20 +2,22 @@
 
 use crate::chat::message::Message;
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::ChannelId;
+use crate::ts::Ts;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct RepliesRequest {
-    pub channel: String,
-    pub ts: String,
+    pub channel: ChannelId,
+    pub ts: Ts,
     pub cursor: Option<String>,
     pub inclusive: Option<bool>,
-    pub latest: Option<String>,
+    pub latest: Option<Ts>,
     pub limit: Option<i32>,
-    pub oldest: Option<String>,
+    pub oldest: Option<Ts>,
 }
 
 #[skip_serializing_none]
@@ -38,11 +40,10 @@ pub async fn replies<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("conversations.replies");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("conversations.replies", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<RepliesResponse>(&result).map_err(Error::SerdeJsonError)
@@ -57,13 +58,13 @@ mod test {
     #[test]
     fn convert_request() {
         let request = RepliesRequest {
-            channel: "C1234567890".to_string(),
-            ts: "1234567890.123456".to_string(),
+            channel: "C1234567890".into(),
+            ts: "1234567890.123456".into(),
             cursor: Some("dXNlcjpVMDYxTkZUVDI=".to_string()),
             inclusive: Some(true),
-            latest: Some("1234567890.123456".to_string()),
+            latest: Some("1234567890.123456".into()),
             limit: Some(20),
-            oldest: Some("1234567890.123456".to_string()),
+            oldest: Some("1234567890.123456".into()),
         };
         let json = r##"{
   "channel": "C1234567890",
@@ -90,22 +91,22 @@ mod test {
                 Message {
                     type_file: Some("message".to_string()),
                     text: Some("island".to_string()),
-                    user: Some("U061F7AUR".to_string()),
-                    ts: Some("1482960137.003543".to_string()),
+                    user: Some("U061F7AUR".into()),
+                    ts: Some("1482960137.003543".into()),
                     ..Default::default()
                 },
                 Message {
                     type_file: Some("message".to_string()),
                     text: Some("island".to_string()),
-                    user: Some("U061F7AUR".to_string()),
-                    ts: Some("1482960137.003543".to_string()),
+                    user: Some("U061F7AUR".into()),
+                    ts: Some("1482960137.003543".into()),
                     ..Default::default()
                 },
                 Message {
                     type_file: Some("message".to_string()),
                     text: Some("island".to_string()),
-                    user: Some("U061F7AUR".to_string()),
-                    ts: Some("1482960137.003543".to_string()),
+                    user: Some("U061F7AUR".into()),
+                    ts: Some("1482960137.003543".into()),
                     ..Default::default()
                 },
             ]),
@@ -147,16 +148,16 @@ mod test {
     #[async_std::test]
     async fn test_replies() {
         let param = RepliesRequest {
-            channel: "C1234567890".to_string(),
-            ts: "1234567890.123456".to_string(),
+            channel: "C1234567890".into(),
+            ts: "1234567890.123456".into(),
             cursor: Some("dXNlcjpVMDYxTkZUVDI=".to_string()),
             inclusive: Some(true),
-            latest: Some("1234567890.123456".to_string()),
+            latest: Some("1234567890.123456".into()),
             limit: Some(20),
-            oldest: Some("1234567890.123456".to_string()),
+            oldest: Some("1234567890.123456".into()),
         };
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "messages": [
@@ -193,22 +194,22 @@ mod test {
                 Message {
                     type_file: Some("message".to_string()),
                     text: Some("island".to_string()),
-                    user: Some("U061F7AUR".to_string()),
-                    ts: Some("1482960137.003543".to_string()),
+                    user: Some("U061F7AUR".into()),
+                    ts: Some("1482960137.003543".into()),
                     ..Default::default()
                 },
                 Message {
                     type_file: Some("message".to_string()),
                     text: Some("island".to_string()),
-                    user: Some("U061F7AUR".to_string()),
-                    ts: Some("1482960137.003543".to_string()),
+                    user: Some("U061F7AUR".into()),
+                    ts: Some("1482960137.003543".into()),
                     ..Default::default()
                 },
                 Message {
                     type_file: Some("message".to_string()),
                     text: Some("island".to_string()),
-                    user: Some("U061F7AUR".to_string()),
-                    ts: Some("1482960137.003543".to_string()),
+                    user: Some("U061F7AUR".into()),
+                    ts: Some("1482960137.003543".into()),
                     ..Default::default()
                 },
             ]),
@@ -1,13 +1,14 @@
 //! Sets the purpose for a conversation.
 
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::ChannelId;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct SetPurposeRequest {
-    pub channel: String,
+    pub channel: ChannelId,
     pub purpose: String,
 }
 
@@ -30,11 +31,10 @@ pub async fn set_purpose<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("conversations.setPurpose");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("conversations.setPurpose", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<SetPurposeResponse>(&result).map_err(Error::SerdeJsonError)
@@ -49,7 +49,7 @@ mod test {
     #[test]
     fn convert_request() {
         let request = SetPurposeRequest {
-            channel: "C1234567890".to_string(),
+            channel: "C1234567890".into(),
             purpose: "My More Special Purpose".to_string(),
         };
         let json = r##"{
@@ -86,12 +86,12 @@ mod test {
     #[async_std::test]
     async fn test_set_purpose() {
         let param = SetPurposeRequest {
-            channel: "C1234567890".to_string(),
+            channel: "C1234567890".into(),
             purpose: "My More Special Purpose".to_string(),
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "purpose": "I didn't set this purpose on purpose!"
@@ -1,12 +1,13 @@
 //! Reverses conversation archival.
 
 use crate::error::Error;
-use crate::http_client::{get_slack_url, DefaultResponse, SlackWebAPIClient};
+use crate::http_client::{DefaultResponse, SlackWebAPIClient};
+use crate::id::ChannelId;
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct UnarchiveRequest {
-    pub channel: String,
+    pub channel: ChannelId,
 }
 
 /// Reverses conversation archival.  
@@ -19,11 +20,10 @@ pub async fn unarchive<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("conversations.unarchive");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("conversations.unarchive", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<DefaultResponse>(&result).map_err(Error::SerdeJsonError)
@@ -38,7 +38,7 @@ mod test {
     #[test]
     fn convert_request() {
         let request = UnarchiveRequest {
-            channel: "C1234567890".to_string(),
+            channel: "C1234567890".into(),
         };
         let json = r##"{
   "channel": "C1234567890"
@@ -54,11 +54,11 @@ mod test {
     #[async_std::test]
     async fn test_unarchive() {
         let param = UnarchiveRequest {
-            channel: "C1234567890".to_string(),
+            channel: "C1234567890".into(),
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true
 }"##
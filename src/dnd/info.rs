@@ -1,13 +1,14 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::{TeamId, UserId};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct InfoRequest {
-    pub team_id: Option<String>,
-    pub user: Option<String>,
+    pub team_id: Option<TeamId>,
+    pub user: Option<UserId>,
 }
 
 #[skip_serializing_none]
@@ -17,10 +18,10 @@ pub struct InfoResponse {
     pub error: Option<String>,
     pub response_metadata: Option<ResponseMetadata>,
     pub dnd_enabled: Option<bool>,
-    pub next_dnd_start_ts: Option<i32>,
-    pub next_dnd_end_ts: Option<i32>,
+    pub next_dnd_start_ts: Option<i64>,
+    pub next_dnd_end_ts: Option<i64>,
     pub snooze_enabled: Option<bool>,
-    pub snooze_endtime: Option<i32>,
+    pub snooze_endtime: Option<i64>,
     pub snooze_remaining: Option<i16>,
     pub snooze_is_indefinite: Option<bool>,
 }
@@ -33,11 +34,10 @@ pub async fn info<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("dnd.info");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("dnd.info", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<InfoResponse>(&result).map_err(Error::SerdeJsonError)
@@ -52,8 +52,8 @@ mod test {
     #[test]
     fn convert_request() {
         let request = InfoRequest {
-            team_id: Some("T1234567890".to_string()),
-            user: Some("U1234".to_string()),
+            team_id: Some("T1234567890".into()),
+            user: Some("U1234".into()),
         };
         let json = r##"{
   "team_id": "T1234567890",
@@ -101,12 +101,12 @@ mod test {
     #[async_std::test]
     async fn test_info() {
         let param = InfoRequest {
-            team_id: Some("T1234567890".to_string()),
-            user: Some("U1234".to_string()),
+            team_id: Some("T1234567890".into()),
+            user: Some("U1234".into()),
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "dnd_enabled": true,
@@ -4,17 +4,33 @@ use std::fmt;
 
 #[derive(Debug)]
 pub enum Error {
+    #[cfg(feature = "hyper-client")]
+    HyperError(hyper::Error),
     InvalidInputError,
     IOError(std::io::Error),
     NotFoundDomain,
     NotFoundStream,
+    ProxyError(String),
+    #[cfg(feature = "reqwest-client")]
+    ReqwestError(reqwest::Error),
     SerdeJsonError(serde_json::Error),
     SocketModeOpenConnectionError,
+    #[cfg(feature = "surf-client")]
     SurfError(surf::Error),
+    TimeoutError,
+    UnmatchedRequestError(String),
     UrlParseError(url::ParseError),
+    #[cfg(feature = "socket-mode")]
     WebSocketError(async_tungstenite::tungstenite::Error),
 }
 
+#[cfg(feature = "hyper-client")]
+impl From<hyper::Error> for Error {
+    fn from(err: hyper::Error) -> Error {
+        Error::HyperError(err)
+    }
+}
+
 impl From<std::io::Error> for Error {
     fn from(err: std::io::Error) -> Error {
         Error::IOError(err)
@@ -27,6 +43,14 @@ impl From<serde_json::Error> for Error {
     }
 }
 
+#[cfg(feature = "reqwest-client")]
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Error {
+        Error::ReqwestError(err)
+    }
+}
+
+#[cfg(feature = "surf-client")]
 impl From<surf::Error> for Error {
     fn from(err: surf::Error) -> Error {
         Error::SurfError(err)
@@ -39,6 +63,7 @@ impl From<url::ParseError> for Error {
     }
 }
 
+#[cfg(feature = "socket-mode")]
 impl From<async_tungstenite::tungstenite::Error> for Error {
     fn from(err: async_tungstenite::tungstenite::error::Error) -> Error {
         Error::WebSocketError(err)
@@ -48,14 +73,23 @@ impl From<async_tungstenite::tungstenite::Error> for Error {
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
+            #[cfg(feature = "hyper-client")]
+            Error::HyperError(ref e) => write!(f, "Hyper Error: {}", e),
             Error::InvalidInputError => write!(f, "Invalid Input Error"),
             Error::IOError(ref e) => write!(f, "IO Error: {}", e),
             Error::NotFoundDomain => write!(f, "NotFound Domain Error"),
             Error::NotFoundStream => write!(f, "NotFound Stream Error"),
+            Error::ProxyError(ref e) => write!(f, "Proxy Error: {}", e),
+            #[cfg(feature = "reqwest-client")]
+            Error::ReqwestError(ref e) => write!(f, "Reqwest Error: {}", e),
             Error::SerdeJsonError(ref e) => write!(f, "Serde Json Error: {}", e),
             Error::SocketModeOpenConnectionError => write!(f, "SocketMode OpenConnection Error"),
+            #[cfg(feature = "surf-client")]
             Error::SurfError(ref e) => write!(f, "Surf Error: {}", e),
+            Error::TimeoutError => write!(f, "Timeout Error"),
+            Error::UnmatchedRequestError(ref e) => write!(f, "Unmatched Request Error: {}", e),
             Error::UrlParseError(ref e) => write!(f, "Url Parse Error: {}", e),
+            #[cfg(feature = "socket-mode")]
             Error::WebSocketError(ref e) => write!(f, "WebSocket Error: {:?}", e),
         }
     }
@@ -3,6 +3,7 @@ use crate::users::user::User;
 
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use std::collections::HashMap;
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, PartialEq)]
@@ -10,6 +11,8 @@ pub struct AppRequest {
     pub id: Option<String>,
     pub app: Option<App>,
     pub previous_resolution: Option<PreviousResolution>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 #[skip_serializing_none]
@@ -29,6 +32,8 @@ pub struct App {
     pub team: Option<Team>,
     pub scopes: Option<Vec<Scope>>,
     pub message: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 #[skip_serializing_none]
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::collections::HashMap;
+
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct DndStatus {
+    pub dnd_enabled: Option<bool>,
+    pub next_dnd_start_ts: Option<i64>,
+    pub next_dnd_end_ts: Option<i64>,
+    pub snooze_enabled: Option<bool>,
+    pub snooze_endtime: Option<i64>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
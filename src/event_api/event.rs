@@ -1,10 +1,23 @@
 //! [Event API Types](https://api.slack.com/events?filter=Events)
 
 use crate::channels::channel::Channel;
+use crate::chat::message::Message;
 use crate::event_api::app::AppRequest;
+use crate::event_api::dnd::DndStatus;
+use crate::event_api::item::EventItem;
+use crate::event_api::link::SharedLink;
+use crate::event_api::metadata::MessageMetadata;
+use crate::event_api::token::RevokedTokens;
+use crate::event_api::workflow::{WorkflowConfiguration, WorkflowStep};
+use crate::files::file::File;
+use crate::id::{AppId, BotId, ChannelId, FileId, TeamId, UserId, UsergroupId};
+use crate::items::item::Item;
 use crate::team::teams::Team;
+use crate::ts::Ts;
+use crate::usergroups::usergroup::Usergroup;
+use crate::users::user::User;
 use crate::views::view::View;
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use serde_with::skip_serializing_none;
 
 /// [Event API](https://api.slack.com/events?filter=Events)
@@ -12,44 +25,45 @@ use serde_with::skip_serializing_none;
 #[serde(tag = "type")]
 pub struct Event {
     pub token: String,
-    pub team_id: String,
-    pub api_app_id: String,
+    pub team_id: TeamId,
+    pub api_app_id: AppId,
     pub event: EventCallback,
     pub event_id: String,
-    pub event_time: i32,
+    pub event_time: i64,
     #[serde(rename = "type")]
     pub _type: String,
 }
 
-#[derive(Deserialize, Serialize, Debug, PartialEq)]
 #[skip_serializing_none]
-#[serde(rename_all = "snake_case", tag = "type")]
+#[derive(Deserialize, Serialize, Debug, PartialEq)]
+#[serde(rename_all = "snake_case", tag = "type", remote = "Self")]
 pub enum EventCallback {
     /// User clicked into your App Home
     #[serde(rename = "app_home_opened")]
     AppHomeOpened {
-        user: String,
-        channel: String,
-        event_ts: String,
+        user: UserId,
+        channel: ChannelId,
+        event_ts: Ts,
         tab: String,
-        view: View,
+        /// Not present when the Messages tab was opened.
+        view: Option<View>,
     },
     /// Subscribe to only the message events that mention your app or bot
     AppMention {
-        channel: String,
-        event_ts: String,
+        channel: ChannelId,
+        event_ts: Ts,
         text: String,
-        thread_ts: Option<String>,
-        ts: String,
-        user: String,
+        thread_ts: Option<Ts>,
+        ts: Ts,
+        user: UserId,
     },
     /// Indicates your app's event subscriptions are being rate limited
     #[serde(rename = "app_rate_limited")]
     AppRateLimited {
         token: String,
-        team_id: String,
+        team_id: TeamId,
         minute_rate_limited: i32,
-        api_app_id: String,
+        api_app_id: AppId,
     },
     /// User requested an app
     #[serde(rename = "app_requested")]
@@ -58,57 +72,115 @@ pub enum EventCallback {
     AppUninstalled,
     /// A channel was archived
     #[serde(rename = "channel_archive")]
-    ChannelArchive { channel: String, user: String },
+    ChannelArchive { channel: ChannelId, user: UserId },
     /// A channel was created
     #[serde(rename = "channel_created")]
     ChannelCreated { channel: Channel },
     /// A channel was deleted
     #[serde(rename = "channel_deleted")]
-    ChannelDeleted { channel: String },
+    ChannelDeleted { channel: ChannelId },
     /// Bulk updates were made to a channel's history
     #[serde(rename = "channel_history_changed")]
-    ChannelHistoryChanged {
-        latest: String,
-        ts: String,
-        event_ts: String,
-    },
+    ChannelHistoryChanged { latest: Ts, ts: Ts, event_ts: Ts },
     /// A channel ID changed
     #[serde(rename = "channel_id_changed")]
     ChannelIDChanged {
-        old_channel_id: String,
-        new_channel_id: String,
-        event_ts: String,
+        old_channel_id: ChannelId,
+        new_channel_id: ChannelId,
+        event_ts: Ts,
     },
     /// You left a channel
     #[serde(rename = "channel_left")]
-    ChannelLeft { channel: String },
+    ChannelLeft { channel: ChannelId },
     /// A channel was renamed
     #[serde(rename = "channel_rename")]
     ChannelRename { channel: Channel },
     /// A channel has been shared with an external workspace
     #[serde(rename = "channel_shared")]
     ChannelShared {
-        connected_team_id: String,
-        channel: String,
-        event_ts: String,
+        connected_team_id: TeamId,
+        channel: ChannelId,
+        event_ts: Ts,
     },
     /// A channel was unarchived
     #[serde(rename = "channel_unarchive")]
-    ChannelUnarchive { channel: String, user: String },
+    ChannelUnarchive { channel: ChannelId, user: UserId },
     ///A channel has been unshared with an external workspace
     #[serde(rename = "channel_unshared")]
     ChannelUnshared {
-        previously_connected_team_id: String,
-        channel: String,
+        previously_connected_team_id: TeamId,
+        channel: ChannelId,
         is_ext_shared: bool,
-        event_ts: String,
+        event_ts: Ts,
     },
+    /// Do not Disturb settings changed for the current user
+    #[serde(rename = "dnd_updated")]
+    DndUpdated { user: UserId, dnd_status: DndStatus },
+    /// Do not Disturb settings changed for a member
+    #[serde(rename = "dnd_updated_user")]
+    DndUpdatedUser { user: UserId, dnd_status: DndStatus },
     /// A custom emoji has been added or changed
+    ///
+    /// `add` carries `name` and `value`, `remove` carries `names` and `rename` carries `old_name`,
+    /// `new_name` and `value`.
     #[serde(rename = "emoji_changed")]
     EmojiChanged {
         subtype: String,
-        names: Vec<String>,
-        event_ts: String,
+        name: Option<String>,
+        names: Option<Vec<String>>,
+        value: Option<String>,
+        old_name: Option<String>,
+        new_name: Option<String>,
+        event_ts: Ts,
+    },
+    /// A file was changed
+    #[serde(rename = "file_change")]
+    FileChange {
+        file_id: FileId,
+        file: File,
+        user_id: Option<UserId>,
+        event_ts: Option<Ts>,
+    },
+    /// A file was created
+    #[serde(rename = "file_created")]
+    FileCreated {
+        file_id: FileId,
+        file: File,
+        user_id: Option<UserId>,
+        event_ts: Option<Ts>,
+    },
+    /// A file was deleted
+    #[serde(rename = "file_deleted")]
+    FileDeleted {
+        file_id: FileId,
+        channel_ids: Option<Vec<ChannelId>>,
+        event_ts: Ts,
+    },
+    /// A file was made public
+    #[serde(rename = "file_public")]
+    FilePublic {
+        file_id: FileId,
+        file: File,
+        user_id: Option<UserId>,
+        event_ts: Option<Ts>,
+    },
+    /// A file was shared
+    #[serde(rename = "file_shared")]
+    FileShared {
+        file_id: FileId,
+        file: File,
+        user_id: Option<UserId>,
+        channel_id: Option<ChannelId>,
+        event_ts: Option<Ts>,
+    },
+    /// A file was unshared
+    #[serde(rename = "file_unshared")]
+    FileUnshared {
+        file_id: FileId,
+        file: File,
+        user_id: Option<UserId>,
+        channel_id: Option<ChannelId>,
+        event_ts: Option<Ts>,
     },
     /// An enterprise grid migration has finished on this workspace.
     GridMigrationFinished,
@@ -116,100 +188,388 @@ pub enum EventCallback {
     GridMigrationStarted,
     /// A private channel was archived
     #[serde(rename = "group_archive")]
-    GroupArchive { channel: String },
+    GroupArchive { channel: ChannelId },
     /// You closed a private channel
     #[serde(rename = "group_close")]
-    GroupClose { user: String, channel: String },
+    GroupClose { user: UserId, channel: ChannelId },
     /// A private channel was deleted
     #[serde(rename = "group_deleted")]
-    GroupDeleted { channel: String },
+    GroupDeleted { channel: ChannelId },
     /// A private channel was deleted
     #[serde(rename = "group_history_changed")]
-    GroupHistoryChanged {
-        latest: String,
-        ts: String,
-        event_ts: String,
-    },
+    GroupHistoryChanged { latest: Ts, ts: Ts, event_ts: Ts },
     /// You left a private channel
     #[serde(rename = "group_left")]
-    GroupLeft { channel: String },
+    GroupLeft { channel: ChannelId },
     /// You created a group DM
     #[serde(rename = "group_open")]
-    GroupOpen { user: String, channel: String },
+    GroupOpen { user: UserId, channel: ChannelId },
     /// A private channel was renamed
     #[serde(rename = "group_rename")]
     GroupRename { channel: Channel },
     /// A private channel was unarchived
     #[serde(rename = "group_unarchive")]
-    GroupUnarchive { channel: String },
+    GroupUnarchive { channel: ChannelId },
     /// You closed a DM
     #[serde(rename = "im_close")]
-    ImClose { user: String, channel: String },
+    ImClose { user: UserId, channel: ChannelId },
     /// A DM was created
     #[serde(rename = "im_created")]
-    ImCreated { user: String, channel: Channel },
+    ImCreated { user: UserId, channel: Channel },
     /// Bulk updates were made to a DM's history
     #[serde(rename = "im_history_changed")]
-    ImHistoryChanged {
-        latest: String,
-        ts: String,
-        event_ts: String,
-    },
+    ImHistoryChanged { latest: Ts, ts: Ts, event_ts: Ts },
     /// You opened a DM
     #[serde(rename = "im_open")]
-    ImOpen { user: String, channel: String },
+    ImOpen { user: UserId, channel: ChannelId },
     /// User requested an invite
     #[serde(rename = "invite_requested")]
     InviteRequested {
         id: String,
         email: String,
-        date_created: i32,
-        requester_ids: Vec<String>,
-        channel_ids: Vec<String>,
+        date_created: i64,
+        requester_ids: Vec<UserId>,
+        channel_ids: Vec<ChannelId>,
         invite_type: String,
         real_name: String,
-        date_expire: i32,
+        date_expire: i64,
         request_reason: String,
         team: Team,
     },
-    // TODO: To be implemented in the future
     /// A message was posted containing one or more links relevant to your application
+    ///
+    /// `channel` is `COMPOSER` and `message_ts` is not a real message when `source` is `composer`.
     #[serde(rename = "link_shared")]
-    LinkShared,
+    LinkShared {
+        channel: ChannelId,
+        user: Option<UserId>,
+        message_ts: Ts,
+        thread_ts: Option<Ts>,
+        links: Vec<SharedLink>,
+        unfurl_id: Option<String>,
+        source: Option<String>,
+        is_bot_user_member: Option<bool>,
+        event_ts: Option<Ts>,
+    },
     /// A user joined a public or private channel
     #[serde(rename = "member_joined_channel")]
     MemberJoinedChannel {
-        user: String,
-        channel: String,
+        user: UserId,
+        channel: ChannelId,
         channel_type: String,
-        team: String,
-        inviter: String,
+        team: TeamId,
+        inviter: UserId,
     },
     /// A user left a public or private channel
-    #[serde(rename = "member_joined_channel")]
+    #[serde(rename = "member_left_channel")]
     MemberLeftChannel {
-        user: String,
-        channel: String,
+        user: UserId,
+        channel: ChannelId,
         channel_type: String,
-        team: String,
+        team: TeamId,
     },
     /// A message was sent to a channel
+    ///
+    /// `message.subtype` tells edits, deletions, bot messages and the like apart.
     Message {
-        channel_type: String,
-        channel: String,
-        event_ts: String,
-        text: String,
-        thread_ts: Option<String>,
-        ts: String,
-        user: String,
+        channel_type: Option<String>,
+        channel: Option<ChannelId>,
+        event_ts: Option<Ts>,
+        #[serde(flatten)]
+        message: Message,
+    },
+    /// Message metadata was deleted
+    #[serde(rename = "message_metadata_deleted")]
+    MessageMetadataDeleted {
+        app_id: AppId,
+        bot_id: BotId,
+        user_id: Option<UserId>,
+        team_id: TeamId,
+        channel_id: ChannelId,
+        previous_metadata: MessageMetadata,
+        message_ts: Ts,
+        event_ts: Ts,
+        deleted_ts: Ts,
+    },
+    /// Message metadata was posted
+    #[serde(rename = "message_metadata_posted")]
+    MessageMetadataPosted {
+        app_id: AppId,
+        bot_id: BotId,
+        user_id: Option<UserId>,
+        team_id: TeamId,
+        channel_id: ChannelId,
+        metadata: MessageMetadata,
+        message_ts: Ts,
+        event_ts: Ts,
+    },
+    /// Message metadata was updated
+    #[serde(rename = "message_metadata_updated")]
+    MessageMetadataUpdated {
+        app_id: AppId,
+        bot_id: BotId,
+        user_id: Option<UserId>,
+        team_id: TeamId,
+        channel_id: ChannelId,
+        previous_metadata: MessageMetadata,
+        metadata: MessageMetadata,
+        message_ts: Ts,
+        event_ts: Ts,
+    },
+    /// A pin was added to a channel
+    #[serde(rename = "pin_added")]
+    PinAdded {
+        user: UserId,
+        channel_id: ChannelId,
+        item: Item,
+        item_user: Option<UserId>,
+        pin_count: Option<i32>,
+        event_ts: Ts,
+    },
+    /// A pin was removed from a channel
+    #[serde(rename = "pin_removed")]
+    PinRemoved {
+        user: UserId,
+        channel_id: ChannelId,
+        item: Item,
+        item_user: Option<UserId>,
+        pin_count: Option<i32>,
+        has_pins: Option<bool>,
+        event_ts: Ts,
+    },
+    /// A member has added an emoji reaction to an item
+    #[serde(rename = "reaction_added")]
+    ReactionAdded {
+        user: UserId,
+        reaction: String,
+        item_user: Option<UserId>,
+        item: EventItem,
+        event_ts: Ts,
+    },
+    /// A member removed an emoji reaction
+    #[serde(rename = "reaction_removed")]
+    ReactionRemoved {
+        user: UserId,
+        reaction: String,
+        item_user: Option<UserId>,
+        item: EventItem,
+        event_ts: Ts,
+    },
+    /// A member has saved an item for later or starred an item
+    #[serde(rename = "star_added")]
+    StarAdded {
+        user: UserId,
+        item: Item,
+        event_ts: Ts,
+    },
+    /// A member has removed an item saved for later or starred an item
+    #[serde(rename = "star_removed")]
+    StarRemoved {
+        user: UserId,
+        item: Item,
+        event_ts: Ts,
+    },
+    /// A User Group has been added to the workspace
+    #[serde(rename = "subteam_created")]
+    SubteamCreated { subteam: Usergroup },
+    /// The membership of an existing User Group has changed
+    #[serde(rename = "subteam_members_changed")]
+    SubteamMembersChanged {
+        subteam_id: UsergroupId,
+        team_id: TeamId,
+        date_previous_update: i64,
+        date_update: i64,
+        added_users: Option<Vec<UserId>>,
+        added_users_count: Option<i32>,
+        removed_users: Option<Vec<UserId>>,
+        removed_users_count: Option<i32>,
+        event_ts: Option<Ts>,
+    },
+    /// You have been added to a User Group
+    #[serde(rename = "subteam_self_added")]
+    SubteamSelfAdded { subteam_id: UsergroupId },
+    /// You have been removed from a User Group
+    #[serde(rename = "subteam_self_removed")]
+    SubteamSelfRemoved { subteam_id: UsergroupId },
+    /// An existing User Group has been updated or its members changed
+    #[serde(rename = "subteam_updated")]
+    SubteamUpdated { subteam: Usergroup },
+    /// A new member has joined
+    #[serde(rename = "team_join")]
+    TeamJoin { user: User },
+    /// The workspace name has changed
+    #[serde(rename = "team_rename")]
+    TeamRename {
+        name: String,
+        team_id: Option<TeamId>,
+    },
+    /// API tokens for your app were revoked
+    #[serde(rename = "tokens_revoked")]
+    TokensRevoked { tokens: RevokedTokens },
+    /// A member's data has changed
+    #[serde(rename = "user_change")]
+    UserChange {
+        user: User,
+        cache_ts: Option<i64>,
+        event_ts: Option<Ts>,
     },
-    #[serde(other)]
-    Other,
+    /// A member's custom status has changed
+    #[serde(rename = "user_status_changed")]
+    UserStatusChanged {
+        user: User,
+        cache_ts: Option<i64>,
+        event_ts: Option<Ts>,
+    },
+    /// A workflow that contains a step supported by your app was deleted
+    #[serde(rename = "workflow_deleted")]
+    WorkflowDeleted {
+        workflow_id: String,
+        workflow_draft_configuration: WorkflowConfiguration,
+        event_ts: Ts,
+    },
+    /// A workflow that contains a step supported by your app was published
+    #[serde(rename = "workflow_published")]
+    WorkflowPublished {
+        workflow_id: String,
+        workflow_published_configuration: WorkflowConfiguration,
+        event_ts: Ts,
+    },
+    /// A workflow step supported by your app was removed from a workflow
+    #[serde(rename = "workflow_step_deleted")]
+    WorkflowStepDeleted {
+        workflow_id: String,
+        workflow_draft_configuration: WorkflowConfiguration,
+        workflow_published_configuration: Option<WorkflowConfiguration>,
+        event_ts: Ts,
+    },
+    /// A workflow step supported by your app should execute
+    #[serde(rename = "workflow_step_execute")]
+    WorkflowStepExecute {
+        callback_id: String,
+        workflow_step: WorkflowStep,
+        event_ts: Ts,
+    },
+    /// A workflow that contains a step supported by your app was unpublished
+    #[serde(rename = "workflow_unpublished")]
+    WorkflowUnpublished {
+        workflow_id: String,
+        workflow_draft_configuration: WorkflowConfiguration,
+        event_ts: Ts,
+    },
+    /// An event this crate doesn't model
+    #[serde(skip)]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
+}
+
+/// The `type` tags of the [`EventCallback`] variants other than [`EventCallback::Unknown`].
+const EVENT_TYPES: &[&str] = &[
+    "app_home_opened",
+    "app_mention",
+    "app_rate_limited",
+    "app_requested",
+    "app_uninstalled",
+    "channel_archive",
+    "channel_created",
+    "channel_deleted",
+    "channel_history_changed",
+    "channel_id_changed",
+    "channel_left",
+    "channel_rename",
+    "channel_shared",
+    "channel_unarchive",
+    "channel_unshared",
+    "dnd_updated",
+    "dnd_updated_user",
+    "emoji_changed",
+    "file_change",
+    "file_created",
+    "file_deleted",
+    "file_public",
+    "file_shared",
+    "file_unshared",
+    "grid_migration_finished",
+    "grid_migration_started",
+    "group_archive",
+    "group_close",
+    "group_deleted",
+    "group_history_changed",
+    "group_left",
+    "group_open",
+    "group_rename",
+    "group_unarchive",
+    "im_close",
+    "im_created",
+    "im_history_changed",
+    "im_open",
+    "invite_requested",
+    "link_shared",
+    "member_joined_channel",
+    "member_left_channel",
+    "message",
+    "message_metadata_deleted",
+    "message_metadata_posted",
+    "message_metadata_updated",
+    "pin_added",
+    "pin_removed",
+    "reaction_added",
+    "reaction_removed",
+    "star_added",
+    "star_removed",
+    "subteam_created",
+    "subteam_members_changed",
+    "subteam_self_added",
+    "subteam_self_removed",
+    "subteam_updated",
+    "team_join",
+    "team_rename",
+    "tokens_revoked",
+    "user_change",
+    "user_status_changed",
+    "workflow_deleted",
+    "workflow_published",
+    "workflow_step_deleted",
+    "workflow_step_execute",
+    "workflow_unpublished",
+];
+
+impl<'de> Deserialize<'de> for EventCallback {
+    fn deserialize<D>(deserializer: D) -> Result<EventCallback, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = serde_json::Value::deserialize(deserializer)?;
+        let type_name = raw
+            .get("type")
+            .and_then(|t| t.as_str())
+            .ok_or_else(|| de::Error::missing_field("type"))?
+            .to_string();
+        if !EVENT_TYPES.contains(&type_name.as_str()) {
+            return Ok(EventCallback::Unknown { type_name, raw });
+        }
+        // A modelled event with a malformed payload is an error, not an unknown event.
+        EventCallback::deserialize(&raw).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for EventCallback {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            EventCallback::Unknown { raw, .. } => raw.serialize(serializer),
+            event => EventCallback::serialize(event, serializer),
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::chat::message::MessageSubtype;
 
     #[test]
     fn deserialize_app_home_opened_event() {
@@ -244,7 +604,7 @@ mod test {
                 assert_eq!(channel, "D0LAN2Q65");
                 assert_eq!(event_ts, "1515449522000016");
                 assert_eq!(tab, "home");
-                assert_eq!(view.id.unwrap(), "VPASKP233");
+                assert_eq!(view.unwrap().id.unwrap(), "VPASKP233");
             }
             _ => panic!("Event callback deserialize into incorrect variant"),
         }
@@ -266,8 +626,1248 @@ mod test {
 
         let event = serde_json::from_str::<Event>(json).unwrap();
         match event.event {
-            EventCallback::Other => assert!(true, "true"),
+            EventCallback::Unknown { type_name, raw } => {
+                assert_eq!(type_name, "other");
+                assert_eq!(raw, serde_json::json!({"type": "other"}));
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+    }
+
+    fn deserialize_event_callback(event: &str) -> EventCallback {
+        let json = format!(
+            r##"{{
+  "token": "bHKJ2n9AW6Ju3MjciOHfbA1b",
+  "team_id": "T1234567890",
+  "api_app_id": "A0000000000",
+  "event_id": "Ev0000000000",
+  "event_time": 1600000000,
+  "type": "event_callback",
+  "event": {}
+}}"##,
+            event
+        );
+        serde_json::from_str::<Event>(&json).unwrap().event
+    }
+
+    #[test]
+    fn deserialize_app_home_opened_messages_tab_event() {
+        let json = r##"{
+  "type": "app_home_opened",
+  "user": "U061F7AUR",
+  "channel": "D0LAN2Q65",
+  "event_ts": "1515449522000016",
+  "tab": "messages"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::AppHomeOpened { tab, view, .. } => {
+                assert_eq!(tab, "messages");
+                assert_eq!(view, None);
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+    }
+
+    #[test]
+    fn deserialize_dnd_updated_event() {
+        let json = r##"{
+  "type": "dnd_updated",
+  "user": "U1234",
+  "dnd_status": {
+    "dnd_enabled": true,
+    "next_dnd_start_ts": 1450387800,
+    "next_dnd_end_ts": 1450423800,
+    "snooze_enabled": true,
+    "snooze_endtime": 1450373897
+  }
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::DndUpdated { user, dnd_status } => {
+                assert_eq!(user, "U1234");
+                assert_eq!(dnd_status.dnd_enabled, Some(true));
+                assert_eq!(dnd_status.next_dnd_start_ts, Some(1450387800));
+                assert_eq!(dnd_status.snooze_endtime, Some(1450373897));
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+
+        let json = r##"{
+  "type": "dnd_updated_user",
+  "user": "U1234",
+  "dnd_status": {
+    "dnd_enabled": true,
+    "next_dnd_start_ts": 1450387800,
+    "next_dnd_end_ts": 1450423800
+  }
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::DndUpdatedUser { dnd_status, .. } => {
+                assert_eq!(dnd_status.next_dnd_end_ts, Some(1450423800));
+                assert_eq!(dnd_status.snooze_enabled, None);
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+    }
+
+    #[test]
+    fn deserialize_emoji_changed_event() {
+        let json = r##"{
+  "type": "emoji_changed",
+  "subtype": "add",
+  "name": "picard_facepalm",
+  "value": "https://my.slack.com/emoji/picard_facepalm/db8e287430eaa459.gif",
+  "event_ts": "1361482916.000004"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::EmojiChanged {
+                subtype,
+                name,
+                value,
+                ..
+            } => {
+                assert_eq!(subtype, "add");
+                assert_eq!(name.unwrap(), "picard_facepalm");
+                assert_eq!(
+                    value.unwrap(),
+                    "https://my.slack.com/emoji/picard_facepalm/db8e287430eaa459.gif"
+                );
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+
+        let json = r##"{
+  "type": "emoji_changed",
+  "subtype": "remove",
+  "names": ["picard_facepalm"],
+  "event_ts": "1361482916.000004"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::EmojiChanged { subtype, names, .. } => {
+                assert_eq!(subtype, "remove");
+                assert_eq!(names.unwrap(), vec!["picard_facepalm".to_string()]);
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+
+        let json = r##"{
+  "type": "emoji_changed",
+  "subtype": "rename",
+  "old_name": "picard_facepalm",
+  "new_name": "captain_picard_facepalm",
+  "value": "https://my.slack.com/emoji/picard_facepalm/db8e287430eaa459.gif",
+  "event_ts": "1361482916.000004"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::EmojiChanged {
+                old_name, new_name, ..
+            } => {
+                assert_eq!(old_name.unwrap(), "picard_facepalm");
+                assert_eq!(new_name.unwrap(), "captain_picard_facepalm");
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+    }
+
+    #[test]
+    fn deserialize_file_events() {
+        let json = r##"{
+  "type": "file_created",
+  "file_id": "F2147483862",
+  "file": {
+    "id": "F2147483862"
+  },
+  "user_id": "U061F7AUR",
+  "event_ts": "1361482916.000004"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::FileCreated {
+                file_id,
+                file,
+                user_id,
+                ..
+            } => {
+                assert_eq!(file_id, "F2147483862");
+                assert_eq!(file.id.unwrap(), "F2147483862");
+                assert_eq!(user_id.unwrap(), "U061F7AUR");
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+
+        let json = r##"{
+  "type": "file_shared",
+  "file_id": "F2147483862",
+  "file": {
+    "id": "F2147483862"
+  },
+  "user_id": "U061F7AUR",
+  "channel_id": "C0LAN2Q65",
+  "event_ts": "1361482916.000004"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::FileShared { channel_id, .. } => {
+                assert_eq!(channel_id.unwrap(), "C0LAN2Q65");
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+
+        let json = r##"{
+  "type": "file_change",
+  "file_id": "F2147483862",
+  "file": {
+    "id": "F2147483862"
+  }
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::FileChange { file_id, .. } => assert_eq!(file_id, "F2147483862"),
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+
+        let json = r##"{
+  "type": "file_deleted",
+  "file_id": "F2147483862",
+  "channel_ids": ["C0LAN2Q65"],
+  "event_ts": "1361482916.000004"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::FileDeleted {
+                file_id,
+                channel_ids,
+                event_ts,
+            } => {
+                assert_eq!(file_id, "F2147483862");
+                assert_eq!(channel_ids.unwrap(), vec!["C0LAN2Q65".to_string()]);
+                assert_eq!(event_ts, "1361482916.000004");
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+    }
+
+    #[test]
+    fn deserialize_link_shared_event() {
+        let json = r##"{
+  "type": "link_shared",
+  "channel": "Cxxxxxx",
+  "is_bot_user_member": true,
+  "user": "Uxxxxxxx",
+  "message_ts": "123456789.9875",
+  "unfurl_id": "C123456.123456789.987501.1b90fa1278528ce6e2f6c5c2bfa1abc9a41d57d02b29d173f40399c9ffdecf4b",
+  "thread_ts": "123456621.1855",
+  "source": "conversations_history",
+  "links": [
+    {
+      "domain": "example.com",
+      "url": "https://example.com/12345"
+    },
+    {
+      "domain": "another-example.com",
+      "url": "https://yet.another-example.com/v/abcde"
+    }
+  ],
+  "event_ts": "123456789.9875"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::LinkShared {
+                channel,
+                message_ts,
+                links,
+                unfurl_id,
+                source,
+                is_bot_user_member,
+                ..
+            } => {
+                assert_eq!(channel, "Cxxxxxx");
+                assert_eq!(message_ts, "123456789.9875");
+                assert_eq!(links.len(), 2);
+                assert_eq!(links[0].domain, "example.com");
+                assert_eq!(links[1].url, "https://yet.another-example.com/v/abcde");
+                assert!(unfurl_id.unwrap().starts_with("C123456.123456789"));
+                assert_eq!(source.unwrap(), "conversations_history");
+                assert_eq!(is_bot_user_member, Some(true));
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+    }
+
+    #[test]
+    fn deserialize_member_left_channel_event() {
+        let json = r##"{
+  "type": "member_left_channel",
+  "user": "W06GH7XHN",
+  "channel": "C0698JE0H",
+  "channel_type": "C",
+  "team": "T024BE7LD"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::MemberLeftChannel { user, channel, .. } => {
+                assert_eq!(user, "W06GH7XHN");
+                assert_eq!(channel, "C0698JE0H");
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+    }
+
+    #[test]
+    fn deserialize_message_metadata_events() {
+        let json = r##"{
+  "type": "message_metadata_posted",
+  "app_id": "AQF4F123M",
+  "bot_id": "BQ7JX1AJ3",
+  "user_id": "UCF1A2KCG",
+  "team_id": "TCF2A71AA",
+  "channel_id": "C015DT1L7FF",
+  "metadata": {
+    "event_type": "task_created",
+    "event_payload": {
+      "id": "11223",
+      "title": "Redesign Homepage"
+    }
+  },
+  "message_ts": "1658387473.102939",
+  "event_ts": "1658387473.102939"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::MessageMetadataPosted {
+                app_id,
+                channel_id,
+                metadata,
+                message_ts,
+                ..
+            } => {
+                assert_eq!(app_id, "AQF4F123M");
+                assert_eq!(channel_id, "C015DT1L7FF");
+                assert_eq!(metadata.event_type, "task_created");
+                assert_eq!(metadata.event_payload.unwrap()["id"], "11223");
+                assert_eq!(message_ts, "1658387473.102939");
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+
+        let json = r##"{
+  "type": "message_metadata_updated",
+  "app_id": "AQF4F123M",
+  "bot_id": "BQ7JX1AJ3",
+  "user_id": "UCF1A2KCG",
+  "team_id": "TCF2A71AA",
+  "channel_id": "C015DT1L7FF",
+  "previous_metadata": {
+    "event_type": "task_created",
+    "event_payload": {
+      "id": "11223"
+    }
+  },
+  "metadata": {
+    "event_type": "task_changed",
+    "event_payload": {
+      "id": "11223"
+    }
+  },
+  "message_ts": "1658387473.102939",
+  "event_ts": "1658387473.102940"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::MessageMetadataUpdated {
+                previous_metadata,
+                metadata,
+                ..
+            } => {
+                assert_eq!(previous_metadata.event_type, "task_created");
+                assert_eq!(metadata.event_type, "task_changed");
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+
+        let json = r##"{
+  "type": "message_metadata_deleted",
+  "app_id": "AQF4F123M",
+  "bot_id": "BQ7JX1AJ3",
+  "team_id": "TCF2A71AA",
+  "channel_id": "C015DT1L7FF",
+  "previous_metadata": {
+    "event_type": "task_created"
+  },
+  "message_ts": "1658387473.102939",
+  "event_ts": "1658387473.102941",
+  "deleted_ts": "1658387473.102941"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::MessageMetadataDeleted {
+                user_id,
+                previous_metadata,
+                deleted_ts,
+                ..
+            } => {
+                assert_eq!(user_id, None);
+                assert_eq!(previous_metadata.event_payload, None);
+                assert_eq!(deleted_ts, "1658387473.102941");
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+    }
+
+    #[test]
+    fn deserialize_pin_events() {
+        let json = r##"{
+  "type": "pin_added",
+  "user": "U024BE7LH",
+  "channel_id": "C02ELGNBH",
+  "item": {
+    "type": "message",
+    "channel": "C02ELGNBH",
+    "message": {
+      "type": "message",
+      "text": "pinned",
+      "ts": "1360782400.498405"
+    }
+  },
+  "item_user": "U024BE7LV",
+  "pin_count": 1,
+  "event_ts": "1360782804.083113"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::PinAdded {
+                user,
+                channel_id,
+                item,
+                pin_count,
+                ..
+            } => {
+                assert_eq!(user, "U024BE7LH");
+                assert_eq!(channel_id, "C02ELGNBH");
+                assert_eq!(item.type_filed.unwrap(), "message");
+                assert_eq!(item.message.unwrap().text.unwrap(), "pinned");
+                assert_eq!(pin_count, Some(1));
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+
+        let json = r##"{
+  "type": "pin_removed",
+  "user": "U024BE7LH",
+  "channel_id": "C02ELGNBH",
+  "item": {
+    "type": "message",
+    "channel": "C02ELGNBH"
+  },
+  "pin_count": 0,
+  "has_pins": false,
+  "event_ts": "1360782845.083114"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::PinRemoved { has_pins, .. } => assert_eq!(has_pins, Some(false)),
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+    }
+
+    #[test]
+    fn deserialize_reaction_events() {
+        let json = r##"{
+  "type": "reaction_added",
+  "user": "U024BE7LH",
+  "reaction": "thumbsup",
+  "item_user": "U0G9QF9C6",
+  "item": {
+    "type": "message",
+    "channel": "C0G9QF9GZ",
+    "ts": "1360782400.498405"
+  },
+  "event_ts": "1360782804.083113"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::ReactionAdded {
+                user,
+                reaction,
+                item_user,
+                item,
+                event_ts,
+            } => {
+                assert_eq!(user, "U024BE7LH");
+                assert_eq!(reaction, "thumbsup");
+                assert_eq!(item_user.unwrap(), "U0G9QF9C6");
+                assert_eq!(item.type_filed, "message");
+                assert_eq!(item.channel.unwrap(), "C0G9QF9GZ");
+                assert_eq!(item.ts.unwrap(), "1360782400.498405");
+                assert_eq!(event_ts, "1360782804.083113");
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+
+        let json = r##"{
+  "type": "reaction_removed",
+  "user": "U024BE7LH",
+  "reaction": "thumbsup",
+  "item": {
+    "type": "file",
+    "file": "F0HS27V1Z"
+  },
+  "event_ts": "1360782804.083113"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::ReactionRemoved { item, .. } => {
+                assert_eq!(item.type_filed, "file");
+                assert_eq!(item.file.unwrap(), "F0HS27V1Z");
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+    }
+
+    #[test]
+    fn deserialize_star_events() {
+        let json = r##"{
+  "type": "star_added",
+  "user": "U024BE7LH",
+  "item": {
+    "type": "channel",
+    "channel": "C2147483705"
+  },
+  "event_ts": "1360782804.083113"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::StarAdded { user, item, .. } => {
+                assert_eq!(user, "U024BE7LH");
+                assert_eq!(item.channel.unwrap(), "C2147483705");
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+
+        let json = r##"{
+  "type": "star_removed",
+  "user": "U024BE7LH",
+  "item": {
+    "type": "channel",
+    "channel": "C2147483705"
+  },
+  "event_ts": "1360782804.083113"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::StarRemoved { event_ts, .. } => {
+                assert_eq!(event_ts, "1360782804.083113")
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+    }
+
+    #[test]
+    fn deserialize_subteam_events() {
+        let json = r##"{
+  "type": "subteam_created",
+  "subteam": {
+    "id": "S0615G0KT",
+    "team_id": "T060RNRCH",
+    "is_usergroup": true,
+    "name": "Marketing Team",
+    "handle": "marketing-team"
+  }
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::SubteamCreated { subteam } => {
+                assert_eq!(subteam.id.unwrap(), "S0615G0KT");
+                assert_eq!(subteam.handle.unwrap(), "marketing-team");
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+
+        let json = r##"{
+  "type": "subteam_updated",
+  "subteam": {
+    "id": "S0614TZR7",
+    "name": "Team Admins"
+  }
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::SubteamUpdated { subteam } => {
+                assert_eq!(subteam.name.unwrap(), "Team Admins")
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+
+        let json = r##"{
+  "type": "subteam_members_changed",
+  "subteam_id": "S0614TZR7",
+  "team_id": "T060RNRCH",
+  "date_previous_update": 1446670362,
+  "date_update": 1492906952,
+  "added_users": ["U060RNRCZ", "U060ULRC0"],
+  "added_users_count": 2,
+  "removed_users": ["U06129G2V"],
+  "removed_users_count": 1
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::SubteamMembersChanged {
+                subteam_id,
+                date_update,
+                added_users,
+                removed_users_count,
+                ..
+            } => {
+                assert_eq!(subteam_id, "S0614TZR7");
+                assert_eq!(date_update, 1492906952);
+                assert_eq!(added_users.unwrap().len(), 2);
+                assert_eq!(removed_users_count, Some(1));
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+
+        let json = r##"{
+  "type": "subteam_self_added",
+  "subteam_id": "S0615G0KT"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::SubteamSelfAdded { subteam_id } => assert_eq!(subteam_id, "S0615G0KT"),
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+
+        let json = r##"{
+  "type": "subteam_self_removed",
+  "subteam_id": "S0615G0KT"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::SubteamSelfRemoved { subteam_id } => {
+                assert_eq!(subteam_id, "S0615G0KT")
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+    }
+
+    #[test]
+    fn deserialize_team_events() {
+        let json = r##"{
+  "type": "team_join",
+  "user": {
+    "id": "W012A3CDE",
+    "name": "spengler"
+  }
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::TeamJoin { user } => {
+                assert_eq!(user.id.unwrap(), "W012A3CDE");
+                assert_eq!(user.name.unwrap(), "spengler");
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+
+        let json = r##"{
+  "type": "team_rename",
+  "name": "New Team Name Inc.",
+  "team_id": "T1234"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::TeamRename { name, team_id } => {
+                assert_eq!(name, "New Team Name Inc.");
+                assert_eq!(team_id.unwrap(), "T1234");
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+    }
+
+    #[test]
+    fn deserialize_tokens_revoked_event() {
+        let json = r##"{
+  "type": "tokens_revoked",
+  "tokens": {
+    "oauth": ["UXXXXXXXX"],
+    "bot": ["UXXXXXXXX"]
+  }
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::TokensRevoked { tokens } => {
+                assert_eq!(tokens.oauth.unwrap(), vec!["UXXXXXXXX".to_string()]);
+                assert_eq!(tokens.bot.unwrap(), vec!["UXXXXXXXX".to_string()]);
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+    }
+
+    #[test]
+    fn deserialize_user_events() {
+        let json = r##"{
+  "type": "user_change",
+  "user": {
+    "id": "U1234567",
+    "name": "some-user",
+    "deleted": false
+  },
+  "cache_ts": 1578087416,
+  "event_ts": "1578087416.000800"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::UserChange {
+                user,
+                cache_ts,
+                event_ts,
+            } => {
+                assert_eq!(user.id.unwrap(), "U1234567");
+                assert_eq!(cache_ts, Some(1578087416));
+                assert_eq!(event_ts.unwrap(), "1578087416.000800");
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+
+        let json = r##"{
+  "type": "user_status_changed",
+  "user": {
+    "id": "U1234567",
+    "profile": {
+      "status_text": "riding a train",
+      "status_emoji": ":mountain_railway:"
+    }
+  },
+  "cache_ts": 1578087416,
+  "event_ts": "1578087416.000800"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::UserStatusChanged { user, .. } => {
+                let profile = user.profile.unwrap();
+                assert_eq!(profile.status_text.unwrap(), "riding a train");
+                assert_eq!(profile.status_emoji.unwrap(), ":mountain_railway:");
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+    }
+
+    #[test]
+    fn deserialize_workflow_events() {
+        let json = r##"{
+  "type": "workflow_published",
+  "workflow_id": "Wf12345678",
+  "workflow_published_configuration": {
+    "version_id": "Wfv12345678",
+    "app_steps": [
+      {
+        "app_id": "A12345678",
+        "workflow_step_id": "Ws12345678",
+        "callback_id": "post_message"
+      }
+    ]
+  },
+  "event_ts": "1617836000.000000"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::WorkflowPublished {
+                workflow_id,
+                workflow_published_configuration,
+                ..
+            } => {
+                assert_eq!(workflow_id, "Wf12345678");
+                assert_eq!(
+                    workflow_published_configuration.version_id.unwrap(),
+                    "Wfv12345678"
+                );
+                let steps = workflow_published_configuration.app_steps.unwrap();
+                assert_eq!(steps[0].callback_id.as_ref().unwrap(), "post_message");
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+
+        for type_name in &[
+            "workflow_unpublished",
+            "workflow_deleted",
+            "workflow_step_deleted",
+        ] {
+            let json = format!(
+                r##"{{
+  "type": "{}",
+  "workflow_id": "Wf12345678",
+  "workflow_draft_configuration": {{
+    "version_id": "Wfv12345678",
+    "app_steps": []
+  }},
+  "event_ts": "1617836000.000000"
+}}"##,
+                type_name
+            );
+            match deserialize_event_callback(&json) {
+                EventCallback::WorkflowUnpublished { workflow_id, .. }
+                | EventCallback::WorkflowDeleted { workflow_id, .. }
+                | EventCallback::WorkflowStepDeleted { workflow_id, .. } => {
+                    assert_eq!(workflow_id, "Wf12345678")
+                }
+                _ => panic!("Event callback deserialize into incorrect variant"),
+            }
+        }
+
+        let json = r##"{
+  "type": "workflow_step_execute",
+  "callback_id": "post_message",
+  "workflow_step": {
+    "workflow_step_execute_id": "1234567890.123",
+    "workflow_id": "Wf12345678",
+    "workflow_instance_id": "1234567890",
+    "step_id": "abcd-efgh",
+    "inputs": {
+      "channel": {
+        "value": "C12345678"
+      }
+    },
+    "outputs": [
+      {
+        "name": "message_ts",
+        "type": "text",
+        "label": "Message timestamp"
+      }
+    ]
+  },
+  "event_ts": "1617836000.000000"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::WorkflowStepExecute {
+                callback_id,
+                workflow_step,
+                ..
+            } => {
+                assert_eq!(callback_id, "post_message");
+                assert_eq!(workflow_step.step_id.unwrap(), "abcd-efgh");
+                assert_eq!(
+                    workflow_step.inputs.unwrap()["channel"].value,
+                    Some(serde_json::json!("C12345678"))
+                );
+                let outputs = workflow_step.outputs.unwrap();
+                assert_eq!(outputs[0].type_filed.as_ref().unwrap(), "text");
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+    }
+
+    #[test]
+    fn deserialize_message_event() {
+        let json = r##"{
+  "type": "message",
+  "channel": "C123ABC456",
+  "user": "U123ABC456",
+  "text": "Hello world",
+  "ts": "1355517523.000005",
+  "event_ts": "1355517523.000005",
+  "channel_type": "channel"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::Message {
+                channel_type,
+                channel,
+                event_ts,
+                message,
+            } => {
+                assert_eq!(channel_type.unwrap(), "channel");
+                assert_eq!(channel.unwrap(), "C123ABC456");
+                assert_eq!(event_ts.unwrap(), "1355517523.000005");
+                assert_eq!(message.user.unwrap(), "U123ABC456");
+                assert_eq!(message.text.unwrap(), "Hello world");
+                assert_eq!(message.subtype, None);
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+    }
+
+    #[test]
+    fn deserialize_message_subtype_events() {
+        let json = r##"{
+  "type": "message",
+  "subtype": "message_changed",
+  "hidden": true,
+  "channel": "C123ABC456",
+  "ts": "1358878755.000001",
+  "message": {
+    "type": "message",
+    "user": "U123ABC456",
+    "text": "Hello, world!",
+    "ts": "1355517523.000005",
+    "edited": {
+      "user": "U123ABC456",
+      "ts": "1358878755.000001"
+    }
+  },
+  "previous_message": {
+    "type": "message",
+    "user": "U123ABC456",
+    "text": "Hello world",
+    "ts": "1355517523.000005"
+  }
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::Message { message, .. } => match message.subtype.unwrap() {
+                MessageSubtype::MessageChanged {
+                    message,
+                    previous_message,
+                    hidden,
+                } => {
+                    assert_eq!(message.text.unwrap(), "Hello, world!");
+                    assert_eq!(message.edited.unwrap().ts.unwrap(), "1358878755.000001");
+                    assert_eq!(previous_message.unwrap().text.unwrap(), "Hello world");
+                    assert_eq!(hidden, Some(true));
+                }
+                _ => panic!("Message subtype deserialize into incorrect variant"),
+            },
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+
+        let json = r##"{
+  "type": "message",
+  "subtype": "message_deleted",
+  "hidden": true,
+  "channel": "C123ABC456",
+  "ts": "1358878755.000001",
+  "deleted_ts": "1358878749.000002"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::Message { message, .. } => match message.subtype.unwrap() {
+                MessageSubtype::MessageDeleted {
+                    deleted_ts,
+                    previous_message,
+                    ..
+                } => {
+                    assert_eq!(deleted_ts, "1358878749.000002");
+                    assert_eq!(previous_message, None);
+                }
+                _ => panic!("Message subtype deserialize into incorrect variant"),
+            },
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+
+        let json = r##"{
+  "type": "message",
+  "subtype": "bot_message",
+  "ts": "1358877455.000010",
+  "text": "Pushing is the answer",
+  "bot_id": "BB12033",
+  "username": "github",
+  "bot_profile": {
+    "id": "BB12033",
+    "app_id": "A0F7XDU93",
+    "name": "github"
+  }
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::Message { message, .. } => {
+                assert_eq!(message.bot_id.unwrap(), "BB12033");
+                match message.subtype.unwrap() {
+                    MessageSubtype::BotMessage { bot_profile, .. } => {
+                        assert_eq!(bot_profile.unwrap().app_id.unwrap(), "A0F7XDU93")
+                    }
+                    _ => panic!("Message subtype deserialize into incorrect variant"),
+                }
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+
+        let json = r##"{
+  "type": "message",
+  "subtype": "channel_join",
+  "ts": "1358877458.000011",
+  "user": "U2147483828",
+  "text": "<@U2147483828> has joined the channel",
+  "inviter": "U123456789"
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::Message { message, .. } => match message.subtype.unwrap() {
+                MessageSubtype::ChannelJoin { inviter } => {
+                    assert_eq!(inviter.unwrap(), "U123456789")
+                }
+                _ => panic!("Message subtype deserialize into incorrect variant"),
+            },
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+
+        let json = r##"{
+  "type": "message",
+  "subtype": "thread_broadcast",
+  "user": "U061F7AUR",
+  "text": "Reply",
+  "ts": "1483037604.017506",
+  "thread_ts": "1482960137.003543",
+  "root": {
+    "type": "message",
+    "user": "U061F7AUR",
+    "text": "Root",
+    "ts": "1482960137.003543"
+  }
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::Message { message, .. } => {
+                assert_eq!(message.thread_ts.unwrap(), "1482960137.003543");
+                match message.subtype.unwrap() {
+                    MessageSubtype::ThreadBroadcast { root } => {
+                        assert_eq!(root.unwrap().text.unwrap(), "Root")
+                    }
+                    _ => panic!("Message subtype deserialize into incorrect variant"),
+                }
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+
+        let json = r##"{
+  "type": "message",
+  "subtype": "file_share",
+  "user": "U061F7AUR",
+  "text": "",
+  "ts": "1483037604.017506",
+  "upload": true,
+  "files": [
+    {
+      "id": "F0HS27V1Z",
+      "name": "report.pdf"
+    }
+  ]
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::Message { message, .. } => match message.subtype.unwrap() {
+                MessageSubtype::FileShare { files, upload } => {
+                    assert_eq!(files.unwrap()[0].id.as_ref().unwrap(), "F0HS27V1Z");
+                    assert_eq!(upload, Some(true));
+                }
+                _ => panic!("Message subtype deserialize into incorrect variant"),
+            },
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+
+        let json = r##"{
+  "type": "message",
+  "subtype": "message_replied",
+  "hidden": true,
+  "channel": "C061EG9SL",
+  "ts": "1483037604.017506",
+  "message": {
+    "type": "message",
+    "user": "U061F7AUR",
+    "text": "Was there was there was there what was there was there what was there was there there was there.",
+    "thread_ts": "1482960137.003543",
+    "ts": "1482960137.003543"
+  }
+}"##;
+        match deserialize_event_callback(json) {
+            EventCallback::Message { message, .. } => match message.subtype.unwrap() {
+                MessageSubtype::MessageReplied { message, hidden } => {
+                    assert_eq!(message.thread_ts.unwrap(), "1482960137.003543");
+                    assert_eq!(hidden, Some(true));
+                }
+                _ => panic!("Message subtype deserialize into incorrect variant"),
+            },
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+    }
+
+    #[test]
+    fn deserialize_other_message_subtypes() {
+        let json = serde_json::json!({
+            "type": "message",
+            "subtype": "channel_topic",
+            "ts": "1358877455.000010",
+            "user": "U2147483828",
+            "topic": "hello world"
+        });
+        let event = serde_json::from_value::<EventCallback>(json.clone()).unwrap();
+        match &event {
+            EventCallback::Message { message, .. } => {
+                assert_eq!(
+                    message.subtype,
+                    Some(MessageSubtype::Other("channel_topic".to_string()))
+                );
+                assert_eq!(message.extra.len(), 1);
+                assert_eq!(message.extra["topic"], "hello world");
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+        assert_eq!(serde_json::to_value(&event).unwrap(), json);
+
+        let json = serde_json::json!({
+            "type": "message",
+            "subtype": "message_deleted",
+            "ts": "1358878755.000001",
+            "hidden": true
+        });
+        let event = serde_json::from_value::<EventCallback>(json.clone()).unwrap();
+        match &event {
+            EventCallback::Message { message, .. } => {
+                assert_eq!(
+                    message.subtype,
+                    Some(MessageSubtype::Other("message_deleted".to_string()))
+                );
+                assert_eq!(message.extra.len(), 1);
+                assert_eq!(message.extra["hidden"], true);
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+        assert_eq!(serde_json::to_value(&event).unwrap(), json);
+    }
+
+    #[test]
+    fn round_trip_unknown_event() {
+        let json = serde_json::json!({
+            "type": "brand_new_event",
+            "user": "U061F7AUR",
+            "payload": {"nested": [1, 2, 3]}
+        });
+        let event = serde_json::from_value::<EventCallback>(json.clone()).unwrap();
+        match &event {
+            EventCallback::Unknown { type_name, .. } => assert_eq!(type_name, "brand_new_event"),
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+        assert_eq!(serde_json::to_value(&event).unwrap(), json);
+    }
+
+    #[test]
+    fn reject_malformed_known_event() {
+        let json = serde_json::json!({
+            "type": "app_mention",
+            "user": "U061F7AUR"
+        });
+        assert!(serde_json::from_value::<EventCallback>(json).is_err());
+    }
+
+    #[test]
+    fn list_every_event_type() {
+        let tags = |error: serde_json::Error| {
+            let error = error.to_string();
+            let (_, tags) = error.split_once("expected one of ").unwrap();
+            tags.split(", ")
+                .map(|tag| tag.trim_matches('`').to_string())
+                .collect::<Vec<_>>()
+        };
+        let error = EventCallback::deserialize(serde_json::json!({"type": "none"})).unwrap_err();
+        assert_eq!(tags(error), EVENT_TYPES);
+        let error =
+            MessageSubtype::deserialize(serde_json::json!({"subtype": "none"})).unwrap_err();
+        assert_eq!(tags(error), crate::chat::message::MESSAGE_SUBTYPES);
+    }
+
+    #[test]
+    fn keep_known_event_with_unknown_nested_block() {
+        let json = serde_json::json!({
+            "type": "message",
+            "channel": "C2147483705",
+            "user": "U2147483697",
+            "text": "Hello world",
+            "ts": "1355517523.000005",
+            "blocks": [
+                {"type": "table", "rows": []},
+                {
+                    "type": "rich_text",
+                    "elements": [{
+                        "type": "rich_text_section",
+                        "elements": [{"type": "team", "team_id": "T0123"}]
+                    }]
+                }
+            ]
+        });
+        let event = serde_json::from_value::<EventCallback>(json.clone()).unwrap();
+        assert!(matches!(event, EventCallback::Message { .. }));
+        assert_eq!(serde_json::to_value(&event).unwrap(), json);
+    }
+
+    #[test]
+    fn round_trip_other_message_subtypes() {
+        let messages = vec![
+            serde_json::json!({
+                "type": "message",
+                "subtype": "channel_topic",
+                "ts": "1358877455.000010",
+                "user": "U2147483828",
+                "text": "<@U2147483828> set the channel topic: hello world",
+                "topic": "hello world"
+            }),
+            serde_json::json!({
+                "type": "message",
+                "subtype": "channel_purpose",
+                "ts": "1358877455.000011",
+                "user": "U2147483828",
+                "purpose": "testing"
+            }),
+            serde_json::json!({
+                "type": "message",
+                "subtype": "pinned_item",
+                "ts": "1358877455.000012",
+                "item_type": "F",
+                "item": {"id": "F0HS27V1Z"}
+            }),
+            serde_json::json!({
+                "type": "message",
+                "subtype": "ekm_access_denied",
+                "ts": "1358877455.000013",
+                "user": "U00",
+                "hidden": true
+            }),
+            serde_json::json!({
+                "type": "message",
+                "subtype": "me_message",
+                "ts": "1358877455.000014",
+                "user": "U2147483828",
+                "text": "is doing that thing"
+            }),
+        ];
+        for json in messages {
+            let event = serde_json::from_value::<EventCallback>(json.clone()).unwrap();
+            match &event {
+                EventCallback::Message { message, .. } => assert_eq!(
+                    message.subtype,
+                    Some(MessageSubtype::Other(
+                        json["subtype"].as_str().unwrap().to_string()
+                    ))
+                ),
+                _ => panic!("Event callback deserialize into incorrect variant"),
+            }
+            assert_eq!(serde_json::to_value(&event).unwrap(), json);
+        }
+    }
+
+    #[test]
+    fn round_trip_unknown_fields() {
+        let json = serde_json::json!({
+            "type": "message",
+            "subtype": "message_deleted",
+            "channel": "C123ABC456",
+            "ts": "1358878755.000001",
+            "deleted_ts": "1358878749.000002",
+            "client_msg_id": "3e9b7bb2-1f24-4c7d-9c71-2fae23ddc6d1"
+        });
+        let event = serde_json::from_value::<EventCallback>(json.clone()).unwrap();
+        match &event {
+            EventCallback::Message { message, .. } => {
+                assert_eq!(message.extra.len(), 1);
+                assert_eq!(
+                    message.extra["client_msg_id"],
+                    "3e9b7bb2-1f24-4c7d-9c71-2fae23ddc6d1"
+                );
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+        assert_eq!(serde_json::to_value(&event).unwrap(), json);
+
+        let json = serde_json::json!({
+            "type": "team_join",
+            "user": {
+                "id": "W012A3CDE",
+                "is_workflow_bot": false,
+                "enterprise_user": {"id": "W012A3CDE"}
+            }
+        });
+        let event = serde_json::from_value::<EventCallback>(json.clone()).unwrap();
+        match &event {
+            EventCallback::TeamJoin { user } => {
+                assert_eq!(user.extra["is_workflow_bot"], false)
+            }
+            _ => panic!("Event callback deserialize into incorrect variant"),
+        }
+        assert_eq!(serde_json::to_value(&event).unwrap(), json);
+
+        let json = serde_json::json!({
+            "type": "link_shared",
+            "channel": "Cxxxxxx",
+            "is_bot_user_member": true,
+            "user": "Uxxxxxxx",
+            "message_ts": "123456789.9875",
+            "unfurl_id": "C123456.123456789.987501.1b90fa1278528ce6e2f6c5c2bfa1abc9a41d57d02b29d173f40399c9ffdecf4b",
+            "thread_ts": "123456621.1855",
+            "source": "conversations_history",
+            "links": [
+                {
+                    "domain": "example.com",
+                    "url": "https://example.com/12345",
+                    "cached": true
+                }
+            ]
+        });
+        let event = serde_json::from_value::<EventCallback>(json.clone()).unwrap();
+        match &event {
+            EventCallback::LinkShared { links, .. } => assert_eq!(links[0].extra["cached"], true),
             _ => panic!("Event callback deserialize into incorrect variant"),
         }
+        assert_eq!(serde_json::to_value(&event).unwrap(), json);
     }
 }
@@ -0,0 +1,19 @@
+use crate::id::ChannelId;
+use crate::ts::Ts;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::collections::HashMap;
+
+/// The item a reaction was added to or removed from.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct EventItem {
+    #[serde(rename = "type")]
+    pub type_filed: String,
+    pub channel: Option<ChannelId>,
+    pub ts: Option<Ts>,
+    pub file: Option<String>,
+    pub file_comment: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
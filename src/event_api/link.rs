@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::collections::HashMap;
+
+/// A link in a `link_shared` event.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct SharedLink {
+    pub domain: String,
+    pub url: String,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
@@ -1,4 +1,10 @@
 //! [Slack Event API](https://api.slack.com/apis/connections/events-api)
 
 pub mod app;
+pub mod dnd;
 pub mod event;
+pub mod item;
+pub mod link;
+pub mod metadata;
+pub mod token;
+pub mod workflow;
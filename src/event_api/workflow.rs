@@ -0,0 +1,50 @@
+use crate::id::AppId;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::collections::HashMap;
+
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct WorkflowConfiguration {
+    pub version_id: Option<String>,
+    pub app_steps: Option<Vec<AppStep>>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct AppStep {
+    pub app_id: Option<AppId>,
+    pub workflow_step_id: Option<String>,
+    pub callback_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct WorkflowStep {
+    pub workflow_step_execute_id: Option<String>,
+    pub workflow_id: Option<String>,
+    pub workflow_instance_id: Option<String>,
+    pub step_id: Option<String>,
+    pub inputs: Option<HashMap<String, WorkflowStepInput>>,
+    pub outputs: Option<Vec<WorkflowStepOutput>>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct WorkflowStepInput {
+    pub value: Option<serde_json::Value>,
+    pub skip_variable_replacement: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct WorkflowStepOutput {
+    pub name: Option<String>,
+    #[serde(rename = "type")]
+    pub type_filed: Option<String>,
+    pub label: Option<String>,
+}
@@ -1,19 +1,23 @@
+use crate::id::{ChannelId, FileId, UserId};
 use crate::reactions::reaction::Reaction;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use std::collections::HashMap;
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct File {
-    pub channels: Option<Vec<String>>,
+    pub channels: Option<Vec<ChannelId>>,
     pub comments_count: Option<i32>,
-    pub created: Option<i32>,
+    pub created: Option<i64>,
     pub groups: Option<Vec<String>>,
-    pub id: Option<String>,
+    pub id: Option<FileId>,
     pub ims: Option<Vec<String>>,
     pub name: Option<String>,
     pub reactions: Option<Vec<Reaction>>,
-    pub timestamp: Option<i32>,
+    pub timestamp: Option<i64>,
     pub title: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<UserId>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
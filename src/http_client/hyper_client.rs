@@ -0,0 +1,336 @@
+//! HTTP client backed by hyper with rustls.
+
+use crate::connection::{self, ConnectionConfig};
+use crate::error::Error;
+use crate::http_client::{get_slack_url_with_base, ClientBuilder, HttpResponse, SlackWebAPIClient};
+use crate::instrument;
+use async_trait::async_trait;
+use hyper::client::connect::{Connected, Connection};
+use hyper::client::HttpConnector;
+use hyper::header::{AUTHORIZATION, CONTENT_TYPE, PROXY_AUTHORIZATION, USER_AGENT};
+use hyper::service::Service;
+use hyper::{Body, Request, Uri};
+use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
+use rustls_021::{Certificate, ClientConfig, OwnedTrustAnchor, PrivateKey, RootCertStore};
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::net::TcpStream;
+use tokio_util::compat::TokioAsyncReadCompatExt;
+
+/// HTTP Client(hyper::Client with rustls and the webpki root certificates).
+#[derive(Debug, Clone)]
+pub struct HyperClient {
+    http: hyper::Client<HttpsConnector<ProxyConnector>>,
+    base_url: String,
+    timeout: Option<Duration>,
+    connection: Arc<ConnectionConfig>,
+}
+
+impl HyperClient {
+    pub fn new() -> HyperClient {
+        HyperClient::from(ClientBuilder::new())
+    }
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
+    }
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+    /// Returns the url of a Slack WEB API method.
+    pub fn method_url(&self, method: &str) -> String {
+        get_slack_url_with_base(&self.base_url, method)
+    }
+    async fn send_request(
+        &self,
+        method: &str,
+        body: Option<&str>,
+        token: &str,
+    ) -> Result<HttpResponse, Error> {
+        let check_url = url::Url::parse(&self.method_url(method))?;
+
+        let mut request =
+            Request::post(check_url.as_str()).header(AUTHORIZATION, format!("Bearer {}", token));
+        if let Some(user_agent) = &self.connection.user_agent {
+            request = request.header(USER_AGENT, user_agent.as_str());
+        }
+        // Requests forwarded to the proxy, rather than tunneled, carry its credentials.
+        if check_url.scheme() == "http" {
+            let proxy = check_url
+                .host_str()
+                .and_then(|host| self.connection.proxy_for(host));
+            if let Some(credentials) = proxy.and_then(connection::proxy_authorization) {
+                request = request.header(PROXY_AUTHORIZATION, credentials);
+            }
+        }
+        let request = match body {
+            Some(body) => request
+                .header(CONTENT_TYPE, "application/json; charset=utf-8")
+                .body(Body::from(body.to_string())),
+            None => request.body(Body::empty()),
+        }
+        .map_err(|_| Error::InvalidInputError)?;
+
+        let exchange = async {
+            let response = self.http.request(request).await?;
+            let (parts, body) = response.into_parts();
+            let body = hyper::body::to_bytes(body).await?;
+            Ok::<_, Error>((parts, body))
+        };
+        let (parts, body) = match self.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, exchange)
+                .await
+                .map_err(|_| Error::TimeoutError)??,
+            None => exchange.await?,
+        };
+
+        let mut http_response = HttpResponse {
+            status: parts.status.as_u16(),
+            body: String::from_utf8_lossy(&body).into_owned(),
+            ..Default::default()
+        };
+        for (name, value) in &parts.headers {
+            if let Ok(value) = value.to_str() {
+                http_response.insert_header(name.as_str(), value);
+            }
+        }
+        Ok(http_response)
+    }
+}
+
+impl Default for HyperClient {
+    fn default() -> Self {
+        HyperClient::new()
+    }
+}
+
+impl From<ClientBuilder> for HyperClient {
+    /// # Panics
+    ///
+    /// If rustls rejects the configuration; see [`HyperClient::try_from_builder`].
+    fn from(builder: ClientBuilder) -> HyperClient {
+        HyperClient::try_from_builder(builder).expect("failed to create the hyper client")
+    }
+}
+
+impl HyperClient {
+    /// Makes a client, or fails if rustls rejects the root certificates or the client
+    /// certificate of the configuration.
+    pub fn try_from_builder(builder: ClientBuilder) -> Result<HyperClient, Error> {
+        let connection = Arc::new(builder.connection.clone());
+        let mut http = HttpConnector::new();
+        http.enforce_http(false);
+        http.set_connect_timeout(connection.connect_timeout);
+        let tls_config = tls_config(&connection)?;
+        let connector = HttpsConnectorBuilder::new()
+            .with_tls_config(tls_config)
+            .https_or_http()
+            .enable_http1()
+            .wrap_connector(ProxyConnector {
+                http,
+                connection: connection.clone(),
+            });
+        Ok(HyperClient {
+            http: hyper::Client::builder().build(connector),
+            base_url: builder.base_url_or_default(),
+            timeout: connection.timeout,
+            connection,
+        })
+    }
+}
+
+/// Trusts the root certificates of `connection`, or the webpki ones if it has none, and presents
+/// its client certificate.
+fn tls_config(connection: &ConnectionConfig) -> Result<ClientConfig, Error> {
+    let mut roots = RootCertStore::empty();
+    match &connection.root_certificates {
+        Some(root_certificates) => {
+            for certificate in root_certificates.certificates() {
+                roots
+                    .add(&Certificate(certificate))
+                    .map_err(|_| Error::InvalidInputError)?;
+            }
+        }
+        None => roots.add_trust_anchors(webpki_roots::TLS_SERVER_ROOTS.iter().map(|anchor| {
+            OwnedTrustAnchor::from_subject_spki_name_constraints(
+                anchor.subject,
+                anchor.spki,
+                anchor.name_constraints,
+            )
+        })),
+    }
+
+    let config = ClientConfig::builder()
+        .with_safe_defaults()
+        .with_root_certificates(roots);
+    match &connection.client_certificate {
+        Some(client_certificate) => {
+            let chain = client_certificate
+                .certificate
+                .certificates()
+                .into_iter()
+                .map(Certificate)
+                .collect();
+            let private_key = client_certificate
+                .private_key
+                .private_key()
+                .ok_or(Error::InvalidInputError)?;
+            config
+                .with_client_auth_cert(chain, PrivateKey(private_key))
+                .map_err(|_| Error::InvalidInputError)
+        }
+        None => Ok(config.with_no_client_auth()),
+    }
+}
+
+/// Connects directly, or through the proxy of a [`ConnectionConfig`]: tunneled with `CONNECT` for
+/// `https` and forwarding requests for `http`.
+#[derive(Debug, Clone)]
+pub struct ProxyConnector {
+    http: HttpConnector,
+    connection: Arc<ConnectionConfig>,
+}
+
+impl Service<Uri> for ProxyConnector {
+    type Response = ProxyStream;
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+    type Future = Pin<Box<dyn Future<Output = Result<ProxyStream, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.http.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, uri: Uri) -> Self::Future {
+        let mut http = self.http.clone();
+        let connection = self.connection.clone();
+        Box::pin(async move {
+            let host = uri
+                .host()
+                .ok_or("missing host")?
+                .trim_start_matches('[')
+                .trim_end_matches(']')
+                .to_string();
+            let proxy = match connection.proxy_for(&host) {
+                Some(proxy) => proxy,
+                None => {
+                    let stream = http.call(uri).await?;
+                    return Ok(ProxyStream {
+                        stream,
+                        forwarding: false,
+                    });
+                }
+            };
+
+            let stream = http.call(proxy.as_str().parse()?).await?;
+            if uri.scheme_str() != Some("https") {
+                return Ok(ProxyStream {
+                    stream,
+                    forwarding: true,
+                });
+            }
+            let mut stream = stream.compat();
+            let port = uri.port_u16().unwrap_or(443);
+            connection::tunnel(&mut stream, &host, port, proxy)
+                .await
+                .map_err(|e| e.to_string())?;
+            Ok(ProxyStream {
+                stream: stream.into_inner(),
+                forwarding: false,
+            })
+        })
+    }
+}
+
+/// A connection made by [`ProxyConnector`].
+#[derive(Debug)]
+pub struct ProxyStream {
+    stream: TcpStream,
+    /// Whether requests are sent to the proxy in absolute form.
+    forwarding: bool,
+}
+
+impl Connection for ProxyStream {
+    fn connected(&self) -> Connected {
+        self.stream.connected().proxy(self.forwarding)
+    }
+}
+
+impl AsyncRead for ProxyStream {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.stream).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for ProxyStream {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.stream).poll_write(cx, buf)
+    }
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.stream).poll_flush(cx)
+    }
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.stream).poll_shutdown(cx)
+    }
+}
+
+#[async_trait]
+impl SlackWebAPIClient for HyperClient {
+    /// Send a post request including the body to the slack web api.
+    async fn call_json(&self, method: &str, body: &str, token: &str) -> Result<String, Error> {
+        Ok(self.send(method, Some(body), token).await?.body)
+    }
+    /// Send a post request to the slack web api.
+    async fn call(&self, method: &str, token: &str) -> Result<String, Error> {
+        Ok(self.send(method, None, token).await?.body)
+    }
+    async fn send<'a>(
+        &self,
+        method: &str,
+        body: Option<&'a str>,
+        token: &str,
+    ) -> Result<HttpResponse, Error> {
+        instrument::web_api_call(method, body, self.send_request(method, body, token)).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::http_client::test::{
+        exercise_backend, exercise_certificates, exercise_proxy, invalid_certificate_builder,
+    };
+
+    #[tokio::test]
+    async fn send_requests() {
+        exercise_backend::<HyperClient>().await;
+    }
+
+    #[tokio::test]
+    async fn send_requests_through_proxy() {
+        exercise_proxy::<HyperClient>().await;
+    }
+
+    #[test]
+    fn build_with_certificates() {
+        exercise_certificates::<HyperClient>();
+        assert!(matches!(
+            HyperClient::try_from_builder(invalid_certificate_builder()),
+            Err(Error::InvalidInputError)
+        ));
+    }
+}
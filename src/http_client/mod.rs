@@ -0,0 +1,358 @@
+//! HTTP client for Slack WEB API.
+//!
+//! Each backend is behind a feature: `surf-client` (the default), `reqwest-client` and
+//! `hyper-client`. [`Client`] is the backend the enabled features select, preferring hyper, then
+//! reqwest, then surf. reqwest and hyper need a tokio runtime.
+
+use crate::connection::ConnectionConfig;
+use crate::error::Error;
+use async_trait::async_trait;
+#[cfg(test)]
+use mockall::automock;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::collections::HashMap;
+use std::time::Duration;
+
+#[cfg(feature = "hyper-client")]
+pub mod hyper_client;
+#[cfg(feature = "reqwest-client")]
+pub mod reqwest_client;
+#[cfg(any(feature = "async-std-runtime", feature = "tokio-runtime"))]
+pub mod retry;
+#[cfg(feature = "surf-client")]
+pub mod surf_client;
+
+/// The base URL of the public Slack WEB API.
+pub const DEFAULT_BASE_URL: &str = "https://slack.com/api/";
+
+/// Slack WEB API Client.
+///
+/// Methods are passed by name, such as `chat.postMessage`, and the client resolves them against
+/// its base URL. These replace `post_json` and `post`, which took the full URL instead.
+#[cfg_attr(test, automock)]
+#[async_trait]
+pub trait SlackWebAPIClient: Sync + Send {
+    async fn call_json(&self, method: &str, body: &str, token: &str) -> Result<String, Error>;
+    async fn call(&self, method: &str, token: &str) -> Result<String, Error>;
+    /// Send a request with a JSON body, or without one if `body` is `None`, and return the whole
+    /// response.
+    ///
+    /// Clients that can't see the HTTP response answer with status 200 and no headers.
+    async fn send<'a>(
+        &self,
+        method: &str,
+        body: Option<&'a str>,
+        token: &str,
+    ) -> Result<HttpResponse, Error> {
+        let body = match body {
+            Some(body) => self.call_json(method, body, token).await?,
+            None => self.call(method, token).await?,
+        };
+        Ok(HttpResponse {
+            status: 200,
+            headers: HashMap::new(),
+            body,
+        })
+    }
+}
+
+/// An HTTP response from the Slack WEB API.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HttpResponse {
+    pub status: u16,
+    /// Header names are lowercase. Repeated headers are joined with `, `.
+    pub headers: HashMap<String, String>,
+    pub body: String,
+}
+
+impl HttpResponse {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(&name.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+
+    #[cfg(any(
+        test,
+        feature = "hyper-client",
+        feature = "reqwest-client",
+        feature = "surf-client"
+    ))]
+    fn insert_header(&mut self, name: &str, value: &str) {
+        self.headers
+            .entry(name.to_ascii_lowercase())
+            .and_modify(|values| {
+                values.push_str(", ");
+                values.push_str(value);
+            })
+            .or_insert_with(|| value.to_string());
+    }
+}
+
+#[cfg(feature = "hyper-client")]
+pub type Client = hyper_client::HyperClient;
+#[cfg(all(feature = "reqwest-client", not(feature = "hyper-client")))]
+pub type Client = reqwest_client::ReqwestClient;
+#[cfg(all(
+    feature = "surf-client",
+    not(any(feature = "hyper-client", feature = "reqwest-client"))
+))]
+pub type Client = surf_client::SurfClient;
+
+/// Configures any of the clients.
+///
+/// `build` makes a [`Client`]; convert into another backend with `into`, or with its
+/// `try_from_builder` to get an error rather than a panic for a configuration it rejects.
+#[derive(Debug, Default)]
+pub struct ClientBuilder {
+    pub base_url: Option<String>,
+    pub connection: ConnectionConfig,
+}
+
+impl ClientBuilder {
+    pub fn new() -> ClientBuilder {
+        ClientBuilder {
+            ..Default::default()
+        }
+    }
+    /// Sets the base URL, such as `https://slack-gov.com/api/` or a local mock server.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> ClientBuilder {
+        self.base_url = Some(base_url.into());
+        self
+    }
+    /// Sets the time allowed for each request, from sending it to reading the whole response.
+    pub fn timeout(mut self, timeout: Duration) -> ClientBuilder {
+        self.connection.timeout = Some(timeout);
+        self
+    }
+    /// Sets the proxy, certificates, timeouts and user agent, replacing any earlier `timeout`.
+    pub fn connection(mut self, connection: ConnectionConfig) -> ClientBuilder {
+        self.connection = connection;
+        self
+    }
+    /// Makes a [`Client`], or fails if the backend rejects the configuration, such as a
+    /// certificate the TLS library can't use.
+    #[cfg(any(
+        feature = "hyper-client",
+        feature = "reqwest-client",
+        feature = "surf-client"
+    ))]
+    pub fn try_build(self) -> Result<Client, Error> {
+        Client::try_from_builder(self)
+    }
+    /// Makes a [`Client`].
+    ///
+    /// # Panics
+    ///
+    /// If the backend rejects the configuration; see [`ClientBuilder::try_build`].
+    #[cfg(any(
+        feature = "hyper-client",
+        feature = "reqwest-client",
+        feature = "surf-client"
+    ))]
+    pub fn build(self) -> Client {
+        Client::from(self)
+    }
+
+    #[cfg(any(
+        feature = "hyper-client",
+        feature = "reqwest-client",
+        feature = "surf-client"
+    ))]
+    fn base_url_or_default(&self) -> String {
+        self.base_url
+            .clone()
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string())
+    }
+}
+
+/// Returns the slack api url for each method.
+pub fn get_slack_url(method: &str) -> String {
+    get_slack_url_with_base(DEFAULT_BASE_URL, method)
+}
+
+/// Returns the url of a method under `base_url`, with or without its trailing slash.
+pub fn get_slack_url_with_base(base_url: &str, method: &str) -> String {
+    format!("{}/{}", base_url.trim_end_matches('/'), method)
+}
+
+/// Provides a default client to give to the API functions to send requests.
+#[cfg(any(
+    feature = "hyper-client",
+    feature = "reqwest-client",
+    feature = "surf-client"
+))]
+pub fn default_client() -> Client {
+    Client::new()
+}
+
+/// Slack WEB API default response.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct DefaultResponse {
+    pub ok: bool,
+    pub error: Option<String>,
+    pub response_metadata: Option<ResponseMetadata>,
+}
+
+/// Slack WEB API response metadata.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+pub struct ResponseMetadata {
+    pub next_cursor: Option<String>,
+    pub messages: Option<Vec<String>>,
+    pub warnings: Option<Vec<String>>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::testing::http_proxy::HttpProxy;
+    use crate::testing::http_stub::{HttpStub, StubResponse};
+    use serde_json::json;
+
+    /// Checks a backend against a local server.
+    pub async fn exercise_backend<C>()
+    where
+        C: SlackWebAPIClient + From<ClientBuilder>,
+    {
+        let stub = HttpStub::start().await.unwrap();
+        let client = C::from(
+            ClientBuilder::new()
+                .base_url(stub.base_url())
+                .timeout(Duration::from_millis(500)),
+        );
+
+        stub.respond_once(
+            StubResponse::json(json!({"ok": false, "error": "ratelimited"}))
+                .status(429)
+                .header("Retry-After", "30")
+                .header("X-Slack-Req-Id", "c9a07dbb"),
+        );
+        let response = client
+            .send(
+                "chat.postMessage",
+                Some(r#"{"channel":"C1234567890"}"#),
+                "xoxb-token",
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status, 429);
+        assert_eq!(response.header("retry-after"), Some("30"));
+        assert_eq!(response.header("X-Slack-Req-Id"), Some("c9a07dbb"));
+        assert_eq!(response.body, r#"{"error":"ratelimited","ok":false}"#);
+
+        let request = &stub.requests()[0];
+        assert_eq!(request.path, "/api/chat.postMessage");
+        assert_eq!(request.headers["authorization"], "Bearer xoxb-token");
+        assert!(request.headers["content-type"].starts_with("application/json"));
+        assert_eq!(request.body, r#"{"channel":"C1234567890"}"#);
+
+        let body = client.call("auth.test", "xoxb-token").await.unwrap();
+        assert_eq!(body, r#"{"ok":true}"#);
+        assert_eq!(stub.requests()[1].path, "/api/auth.test");
+
+        stub.respond_once(StubResponse::json(json!({"ok": true})).delay(Duration::from_secs(3)));
+        assert!(client.call("auth.test", "xoxb-token").await.is_err());
+    }
+
+    /// Checks that a backend connects through the configured proxy, except to `no_proxy` hosts.
+    pub async fn exercise_proxy<C>()
+    where
+        C: SlackWebAPIClient + From<ClientBuilder>,
+    {
+        let stub = HttpStub::start().await.unwrap();
+        let proxy = HttpProxy::start().await.unwrap();
+        let connection = ConnectionConfig::new()
+            .proxy(proxy.url())
+            .unwrap()
+            .connect_timeout(Duration::from_secs(1))
+            .timeout(Duration::from_secs(1))
+            .user_agent("slack-rust-test/1.0");
+        let client = C::from(
+            ClientBuilder::new()
+                .base_url(stub.base_url())
+                .connection(connection.clone()),
+        );
+
+        let body = client.call("auth.test", "xoxb-token").await.unwrap();
+        assert_eq!(body, r#"{"ok":true}"#);
+        let request = &proxy.requests()[0];
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.target, format!("{}auth.test", stub.base_url()));
+        let request = &stub.requests()[0];
+        assert_eq!(request.path, "/api/auth.test");
+        assert_eq!(request.headers["user-agent"], "slack-rust-test/1.0");
+
+        let client = C::from(
+            ClientBuilder::new()
+                .base_url(stub.base_url())
+                .connection(connection.no_proxy("127.0.0.1")),
+        );
+        client.call("auth.test", "xoxb-token").await.unwrap();
+        assert_eq!(proxy.requests().len(), 1);
+        assert_eq!(stub.requests().len(), 2);
+    }
+
+    /// Checks that a backend accepts the root and client certificates of a connection config.
+    pub fn exercise_certificates<C>()
+    where
+        C: From<ClientBuilder>,
+    {
+        let (certificate, private_key) = crate::connection::test::certificate_files();
+        let connection = ConnectionConfig::new()
+            .root_ca_file(&certificate)
+            .unwrap()
+            .client_certificate(&certificate, &private_key)
+            .unwrap();
+        let _client = C::from(ClientBuilder::new().connection(connection));
+    }
+
+    /// A builder whose root certificate is a PEM block that doesn't hold a certificate.
+    #[cfg(any(feature = "hyper-client", feature = "reqwest-client"))]
+    pub fn invalid_certificate_builder() -> ClientBuilder {
+        let certificate = crate::connection::test::invalid_certificate_file();
+        let connection = ConnectionConfig::new().root_ca_file(certificate).unwrap();
+        ClientBuilder::new().connection(connection)
+    }
+
+    #[cfg(any(feature = "hyper-client", feature = "reqwest-client"))]
+    #[test]
+    fn fail_to_build_with_invalid_certificate() {
+        assert!(invalid_certificate_builder().try_build().is_err());
+    }
+
+    #[test]
+    fn resolve_method_url() {
+        assert_eq!(
+            default_client().method_url("chat.postMessage"),
+            "https://slack.com/api/chat.postMessage"
+        );
+        assert_eq!(
+            Client::builder()
+                .base_url("https://slack-gov.com/api")
+                .build()
+                .method_url("chat.postMessage"),
+            "https://slack-gov.com/api/chat.postMessage"
+        );
+        assert_eq!(
+            Client::builder()
+                .base_url("http://127.0.0.1:8080/api/")
+                .build()
+                .method_url("apps.connections.open"),
+            "http://127.0.0.1:8080/api/apps.connections.open"
+        );
+    }
+
+    #[test]
+    fn read_headers() {
+        let mut response = HttpResponse::default();
+        response.insert_header("X-Slack-Req-Id", "1234");
+        response.insert_header("Vary", "Accept-Encoding");
+        response.insert_header("vary", "Origin");
+        assert_eq!(response.header("x-slack-req-id"), Some("1234"));
+        assert_eq!(response.header("VARY"), Some("Accept-Encoding, Origin"));
+        assert_eq!(response.header("Retry-After"), None);
+    }
+}
@@ -0,0 +1,169 @@
+//! HTTP client backed by reqwest with rustls.
+
+use crate::connection::ConnectionConfig;
+use crate::error::Error;
+use crate::http_client::{get_slack_url_with_base, ClientBuilder, HttpResponse, SlackWebAPIClient};
+use crate::instrument;
+use async_trait::async_trait;
+use std::time::Duration;
+
+/// HTTP Client(reqwest::Client).
+#[derive(Debug, Clone)]
+pub struct ReqwestClient {
+    http: reqwest::Client,
+    base_url: String,
+    timeout: Option<Duration>,
+}
+
+impl ReqwestClient {
+    pub fn new() -> ReqwestClient {
+        ReqwestClient::from(ClientBuilder::new())
+    }
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
+    }
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+    /// Returns the url of a Slack WEB API method.
+    pub fn method_url(&self, method: &str) -> String {
+        get_slack_url_with_base(&self.base_url, method)
+    }
+    async fn send_request(
+        &self,
+        method: &str,
+        body: Option<&str>,
+        token: &str,
+    ) -> Result<HttpResponse, Error> {
+        let check_url = url::Url::parse(&self.method_url(method))?;
+
+        let mut request = self.http.post(check_url).bearer_auth(token);
+        if let Some(body) = body {
+            request = request
+                .header("Content-type", "application/json; charset=utf-8")
+                .body(body.to_string());
+        }
+        let response = request.send().await?;
+
+        let mut http_response = HttpResponse {
+            status: response.status().as_u16(),
+            ..Default::default()
+        };
+        for (name, value) in response.headers() {
+            if let Ok(value) = value.to_str() {
+                http_response.insert_header(name.as_str(), value);
+            }
+        }
+        http_response.body = response.text().await?;
+        Ok(http_response)
+    }
+}
+
+impl Default for ReqwestClient {
+    fn default() -> Self {
+        ReqwestClient::new()
+    }
+}
+
+impl From<ClientBuilder> for ReqwestClient {
+    /// # Panics
+    ///
+    /// If reqwest rejects the configuration; see [`ReqwestClient::try_from_builder`].
+    fn from(builder: ClientBuilder) -> ReqwestClient {
+        ReqwestClient::try_from_builder(builder).expect("failed to create the reqwest client")
+    }
+}
+
+impl ReqwestClient {
+    /// Makes a client, or fails if the TLS backend can't be initialized or rejects the
+    /// certificates of the configuration.
+    pub fn try_from_builder(builder: ClientBuilder) -> Result<ReqwestClient, Error> {
+        Ok(ReqwestClient {
+            http: reqwest_client(&builder.connection)?,
+            base_url: builder.base_url_or_default(),
+            timeout: builder.connection.timeout,
+        })
+    }
+}
+
+fn reqwest_client(connection: &ConnectionConfig) -> Result<reqwest::Client, reqwest::Error> {
+    let mut http = reqwest::Client::builder();
+    if connection.proxy.is_some() {
+        let connection = connection.clone();
+        http = http.proxy(reqwest::Proxy::custom(move |url| {
+            connection.proxy_for(url.host_str()?).cloned()
+        }));
+    }
+    if let Some(roots) = &connection.root_certificates {
+        http = http.tls_built_in_root_certs(false);
+        for certificate in roots.certificates() {
+            http = http.add_root_certificate(reqwest::Certificate::from_der(&certificate)?);
+        }
+    }
+    if let Some(client_certificate) = &connection.client_certificate {
+        let mut pem = client_certificate.certificate.pem().to_vec();
+        pem.push(b'\n');
+        pem.extend_from_slice(client_certificate.private_key.pem());
+        http = http.identity(reqwest::Identity::from_pem(&pem)?);
+    }
+    if let Some(connect_timeout) = connection.connect_timeout {
+        http = http.connect_timeout(connect_timeout);
+    }
+    if let Some(timeout) = connection.timeout {
+        http = http.timeout(timeout);
+    }
+    if let Some(user_agent) = &connection.user_agent {
+        http = http.user_agent(user_agent);
+    }
+    http.build()
+}
+
+#[async_trait]
+impl SlackWebAPIClient for ReqwestClient {
+    /// Send a post request including the body to the slack web api.
+    async fn call_json(&self, method: &str, body: &str, token: &str) -> Result<String, Error> {
+        Ok(self.send(method, Some(body), token).await?.body)
+    }
+    /// Send a post request to the slack web api.
+    async fn call(&self, method: &str, token: &str) -> Result<String, Error> {
+        Ok(self.send(method, None, token).await?.body)
+    }
+    async fn send<'a>(
+        &self,
+        method: &str,
+        body: Option<&'a str>,
+        token: &str,
+    ) -> Result<HttpResponse, Error> {
+        instrument::web_api_call(method, body, self.send_request(method, body, token)).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::http_client::test::{
+        exercise_backend, exercise_certificates, exercise_proxy, invalid_certificate_builder,
+    };
+
+    #[tokio::test]
+    async fn send_requests() {
+        exercise_backend::<ReqwestClient>().await;
+    }
+
+    #[tokio::test]
+    async fn send_requests_through_proxy() {
+        exercise_proxy::<ReqwestClient>().await;
+    }
+
+    #[test]
+    fn build_with_certificates() {
+        exercise_certificates::<ReqwestClient>();
+        assert!(matches!(
+            ReqwestClient::try_from_builder(invalid_certificate_builder()),
+            Err(Error::ReqwestError(_))
+        ));
+    }
+}
@@ -0,0 +1,465 @@
+//! Retries for transient network and server errors.
+//!
+//! [`RetryClient`] wraps another client and retries calls that failed to get a response, got a
+//! 5xx or 429 status, or got one of [`RETRYABLE_ERRORS`], waiting with exponential backoff and
+//! jitter in between. Only the read methods in [`IDEMPOTENT_METHODS`] are retried unless others are
+//! allowed with [`RetryPolicy::allow_method`], since a failed call may still have taken effect.
+//! The waits for rate limits are reported to [`RetryClient::metrics`].
+//!
+//! ```no_run
+//! use slack_rust::http_client::default_client;
+//! use slack_rust::http_client::retry::{RetryClient, RetryPolicy};
+//! use std::time::Duration;
+//!
+//! let client = RetryClient::new(
+//!     default_client(),
+//!     RetryPolicy::new()
+//!         .max_attempts(5)
+//!         .initial_backoff(Duration::from_millis(200)),
+//! );
+//! ```
+
+use crate::error::Error;
+use crate::http_client::{DefaultResponse, HttpResponse, SlackWebAPIClient};
+use crate::metrics::{Metrics, NoopMetrics};
+use crate::runtime;
+use async_trait::async_trait;
+use std::collections::hash_map::RandomState;
+use std::collections::HashSet;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Slack error codes that are worth retrying.
+pub const RETRYABLE_ERRORS: &[&str] = &[
+    "ratelimited",
+    "internal_error",
+    "fatal_error",
+    "service_unavailable",
+];
+
+/// The read methods this crate has a module for, which can't take effect twice if retried. Every
+/// other method is only retried if allowed with [`RetryPolicy::allow_method`], including reads
+/// sent through [`SlackWebAPIClient::call`] that this crate doesn't wrap yet, such as
+/// `files.info`, `bots.info` or `emoji.list`.
+pub const IDEMPOTENT_METHODS: &[&str] = &[
+    "api.test",
+    "apps.connections.open",
+    "auth.teams.list",
+    "auth.test",
+    "chat.getPermalink",
+    "chat.scheduledMessages.list",
+    "conversations.history",
+    "conversations.info",
+    "conversations.list",
+    "conversations.members",
+    "conversations.replies",
+    "dnd.info",
+    "reactions.get",
+    "reactions.list",
+    "reminders.info",
+    "reminders.list",
+    "team.accessLogs",
+    "team.billableInfo",
+    "team.billing.info",
+    "team.info",
+    "team.integrationLogs",
+    "team.preferences.list",
+    "team.profile.get",
+    "usergroups.list",
+    "usergroups.users.list",
+    "users.conversations",
+    "users.getPresence",
+    "users.identity",
+    "users.info",
+    "users.list",
+    "users.lookupByEmail",
+    "users.profile.get",
+];
+
+/// When and how long to wait before retrying a call.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Attempts per call, including the first one.
+    pub max_attempts: u32,
+    /// The wait before the first retry.
+    pub initial_backoff: Duration,
+    /// The longest wait computed by backoff. A longer `Retry-After` is still honored.
+    pub max_backoff: Duration,
+    /// Each wait is the previous one times this.
+    pub multiplier: f64,
+    /// The fraction of each wait that is randomized, from `0.0` (none) to `1.0` (all of it).
+    pub jitter: f64,
+    pub retryable_errors: HashSet<String>,
+    /// Methods outside [`IDEMPOTENT_METHODS`] that may be retried anyway.
+    pub allowed_methods: HashSet<String>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: 0.5,
+            retryable_errors: RETRYABLE_ERRORS.iter().map(|e| e.to_string()).collect(),
+            allowed_methods: HashSet::new(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new() -> RetryPolicy {
+        RetryPolicy::default()
+    }
+    pub fn max_attempts(mut self, max_attempts: u32) -> RetryPolicy {
+        self.max_attempts = max_attempts;
+        self
+    }
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> RetryPolicy {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+    pub fn max_backoff(mut self, max_backoff: Duration) -> RetryPolicy {
+        self.max_backoff = max_backoff;
+        self
+    }
+    pub fn multiplier(mut self, multiplier: f64) -> RetryPolicy {
+        self.multiplier = multiplier;
+        self
+    }
+    pub fn jitter(mut self, jitter: f64) -> RetryPolicy {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+    /// Also retries calls that fail with `error`.
+    pub fn retryable_error(mut self, error: impl Into<String>) -> RetryPolicy {
+        self.retryable_errors.insert(error.into());
+        self
+    }
+    /// Retries `method` even though it isn't in [`IDEMPOTENT_METHODS`].
+    pub fn allow_method(mut self, method: impl Into<String>) -> RetryPolicy {
+        self.allowed_methods.insert(method.into());
+        self
+    }
+
+    /// Whether calls to `method` may be retried at all.
+    pub fn may_retry(&self, method: &str) -> bool {
+        IDEMPOTENT_METHODS.contains(&method) || self.allowed_methods.contains(method)
+    }
+    /// Whether the outcome of a call is a transient failure.
+    pub fn is_retryable(&self, result: &Result<HttpResponse, Error>) -> bool {
+        let response = match result {
+            Ok(response) => response,
+            Err(e) => return is_network_error(e),
+        };
+        if response.status >= 500 || response.status == 429 {
+            return true;
+        }
+        serde_json::from_str::<DefaultResponse>(&response.body)
+            .ok()
+            .and_then(|response| response.error)
+            .is_some_and(|error| self.retryable_errors.contains(&error))
+    }
+    /// The wait before retry number `retry`, counting from 1, without jitter.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = self
+            .multiplier
+            .max(1.0)
+            .powi(retry.saturating_sub(1) as i32);
+        let backoff = self.initial_backoff.as_secs_f64() * factor;
+        Duration::from_secs_f64(backoff.min(self.max_backoff.as_secs_f64()))
+    }
+
+    /// The wait before retry number `retry`: the backoff with jitter, or `Retry-After` if it is
+    /// longer.
+    fn delay(&self, retry: u32, result: &Result<HttpResponse, Error>) -> Duration {
+        let backoff = self
+            .backoff(retry)
+            .mul_f64(1.0 - self.jitter * random_fraction());
+        let retry_after = result
+            .as_ref()
+            .ok()
+            .and_then(|response| response.header("retry-after"))
+            .and_then(|seconds| seconds.trim().parse().ok())
+            .map(Duration::from_secs)
+            .unwrap_or_default();
+        backoff.max(retry_after)
+    }
+}
+
+/// Whether an error happened sending the request or reading the response.
+fn is_network_error(error: &Error) -> bool {
+    match error {
+        #[cfg(feature = "hyper-client")]
+        Error::HyperError(_) => true,
+        #[cfg(feature = "reqwest-client")]
+        Error::ReqwestError(_) => true,
+        #[cfg(feature = "surf-client")]
+        Error::SurfError(_) => true,
+        Error::IOError(_) | Error::TimeoutError => true,
+        _ => false,
+    }
+}
+
+/// Whether Slack rate limited a call.
+fn is_rate_limited(result: &Result<HttpResponse, Error>) -> bool {
+    match result {
+        Ok(response) => {
+            response.status == 429
+                || serde_json::from_str::<DefaultResponse>(&response.body)
+                    .is_ok_and(|response| response.error.as_deref() == Some("ratelimited"))
+        }
+        Err(_) => false,
+    }
+}
+
+/// A random number in `[0, 1)`.
+fn random_fraction() -> f64 {
+    let random = RandomState::new().build_hasher().finish();
+    (random >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// A client that retries transient failures of another client. See [`RetryPolicy`].
+pub struct RetryClient<C> {
+    client: C,
+    policy: RetryPolicy,
+    metrics: Arc<dyn Metrics>,
+}
+
+impl<C> RetryClient<C>
+where
+    C: SlackWebAPIClient,
+{
+    pub fn new(client: C, policy: RetryPolicy) -> RetryClient<C> {
+        RetryClient {
+            client,
+            policy,
+            metrics: Arc::new(NoopMetrics),
+        }
+    }
+    /// Reports how long each retry of a rate limited call waited to `metrics`.
+    pub fn metrics<M>(mut self, metrics: M) -> RetryClient<C>
+    where
+        M: Metrics + 'static,
+    {
+        self.metrics = Arc::new(metrics);
+        self
+    }
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+    pub fn policy(&self) -> &RetryPolicy {
+        &self.policy
+    }
+}
+
+#[async_trait]
+impl<C> SlackWebAPIClient for RetryClient<C>
+where
+    C: SlackWebAPIClient,
+{
+    async fn call_json(&self, method: &str, body: &str, token: &str) -> Result<String, Error> {
+        Ok(self.send(method, Some(body), token).await?.body)
+    }
+    async fn call(&self, method: &str, token: &str) -> Result<String, Error> {
+        Ok(self.send(method, None, token).await?.body)
+    }
+    /// Returns the first response that isn't retryable, or the last one.
+    async fn send<'a>(
+        &self,
+        method: &str,
+        body: Option<&'a str>,
+        token: &str,
+    ) -> Result<HttpResponse, Error> {
+        let mut attempt = 1;
+        loop {
+            let result = self.client.send(method, body, token).await;
+            if attempt >= self.policy.max_attempts
+                || !self.policy.may_retry(method)
+                || !self.policy.is_retryable(&result)
+            {
+                return result;
+            }
+            let delay = self.policy.delay(attempt, &result);
+            if is_rate_limited(&result) {
+                self.metrics.rate_limited(method, delay);
+            }
+            log::debug!(
+                "retrying {} in {:?} after attempt {}",
+                method,
+                delay,
+                attempt
+            );
+            runtime::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::http_client::{Client, ClientBuilder};
+    use crate::testing::http_stub::{HttpStub, StubResponse};
+    use serde_json::json;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy::new().initial_backoff(Duration::from_millis(1))
+    }
+
+    async fn retry_client(policy: RetryPolicy) -> (HttpStub, RetryClient<Client>) {
+        let stub = HttpStub::start().await.unwrap();
+        let client = ClientBuilder::new()
+            .base_url(stub.base_url())
+            .timeout(Duration::from_millis(500))
+            .build();
+        (stub, RetryClient::new(client, policy))
+    }
+
+    #[test]
+    fn compute_backoff() {
+        let policy = RetryPolicy::new()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_secs(1));
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(10), Duration::from_secs(1));
+
+        for retry in 1..5 {
+            let delay = policy.delay(retry, &Err(Error::TimeoutError));
+            assert!(delay <= policy.backoff(retry));
+            assert!(delay >= policy.backoff(retry) / 2);
+        }
+
+        let rate_limited = Ok(HttpResponse {
+            status: 429,
+            headers: vec![("retry-after".to_string(), "30".to_string())]
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        });
+        assert_eq!(policy.delay(1, &rate_limited), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn classify_results() {
+        let policy = RetryPolicy::new();
+        let response = |status: u16, body: &str| HttpResponse {
+            status,
+            body: body.to_string(),
+            ..Default::default()
+        };
+        assert!(policy.is_retryable(&Err(Error::TimeoutError)));
+        assert!(!policy.is_retryable(&Err(Error::InvalidInputError)));
+        assert!(policy.is_retryable(&Ok(response(503, ""))));
+        assert!(policy.is_retryable(&Ok(response(429, ""))));
+        assert!(policy.is_retryable(&Ok(response(
+            200,
+            r#"{"ok":false,"error":"internal_error"}"#
+        ))));
+        assert!(!policy.is_retryable(&Ok(response(
+            200,
+            r#"{"ok":false,"error":"channel_not_found"}"#
+        ))));
+        assert!(!policy.is_retryable(&Ok(response(200, r#"{"ok":true}"#))));
+
+        assert!(policy.may_retry("conversations.history"));
+        assert!(!policy.may_retry("chat.postMessage"));
+        assert!(!policy.may_retry("chat.unfurl"));
+        assert!(!policy.may_retry("usergroups.users.update"));
+        assert!(!policy.may_retry("brand.newMethod"));
+        assert!(policy
+            .allow_method("chat.postMessage")
+            .may_retry("chat.postMessage"));
+    }
+
+    #[cfg_attr(feature = "tokio-runtime", tokio::test)]
+    #[cfg_attr(not(feature = "tokio-runtime"), async_std::test)]
+    async fn retry_flaky_server() {
+        let (stub, client) = retry_client(policy().max_attempts(4)).await;
+        stub.respond_once(StubResponse::json(json!({"ok": false})).status(503));
+        stub.respond_once(StubResponse::json(json!({"ok": true})).delay(Duration::from_secs(3)));
+        stub.respond_once(StubResponse::json(
+            json!({"ok": false, "error": "service_unavailable"}),
+        ));
+
+        let body = client
+            .call_json("conversations.history", r#"{"channel":"C1"}"#, "xoxb-token")
+            .await
+            .unwrap();
+        assert_eq!(body, r#"{"ok":true}"#);
+        assert_eq!(stub.requests().len(), 4);
+        assert!(stub
+            .requests()
+            .iter()
+            .all(|request| request.body == r#"{"channel":"C1"}"#));
+    }
+
+    #[cfg_attr(feature = "tokio-runtime", tokio::test)]
+    #[cfg_attr(not(feature = "tokio-runtime"), async_std::test)]
+    async fn give_up_after_max_attempts() {
+        let (stub, client) = retry_client(policy()).await;
+        stub.respond(StubResponse::json(json!({"ok": false, "error": "fatal_error"})).status(500));
+
+        let response = client.send("auth.test", None, "xoxb-token").await.unwrap();
+        assert_eq!(response.status, 500);
+        assert_eq!(stub.requests().len(), 3);
+    }
+
+    #[cfg_attr(feature = "tokio-runtime", tokio::test)]
+    #[cfg_attr(not(feature = "tokio-runtime"), async_std::test)]
+    async fn keep_non_idempotent_calls_single() {
+        let (stub, client) = retry_client(policy()).await;
+        stub.respond_once(StubResponse::json(json!({"ok": false})).status(502));
+
+        let response = client
+            .send("chat.postMessage", Some("{}"), "xoxb-token")
+            .await
+            .unwrap();
+        assert_eq!(response.status, 502);
+        assert_eq!(stub.requests().len(), 1);
+
+        let (stub, client) = retry_client(policy().allow_method("chat.postMessage")).await;
+        stub.respond_once(StubResponse::json(json!({"ok": false})).status(502));
+
+        let response = client
+            .send("chat.postMessage", Some("{}"), "xoxb-token")
+            .await
+            .unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(stub.requests().len(), 2);
+    }
+
+    #[cfg_attr(feature = "tokio-runtime", tokio::test)]
+    #[cfg_attr(not(feature = "tokio-runtime"), async_std::test)]
+    async fn retry_allowed_reads_this_crate_does_not_wrap() {
+        let (stub, client) = retry_client(policy()).await;
+        stub.respond_once(
+            StubResponse::json(json!({"ok": false, "error": "ratelimited"}))
+                .status(429)
+                .header("retry-after", "0"),
+        );
+
+        let response = client.send("files.info", None, "xoxb-token").await.unwrap();
+        assert_eq!(response.status, 429);
+        assert_eq!(stub.requests().len(), 1);
+
+        let (stub, client) = retry_client(policy().allow_method("files.info")).await;
+        stub.respond_once(
+            StubResponse::json(json!({"ok": false, "error": "ratelimited"}))
+                .status(429)
+                .header("retry-after", "0"),
+        );
+
+        let response = client.send("files.info", None, "xoxb-token").await.unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(stub.requests().len(), 2);
+        assert!(stub
+            .requests()
+            .iter()
+            .all(|request| request.path == "/api/files.info"));
+    }
+}
@@ -0,0 +1,217 @@
+//! HTTP client backed by surf with libcurl.
+
+use crate::connection::ConnectionConfig;
+use crate::error::Error;
+use crate::http_client::{
+    get_slack_url_with_base, ClientBuilder, HttpResponse, SlackWebAPIClient, DEFAULT_BASE_URL,
+};
+use crate::instrument;
+use async_trait::async_trait;
+use http_client::isahc::IsahcClient;
+use isahc::config::{CaCertificate, ClientCertificate, Configurable, PrivateKey};
+use std::convert::TryFrom;
+use std::time::Duration;
+
+/// HTTP Client(surf::Client).
+#[derive(Debug, Clone)]
+pub struct SurfClient {
+    http: surf::Client,
+    base_url: String,
+    timeout: Option<Duration>,
+}
+
+impl SurfClient {
+    pub fn new() -> SurfClient {
+        SurfClient::from(ClientBuilder::new())
+    }
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
+    }
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+    /// Returns the url of a Slack WEB API method.
+    pub fn method_url(&self, method: &str) -> String {
+        get_slack_url_with_base(&self.base_url, method)
+    }
+}
+
+impl Default for SurfClient {
+    fn default() -> Self {
+        SurfClient::new()
+    }
+}
+
+impl From<ClientBuilder> for SurfClient {
+    /// # Panics
+    ///
+    /// If the configuration is rejected; see [`SurfClient::try_from_builder`].
+    fn from(builder: ClientBuilder) -> SurfClient {
+        SurfClient::try_from_builder(builder).expect("failed to create the surf client")
+    }
+}
+
+impl SurfClient {
+    /// Makes a client, or fails if libcurl can't be initialized or the proxy isn't a valid URI.
+    /// Certificates are only read by libcurl when connecting, so bad ones fail the requests.
+    pub fn try_from_builder(builder: ClientBuilder) -> Result<SurfClient, Error> {
+        let isahc = isahc_client(&builder.connection)?;
+        let config = surf::Config::new().set_http_client(IsahcClient::from_client(isahc));
+        Ok(SurfClient {
+            http: surf::Client::try_from(config)
+                .map_err(|e| Error::SurfError(surf::Error::from(e)))?,
+            base_url: builder.base_url_or_default(),
+            timeout: builder.connection.timeout,
+        })
+    }
+}
+
+/// Configures libcurl, which surf's own config can't.
+fn isahc_client(connection: &ConnectionConfig) -> Result<isahc::HttpClient, Error> {
+    let mut builder = isahc::HttpClient::builder();
+    if let Some(proxy) = &connection.proxy {
+        let proxy = proxy
+            .as_str()
+            .parse::<isahc::http::Uri>()
+            .map_err(|e| Error::ProxyError(format!("invalid proxy: {}", e)))?;
+        builder = builder.proxy(Some(proxy));
+    }
+    if !connection.no_proxy.is_empty() {
+        builder = builder.proxy_blacklist(connection.no_proxy.clone());
+    }
+    if let Some(roots) = &connection.root_certificates {
+        builder = builder.ssl_ca_certificate(CaCertificate::file(roots.path()));
+    }
+    if let Some(client_certificate) = &connection.client_certificate {
+        let private_key = PrivateKey::pem_file(client_certificate.private_key.path(), None);
+        builder = builder.ssl_client_certificate(ClientCertificate::pem_file(
+            client_certificate.certificate.path(),
+            private_key,
+        ));
+    }
+    if let Some(connect_timeout) = connection.connect_timeout {
+        builder = builder.connect_timeout(connect_timeout);
+    }
+    if let Some(timeout) = connection.timeout {
+        builder = builder.timeout(timeout);
+    }
+    if let Some(user_agent) = &connection.user_agent {
+        builder = builder.default_header("user-agent", user_agent.as_str());
+    }
+    builder
+        .build()
+        .map_err(|e| Error::SurfError(surf::Error::from(e)))
+}
+
+#[async_trait]
+impl SlackWebAPIClient for SurfClient {
+    /// Send a post request including the body to the slack web api.
+    async fn call_json(&self, method: &str, body: &str, token: &str) -> Result<String, Error> {
+        Ok(self.send(method, Some(body), token).await?.body)
+    }
+    /// Send a post request to the slack web api.
+    async fn call(&self, method: &str, token: &str) -> Result<String, Error> {
+        Ok(self.send(method, None, token).await?.body)
+    }
+    async fn send<'a>(
+        &self,
+        method: &str,
+        body: Option<&'a str>,
+        token: &str,
+    ) -> Result<HttpResponse, Error> {
+        let url = self.method_url(method);
+        instrument::web_api_call(method, body, send_request(&self.http, &url, body, token)).await
+    }
+}
+
+/// Resolves methods against [`DEFAULT_BASE_URL`] with surf's own configuration.
+#[async_trait]
+impl SlackWebAPIClient for surf::Client {
+    async fn call_json(&self, method: &str, body: &str, token: &str) -> Result<String, Error> {
+        Ok(SlackWebAPIClient::send(self, method, Some(body), token)
+            .await?
+            .body)
+    }
+    async fn call(&self, method: &str, token: &str) -> Result<String, Error> {
+        Ok(SlackWebAPIClient::send(self, method, None, token)
+            .await?
+            .body)
+    }
+    async fn send<'a>(
+        &self,
+        method: &str,
+        body: Option<&'a str>,
+        token: &str,
+    ) -> Result<HttpResponse, Error> {
+        let url = get_slack_url_with_base(DEFAULT_BASE_URL, method);
+        instrument::web_api_call(method, body, send_request(self, &url, body, token)).await
+    }
+}
+
+async fn send_request(
+    http: &surf::Client,
+    url: &str,
+    body: Option<&str>,
+    token: &str,
+) -> Result<HttpResponse, Error> {
+    let check_url = url::Url::parse(url)?;
+
+    let mut request = http
+        .post(check_url)
+        .header("Authorization", format!("Bearer {}", token));
+    if let Some(body) = body {
+        request = request
+            .header("Content-type", "application/json; charset=utf-8")
+            .body(body);
+    }
+    let mut response = request.await?;
+
+    let mut http_response = HttpResponse {
+        status: response.status() as u16,
+        ..Default::default()
+    };
+    for (name, values) in response.iter() {
+        for value in values {
+            http_response.insert_header(name.as_str(), value.as_str());
+        }
+    }
+    http_response.body = response.body_string().await?;
+    Ok(http_response)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::http_client::test::{exercise_backend, exercise_certificates, exercise_proxy};
+
+    #[cfg_attr(feature = "tokio-runtime", tokio::test)]
+    #[cfg_attr(not(feature = "tokio-runtime"), async_std::test)]
+    async fn send_requests() {
+        exercise_backend::<SurfClient>().await;
+    }
+
+    #[cfg_attr(feature = "tokio-runtime", tokio::test)]
+    #[cfg_attr(not(feature = "tokio-runtime"), async_std::test)]
+    async fn send_requests_through_proxy() {
+        exercise_proxy::<SurfClient>().await;
+    }
+
+    #[test]
+    fn build_with_certificates() {
+        exercise_certificates::<SurfClient>();
+    }
+
+    #[test]
+    fn reject_invalid_proxy() {
+        // Valid as a URL, but longer than an HTTP URI may be.
+        let proxy = format!("http://proxy.example.com:3128/{}", "a".repeat(70000));
+        let connection = ConnectionConfig::new().proxy(&proxy).unwrap();
+        assert!(matches!(
+            SurfClient::try_from_builder(ClientBuilder::new().connection(connection)),
+            Err(Error::ProxyError(_))
+        ));
+    }
+}
@@ -0,0 +1,177 @@
+//! Typed Slack IDs.
+//!
+//! Each ID serializes as a plain string. Converting from `&str` or `String` never fails, so
+//! IDs Slack sends are always accepted; [`str::parse`] also checks the prefix of the kind of ID.
+//!
+//! ```
+//! use slack_rust::id::{ChannelId, UserId};
+//!
+//! let channel: ChannelId = "C1234567890".parse().unwrap();
+//! assert_eq!(channel, "C1234567890");
+//! assert!("C1234567890".parse::<UserId>().is_err());
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// An ID that doesn't start with a prefix its kind of ID uses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidIdError {
+    pub kind: &'static str,
+    pub value: String,
+}
+
+impl fmt::Display for InvalidIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid {} Error: {}", self.kind, self.value)
+    }
+}
+
+impl std::error::Error for InvalidIdError {}
+
+macro_rules! slack_id {
+    ($(#[$meta:meta])* $name:ident, [$($prefix:expr),+]) => {
+        $(#[$meta])*
+        #[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl $name {
+            /// Prefixes this kind of ID starts with.
+            pub const PREFIXES: &'static [&'static str] = &[$($prefix),+];
+
+            pub fn new(id: impl Into<String>) -> $name {
+                $name(id.into())
+            }
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+            pub fn into_string(self) -> String {
+                self.0
+            }
+            /// Whether the ID starts with one of [`Self::PREFIXES`].
+            pub fn is_valid(&self) -> bool {
+                Self::PREFIXES.iter().any(|prefix| self.0.starts_with(prefix))
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = InvalidIdError;
+
+            fn from_str(s: &str) -> Result<$name, InvalidIdError> {
+                let id = $name::new(s);
+                if id.is_valid() {
+                    Ok(id)
+                } else {
+                    Err(InvalidIdError {
+                        kind: stringify!($name),
+                        value: s.to_string(),
+                    })
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(id: &str) -> $name {
+                $name::new(id)
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(id: String) -> $name {
+                $name(id)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> String {
+                id.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl PartialEq<String> for $name {
+            fn eq(&self, other: &String) -> bool {
+                &self.0 == other
+            }
+        }
+    };
+}
+
+slack_id!(
+    /// A public channel, private channel, DM or group DM.
+    ChannelId,
+    ["C", "G", "D"]
+);
+slack_id!(
+    /// A user, including Enterprise Grid users.
+    UserId,
+    ["U", "W"]
+);
+slack_id!(TeamId, ["T"]);
+slack_id!(EnterpriseId, ["E"]);
+slack_id!(BotId, ["B"]);
+slack_id!(AppId, ["A"]);
+slack_id!(
+    /// A user group, also called a subteam.
+    UsergroupId,
+    ["S"]
+);
+slack_id!(FileId, ["F"]);
+slack_id!(ViewId, ["V"]);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_id() {
+        assert_eq!("C024BE91L".parse::<ChannelId>().unwrap(), "C024BE91L");
+        assert_eq!("D024BE91L".parse::<ChannelId>().unwrap(), "D024BE91L");
+        assert_eq!("W012A3CDE".parse::<UserId>().unwrap(), "W012A3CDE");
+        assert_eq!(
+            "U024BE7LH".parse::<ChannelId>(),
+            Err(InvalidIdError {
+                kind: "ChannelId",
+                value: "U024BE7LH".to_string()
+            })
+        );
+        assert!(!UserId::from("C024BE91L").is_valid());
+    }
+
+    #[test]
+    fn convert_id() {
+        let id = ChannelId::from("C024BE91L");
+        assert_eq!(serde_json::to_string(&id).unwrap(), r#""C024BE91L""#);
+        assert_eq!(
+            serde_json::from_str::<ChannelId>(r#""C024BE91L""#).unwrap(),
+            id
+        );
+        assert_eq!(id.to_string(), "C024BE91L");
+        assert_eq!(String::from(id), "C024BE91L");
+    }
+}
@@ -0,0 +1,368 @@
+//! Spans for Web API calls and Socket Mode envelopes, emitted with the `tracing` feature, and
+//! the [`Metrics`](crate::metrics::Metrics) reported for envelopes.
+//!
+//! Each Web API call runs in a `slack.web_api` span with the method, duration, HTTP status, `ok`,
+//! error code and `x-slack-req-id`. Each Socket Mode envelope is handled in a
+//! `slack.socket_mode.envelope` span with the envelope id, type, ack latency and handler duration.
+//! Request, response and envelope bodies are only logged at trace level.
+
+#[cfg(all(
+    feature = "tracing",
+    any(
+        feature = "hyper-client",
+        feature = "reqwest-client",
+        feature = "surf-client",
+        feature = "socket-mode"
+    )
+))]
+fn millis(duration: std::time::Duration) -> u64 {
+    duration.as_millis() as u64
+}
+
+#[cfg(any(
+    feature = "hyper-client",
+    feature = "reqwest-client",
+    feature = "surf-client"
+))]
+pub use self::web_api::web_api_call;
+
+#[cfg(any(
+    feature = "hyper-client",
+    feature = "reqwest-client",
+    feature = "surf-client"
+))]
+mod web_api {
+    use crate::error::Error;
+    use crate::http_client::HttpResponse;
+    use std::future::Future;
+
+    /// Runs a Web API call in a `slack.web_api` span.
+    #[cfg(feature = "tracing")]
+    pub async fn web_api_call<F>(
+        method: &str,
+        body: Option<&str>,
+        call: F,
+    ) -> Result<HttpResponse, Error>
+    where
+        F: Future<Output = Result<HttpResponse, Error>>,
+    {
+        use crate::http_client::DefaultResponse;
+        use tracing::field::Empty;
+        use tracing::Instrument;
+
+        let span = tracing::info_span!(
+            "slack.web_api",
+            method,
+            duration_ms = Empty,
+            status = Empty,
+            ok = Empty,
+            error = Empty,
+            req_id = Empty,
+        );
+        if let Some(body) = body {
+            tracing::trace!(parent: &span, body, "request");
+        }
+
+        let start = std::time::Instant::now();
+        let result = call.instrument(span.clone()).await;
+        span.record("duration_ms", super::millis(start.elapsed()));
+
+        match &result {
+            Ok(response) if !span.is_disabled() => {
+                span.record("status", response.status);
+                if let Some(req_id) = response.header("x-slack-req-id") {
+                    span.record("req_id", req_id);
+                }
+                if let Ok(outcome) = serde_json::from_str::<DefaultResponse>(&response.body) {
+                    span.record("ok", outcome.ok);
+                    if let Some(error) = &outcome.error {
+                        span.record("error", error.as_str());
+                    }
+                }
+                tracing::trace!(parent: &span, body = response.body.as_str(), "response");
+            }
+            Ok(_) => {}
+            Err(e) => {
+                span.record("error", tracing::field::display(e));
+            }
+        }
+        result
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    pub async fn web_api_call<F>(
+        _method: &str,
+        _body: Option<&str>,
+        call: F,
+    ) -> Result<HttpResponse, Error>
+    where
+        F: Future<Output = Result<HttpResponse, Error>>,
+    {
+        call.await
+    }
+}
+
+#[cfg(feature = "socket-mode")]
+pub use self::envelope::{Envelope, PendingAcks};
+
+#[cfg(feature = "socket-mode")]
+mod envelope {
+    use crate::metrics::Metrics;
+    use crate::socket::event::SocketModeEvent;
+    use futures_util::FutureExt;
+    use std::future::Future;
+    use std::panic::{self, AssertUnwindSafe};
+    use std::sync::Arc;
+    use std::time::Instant;
+    #[cfg(feature = "tracing")]
+    use tracing::{field::Empty, Instrument, Span};
+
+    /// An envelope handed to a handler and not acknowledged yet.
+    struct Pending {
+        envelope_id: String,
+        event_type: String,
+        received: Instant,
+        metrics: Arc<dyn Metrics>,
+        #[cfg(feature = "tracing")]
+        span: Span,
+    }
+
+    /// The envelopes of a connection whose handler is running and that haven't been acknowledged
+    /// yet. `ack` only gets the envelope id, so this is how it finds the envelope it acknowledges.
+    #[derive(Default)]
+    pub struct PendingAcks(Vec<Pending>);
+
+    impl PendingAcks {
+        fn take(&mut self, envelope_id: &str) -> Option<Pending> {
+            let index = self
+                .0
+                .iter()
+                .position(|pending| pending.envelope_id == envelope_id)?;
+            Some(self.0.swap_remove(index))
+        }
+
+        /// Reports the ack latency of an envelope that is still being handled.
+        pub fn acknowledged(&mut self, envelope_id: &str) {
+            if let Some(pending) = self.take(envelope_id) {
+                let latency = pending.received.elapsed();
+                pending
+                    .metrics
+                    .envelope_acknowledged(&pending.event_type, latency);
+
+                #[cfg(feature = "tracing")]
+                {
+                    let latency = super::millis(latency);
+                    pending.span.record("ack_latency_ms", latency);
+                    tracing::debug!(
+                        parent: &pending.span,
+                        envelope_id,
+                        ack_latency_ms = latency,
+                        "acknowledged"
+                    );
+                }
+            }
+        }
+    }
+
+    /// A Socket Mode envelope being handled.
+    pub struct Envelope {
+        envelope_id: Option<String>,
+        event_type: String,
+        metrics: Arc<dyn Metrics>,
+        #[cfg(feature = "tracing")]
+        span: Span,
+    }
+
+    impl Envelope {
+        pub fn received(
+            event: &SocketModeEvent,
+            text: &str,
+            metrics: &Arc<dyn Metrics>,
+            pending: &mut PendingAcks,
+        ) -> Envelope {
+            let envelope_id = event.envelope_id().map(str::to_string);
+            let event_type = event.event_type().as_str().to_string();
+            metrics.envelope_received(&event_type);
+
+            #[cfg(feature = "tracing")]
+            let span = tracing::info_span!(
+                "slack.socket_mode.envelope",
+                envelope_id = envelope_id.as_deref(),
+                r#type = event_type.as_str(),
+                ack_latency_ms = Empty,
+                handler_ms = Empty,
+            );
+            #[cfg(feature = "tracing")]
+            tracing::trace!(parent: &span, payload = text, "envelope");
+            #[cfg(not(feature = "tracing"))]
+            log::trace!("{}", text);
+
+            if let Some(id) = &envelope_id {
+                pending.0.push(Pending {
+                    envelope_id: id.clone(),
+                    event_type: event_type.clone(),
+                    received: Instant::now(),
+                    metrics: metrics.clone(),
+                    #[cfg(feature = "tracing")]
+                    span: span.clone(),
+                });
+            }
+            Envelope {
+                envelope_id,
+                event_type,
+                metrics: metrics.clone(),
+                #[cfg(feature = "tracing")]
+                span,
+            }
+        }
+
+        /// Runs the handler, reporting how long it took or that it panicked. A panic is resumed
+        /// once reported.
+        pub async fn handle<F>(&self, handler: F) -> F::Output
+        where
+            F: Future,
+        {
+            #[cfg(feature = "tracing")]
+            let handler = handler.instrument(self.span.clone());
+
+            let start = Instant::now();
+            let output = AssertUnwindSafe(handler).catch_unwind().await;
+            let elapsed = start.elapsed();
+            #[cfg(feature = "tracing")]
+            self.span.record("handler_ms", super::millis(elapsed));
+
+            match output {
+                Ok(output) => {
+                    self.metrics.envelope_handled(&self.event_type, elapsed);
+                    output
+                }
+                Err(payload) => {
+                    self.metrics.handler_failed(&self.event_type);
+                    panic::resume_unwind(payload)
+                }
+            }
+        }
+
+        /// Stops waiting for the ack once the handler has returned: later acks aren't measured.
+        pub fn handled(self, pending: &mut PendingAcks) {
+            if let Some(id) = &self.envelope_id {
+                pending.take(id);
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod test {
+    use super::*;
+    use crate::http_client::HttpResponse;
+    use std::collections::HashMap;
+    use std::fmt;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    type Fields = HashMap<String, String>;
+
+    /// Keeps the name and fields of every span.
+    #[derive(Default)]
+    struct Recorder {
+        spans: Mutex<Vec<(&'static str, Fields)>>,
+    }
+
+    impl Recorder {
+        fn span(&self, name: &str) -> Fields {
+            let spans = self.spans.lock().unwrap();
+            spans.iter().find(|(n, _)| *n == name).unwrap().1.clone()
+        }
+    }
+
+    struct FieldVisitor<'a>(&'a mut Fields);
+
+    impl Visit for FieldVisitor<'_> {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.insert(field.name().to_string(), value.to_string());
+        }
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0
+                .insert(field.name().to_string(), format!("{:?}", value));
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut fields = Fields::new();
+            span.record(&mut FieldVisitor(&mut fields));
+            let mut spans = self.spans.lock().unwrap();
+            spans.push((span.metadata().name(), fields));
+            Id::from_u64(spans.len() as u64)
+        }
+        fn record(&self, span: &Id, values: &Record<'_>) {
+            let mut spans = self.spans.lock().unwrap();
+            values.record(&mut FieldVisitor(
+                &mut spans[span.into_u64() as usize - 1].1,
+            ));
+        }
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+        fn event(&self, _: &Event<'_>) {}
+        fn enter(&self, _: &Id) {}
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[cfg_attr(feature = "tokio-runtime", tokio::test)]
+    #[cfg_attr(not(feature = "tokio-runtime"), async_std::test)]
+    async fn record_web_api_call() {
+        let recorder = Arc::new(Recorder::default());
+        let _guard = tracing::subscriber::set_default(recorder.clone());
+
+        let response = HttpResponse {
+            status: 200,
+            headers: vec![("x-slack-req-id".to_string(), "c9a07dbb".to_string())]
+                .into_iter()
+                .collect(),
+            body: r#"{"ok":false,"error":"channel_not_found"}"#.to_string(),
+        };
+        web_api_call("chat.postMessage", Some("{}"), async { Ok(response) })
+            .await
+            .unwrap();
+
+        let fields = recorder.span("slack.web_api");
+        assert_eq!(fields["method"], "chat.postMessage");
+        assert_eq!(fields["status"], "200");
+        assert_eq!(fields["ok"], "false");
+        assert_eq!(fields["error"], "channel_not_found");
+        assert_eq!(fields["req_id"], "c9a07dbb");
+        assert!(fields.contains_key("duration_ms"));
+    }
+
+    #[cfg(feature = "socket-mode")]
+    #[cfg_attr(feature = "tokio-runtime", tokio::test)]
+    #[cfg_attr(not(feature = "tokio-runtime"), async_std::test)]
+    async fn record_envelope() {
+        use crate::metrics::{Metrics, NoopMetrics};
+        use crate::socket::event::SocketModeEvent;
+
+        let recorder = Arc::new(Recorder::default());
+        let _guard = tracing::subscriber::set_default(recorder.clone());
+
+        let text = r#"{"type":"slash_commands","envelope_id":"1d2e3f","accepts_response_payload":true,"payload":{"command":"/deploy"}}"#;
+        let event = serde_json::from_str::<SocketModeEvent>(text).unwrap();
+        let metrics: Arc<dyn Metrics> = Arc::new(NoopMetrics);
+        let mut pending = PendingAcks::default();
+        let envelope = Envelope::received(&event, text, &metrics, &mut pending);
+        envelope
+            .handle(async { pending.acknowledged("1d2e3f") })
+            .await;
+        envelope.handled(&mut pending);
+
+        let fields = recorder.span("slack.socket_mode.envelope");
+        assert_eq!(fields["envelope_id"], "1d2e3f");
+        assert_eq!(fields["type"], "slash_commands");
+        assert!(fields.contains_key("ack_latency_ms"));
+        assert!(fields.contains_key("handler_ms"));
+    }
+}
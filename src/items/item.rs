@@ -1,17 +1,22 @@
 use crate::chat::message::Message;
 use crate::comments::comment::Comment;
 use crate::files::file::File;
+use crate::id::ChannelId;
+use crate::ts::Ts;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use std::collections::HashMap;
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct Item {
     #[serde(rename = "type")]
     pub type_filed: Option<String>,
-    pub channel: Option<String>,
+    pub channel: Option<ChannelId>,
     pub message: Option<Message>,
     pub file: Option<File>,
     pub comment: Option<Comment>,
-    pub timestamp: Option<String>,
+    pub timestamp: Option<Ts>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
@@ -19,7 +19,7 @@
 //!
 //!     let slack_api_client = default_client();
 //!     let param = PostMessageRequest {
-//!         channel: "channel_id".to_string(),
+//!         channel: "channel_id".into(),
 //!         text: Some("Hello world!!".to_string()),
 //!         ..Default::default()
 //!     };
@@ -40,7 +40,7 @@
 //!         .author_icon("https://!2.bp.blogspot.com/-3o7K8_p8NNM/WGCRsl8GiCI/AAAAAAABAoc/XKnspjvc0YIoOiSRK9HW6wXhtlnZvHQ9QCLcB/s800/pyoko_hashiru.png".to_string())
 //!         .title("slack_rust_example".to_string())
 //!         .build()];
-//!     let param = PostMessageRequest::builder(slack_channel_id)
+//!     let param = PostMessageRequest::builder(slack_channel_id.into())
 //!         .text("Hello World!!".to_string())
 //!         .attachments(attachments)
 //!         .build();
@@ -103,7 +103,8 @@
 //!                 .option_parameter
 //!                 .get("SLACK_CHANNEL_ID")
 //!                 .unwrap()
-//!                 .to_string(),
+//!                 .as_str()
+//!                 .into(),
 //!             text: Some("Hello World!!".to_string()),
 //!             ..Default::default()
 //!         };
@@ -114,27 +115,57 @@
 //!     }
 //! }
 //! ```
+//!
+//! ## Unmodelled fields
+//!
+//! Slack adds fields faster than this crate models them. Types with an `extra` field, such as
+//! [`Message`](chat::message::Message) and [`User`](users::user::User), collect the fields they
+//! don't model there through `#[serde(flatten)]`, so that those survive a round trip.
 
+#[cfg(feature = "web-api-apps")]
 pub mod apps;
 pub mod attachment;
+#[cfg(feature = "web-api-auth")]
 pub mod auth;
+#[cfg(feature = "block-kit")]
 pub mod block;
 pub mod channels;
 pub mod chat;
 pub mod comments;
+pub mod connection;
+#[cfg(feature = "web-api-conversations")]
 pub mod conversations;
+#[cfg(feature = "web-api-dnd")]
 pub mod dnd;
 pub mod error;
+#[cfg(feature = "events-api")]
 pub mod event_api;
 pub mod files;
 pub mod http_client;
+pub mod id;
+mod instrument;
 pub mod items;
+pub mod metrics;
+pub mod mrkdwn;
+#[cfg(feature = "events-api")]
 pub mod payloads;
 pub mod profiles;
 pub mod reactions;
 pub mod reminders;
+#[cfg(any(
+    test,
+    feature = "async-std-runtime",
+    feature = "socket-mode",
+    feature = "tokio-runtime"
+))]
+mod runtime;
+#[cfg(feature = "socket-mode")]
 pub mod socket;
 pub mod team;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+pub mod ts;
 pub mod usergroups;
 pub mod users;
+#[cfg(feature = "block-kit")]
 pub mod views;
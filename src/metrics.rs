@@ -0,0 +1,471 @@
+//! Metrics for Web API usage and Socket Mode event handling.
+//!
+//! Implement [`Metrics`] to feed your own collector, or enable the `metrics` feature and use
+//! `MetricsRecorder` to report through the [metrics](https://docs.rs/metrics) crate. Web API
+//! calls are measured by wrapping a client in [`MeteredClient`], the waits of retried rate
+//! limits by [`RetryClient::metrics`](crate::http_client::retry::RetryClient::metrics), and Socket
+//! Mode envelopes by `SocketMode::metrics`.
+//!
+//! ```
+//! use slack_rust::http_client::default_client;
+//! use slack_rust::metrics::{MeteredClient, Metrics};
+//! use std::time::Duration;
+//!
+//! struct PrintMetrics;
+//!
+//! impl Metrics for PrintMetrics {
+//!     fn web_api_call(&self, method: &str, error: Option<&str>, duration: Duration) {
+//!         println!("{} {:?} {:?}", method, error, duration);
+//!     }
+//! }
+//!
+//! let client = MeteredClient::new(default_client(), PrintMetrics);
+//! ```
+
+use crate::error::Error;
+use crate::http_client::{DefaultResponse, HttpResponse, SlackWebAPIClient};
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// The error code reported for calls that got no response, such as timeouts.
+pub const REQUEST_FAILED: &str = "request_failed";
+
+/// Receives measurements. Every method does nothing by default.
+#[allow(unused_variables)]
+pub trait Metrics: Send + Sync {
+    /// A Web API call finished. `error` is Slack's error code, or [`REQUEST_FAILED`].
+    fn web_api_call(&self, method: &str, error: Option<&str>, duration: Duration) {}
+    /// Slack rate limited a call. `retry_after` is how long a `RetryClient` waited before retrying
+    /// it, or, for a response [`MeteredClient`] passes on, the `Retry-After` Slack asked for.
+    fn rate_limited(&self, method: &str, retry_after: Duration) {}
+    /// `SocketMode::run` opened its WebSocket connection. `run` connects once and doesn't
+    /// reconnect, so more than one means the application ran it again.
+    fn socket_mode_connected(&self) {}
+    /// Slack asked to close the Socket Mode connection.
+    fn socket_mode_disconnect(&self, reason: &str) {}
+    /// A Socket Mode envelope arrived.
+    fn envelope_received(&self, event_type: &str) {}
+    /// An envelope was acknowledged `latency` after it arrived. Only acks sent while the handler
+    /// of the envelope runs are reported, not ones sent later, e.g. from a spawned task.
+    fn envelope_acknowledged(&self, event_type: &str, latency: Duration) {}
+    /// The handler of an envelope returned.
+    fn envelope_handled(&self, event_type: &str, duration: Duration) {}
+    /// The handler of an envelope panicked.
+    fn handler_failed(&self, event_type: &str) {}
+}
+
+/// Discards every measurement.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopMetrics;
+
+impl Metrics for NoopMetrics {}
+
+impl<M> Metrics for Arc<M>
+where
+    M: Metrics + ?Sized,
+{
+    fn web_api_call(&self, method: &str, error: Option<&str>, duration: Duration) {
+        (**self).web_api_call(method, error, duration)
+    }
+    fn rate_limited(&self, method: &str, retry_after: Duration) {
+        (**self).rate_limited(method, retry_after)
+    }
+    fn socket_mode_connected(&self) {
+        (**self).socket_mode_connected()
+    }
+    fn socket_mode_disconnect(&self, reason: &str) {
+        (**self).socket_mode_disconnect(reason)
+    }
+    fn envelope_received(&self, event_type: &str) {
+        (**self).envelope_received(event_type)
+    }
+    fn envelope_acknowledged(&self, event_type: &str, latency: Duration) {
+        (**self).envelope_acknowledged(event_type, latency)
+    }
+    fn envelope_handled(&self, event_type: &str, duration: Duration) {
+        (**self).envelope_handled(event_type, duration)
+    }
+    fn handler_failed(&self, event_type: &str) {
+        (**self).handler_failed(event_type)
+    }
+}
+
+/// A client that reports each call it sends to [`Metrics`].
+///
+/// Wrap it around a `RetryClient`, not the other way round, so that a call and its retries are
+/// measured once. Rate limits that `RetryClient` retries never reach it; give the `RetryClient`
+/// the same metrics to report those.
+pub struct MeteredClient<C> {
+    client: C,
+    metrics: Arc<dyn Metrics>,
+}
+
+impl<C> MeteredClient<C>
+where
+    C: SlackWebAPIClient,
+{
+    pub fn new<M>(client: C, metrics: M) -> MeteredClient<C>
+    where
+        M: Metrics + 'static,
+    {
+        MeteredClient {
+            client,
+            metrics: Arc::new(metrics),
+        }
+    }
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+    pub fn metrics(&self) -> &Arc<dyn Metrics> {
+        &self.metrics
+    }
+
+    fn record(&self, method: &str, response: &Result<HttpResponse, Error>, duration: Duration) {
+        let response = match response {
+            Ok(response) => response,
+            Err(_) => {
+                self.metrics
+                    .web_api_call(method, Some(REQUEST_FAILED), duration);
+                return;
+            }
+        };
+        if response.status == 429 {
+            let retry_after = response
+                .header("retry-after")
+                .and_then(|seconds| seconds.trim().parse().ok())
+                .map(Duration::from_secs)
+                .unwrap_or_default();
+            self.metrics.rate_limited(method, retry_after);
+        }
+        let error = serde_json::from_str::<DefaultResponse>(&response.body)
+            .ok()
+            .and_then(|response| response.error);
+        self.metrics
+            .web_api_call(method, error.as_deref(), duration);
+    }
+}
+
+#[async_trait]
+impl<C> SlackWebAPIClient for MeteredClient<C>
+where
+    C: SlackWebAPIClient,
+{
+    async fn call_json(&self, method: &str, body: &str, token: &str) -> Result<String, Error> {
+        Ok(self.send(method, Some(body), token).await?.body)
+    }
+    async fn call(&self, method: &str, token: &str) -> Result<String, Error> {
+        Ok(self.send(method, None, token).await?.body)
+    }
+    async fn send<'a>(
+        &self,
+        method: &str,
+        body: Option<&'a str>,
+        token: &str,
+    ) -> Result<HttpResponse, Error> {
+        let start = Instant::now();
+        let response = self.client.send(method, body, token).await;
+        self.record(method, &response, start.elapsed());
+        response
+    }
+}
+
+/// Reports to the [metrics](https://docs.rs/metrics) crate's global recorder.
+///
+/// | Metric | Type | Labels |
+/// | --- | --- | --- |
+/// | `slack_web_api_calls_total` | counter | `method`, `error` (empty on success) |
+/// | `slack_web_api_call_duration_seconds` | histogram | `method` |
+/// | `slack_web_api_rate_limited_total` | counter | `method` |
+/// | `slack_web_api_rate_limit_wait_seconds` | histogram | `method` |
+/// | `slack_socket_mode_connections_total` | counter | |
+/// | `slack_socket_mode_disconnects_total` | counter | `reason` |
+/// | `slack_socket_mode_envelopes_total` | counter | `type` |
+/// | `slack_socket_mode_ack_latency_seconds` | histogram | `type` |
+/// | `slack_socket_mode_handler_duration_seconds` | histogram | `type` |
+/// | `slack_socket_mode_handler_failures_total` | counter | `type` |
+#[cfg(feature = "metrics")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MetricsRecorder;
+
+#[cfg(feature = "metrics")]
+impl Metrics for MetricsRecorder {
+    fn web_api_call(&self, method: &str, error: Option<&str>, duration: Duration) {
+        ::metrics::counter!(
+            "slack_web_api_calls_total",
+            1,
+            "method" => method.to_string(),
+            "error" => error.unwrap_or_default().to_string()
+        );
+        ::metrics::histogram!(
+            "slack_web_api_call_duration_seconds",
+            duration.as_secs_f64(),
+            "method" => method.to_string()
+        );
+    }
+    fn rate_limited(&self, method: &str, retry_after: Duration) {
+        ::metrics::counter!("slack_web_api_rate_limited_total", 1, "method" => method.to_string());
+        ::metrics::histogram!(
+            "slack_web_api_rate_limit_wait_seconds",
+            retry_after.as_secs_f64(),
+            "method" => method.to_string()
+        );
+    }
+    fn socket_mode_connected(&self) {
+        ::metrics::counter!("slack_socket_mode_connections_total", 1);
+    }
+    fn socket_mode_disconnect(&self, reason: &str) {
+        ::metrics::counter!(
+            "slack_socket_mode_disconnects_total",
+            1,
+            "reason" => reason.to_string()
+        );
+    }
+    fn envelope_received(&self, event_type: &str) {
+        ::metrics::counter!(
+            "slack_socket_mode_envelopes_total",
+            1,
+            "type" => event_type.to_string()
+        );
+    }
+    fn envelope_acknowledged(&self, event_type: &str, latency: Duration) {
+        ::metrics::histogram!(
+            "slack_socket_mode_ack_latency_seconds",
+            latency.as_secs_f64(),
+            "type" => event_type.to_string()
+        );
+    }
+    fn envelope_handled(&self, event_type: &str, duration: Duration) {
+        ::metrics::histogram!(
+            "slack_socket_mode_handler_duration_seconds",
+            duration.as_secs_f64(),
+            "type" => event_type.to_string()
+        );
+    }
+    fn handler_failed(&self, event_type: &str) {
+        ::metrics::counter!(
+            "slack_socket_mode_handler_failures_total",
+            1,
+            "type" => event_type.to_string()
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::http_client::MockSlackWebAPIClient;
+    use std::sync::Mutex;
+
+    /// Keeps every measurement as a line of text.
+    #[derive(Default)]
+    struct RecordedMetrics(Mutex<Vec<String>>);
+
+    impl RecordedMetrics {
+        fn lines(&self) -> Vec<String> {
+            self.0.lock().unwrap().clone()
+        }
+        fn push(&self, line: String) {
+            self.0.lock().unwrap().push(line);
+        }
+    }
+
+    impl Metrics for RecordedMetrics {
+        fn web_api_call(&self, method: &str, error: Option<&str>, _: Duration) {
+            self.push(format!("web_api_call {} {:?}", method, error));
+        }
+        fn rate_limited(&self, method: &str, retry_after: Duration) {
+            self.push(format!("rate_limited {} {:?}", method, retry_after));
+        }
+        fn socket_mode_connected(&self) {
+            self.push("socket_mode_connected".to_string());
+        }
+        fn socket_mode_disconnect(&self, reason: &str) {
+            self.push(format!("socket_mode_disconnect {}", reason));
+        }
+        fn envelope_received(&self, event_type: &str) {
+            self.push(format!("envelope_received {}", event_type));
+        }
+        fn envelope_acknowledged(&self, event_type: &str, _: Duration) {
+            self.push(format!("envelope_acknowledged {}", event_type));
+        }
+        fn envelope_handled(&self, event_type: &str, _: Duration) {
+            self.push(format!("envelope_handled {}", event_type));
+        }
+        fn handler_failed(&self, event_type: &str) {
+            self.push(format!("handler_failed {}", event_type));
+        }
+    }
+
+    #[cfg_attr(feature = "tokio-runtime", tokio::test)]
+    #[cfg_attr(not(feature = "tokio-runtime"), async_std::test)]
+    async fn meter_web_api_calls() {
+        let mut mock = MockSlackWebAPIClient::new();
+        mock.expect_send()
+            .withf(|method, _, _| method == "chat.postMessage")
+            .returning(|_, _, _| {
+                Ok(HttpResponse {
+                    status: 429,
+                    headers: vec![("retry-after".to_string(), "30".to_string())]
+                        .into_iter()
+                        .collect(),
+                    body: r#"{"ok":false,"error":"ratelimited"}"#.to_string(),
+                })
+            });
+        mock.expect_send()
+            .withf(|method, _, _| method == "auth.test")
+            .returning(|_, _, _| {
+                Ok(HttpResponse {
+                    status: 200,
+                    body: r#"{"ok":true}"#.to_string(),
+                    ..Default::default()
+                })
+            });
+        mock.expect_send()
+            .withf(|method, _, _| method == "users.list")
+            .returning(|_, _, _| Err(Error::TimeoutError));
+
+        let metrics = Arc::new(RecordedMetrics::default());
+        let client = MeteredClient::new(mock, metrics.clone());
+        client
+            .call_json("chat.postMessage", "{}", "xoxb-token")
+            .await
+            .unwrap();
+        client.call("auth.test", "xoxb-token").await.unwrap();
+        assert!(client.call("users.list", "xoxb-token").await.is_err());
+
+        assert_eq!(
+            metrics.lines(),
+            vec![
+                "rate_limited chat.postMessage 30s",
+                r#"web_api_call chat.postMessage Some("ratelimited")"#,
+                "web_api_call auth.test None",
+                r#"web_api_call users.list Some("request_failed")"#,
+            ]
+        );
+    }
+
+    #[cfg_attr(feature = "tokio-runtime", tokio::test)]
+    #[cfg_attr(not(feature = "tokio-runtime"), async_std::test)]
+    async fn meter_retried_rate_limits() {
+        use crate::http_client::retry::{RetryClient, RetryPolicy};
+
+        let mut mock = MockSlackWebAPIClient::new();
+        mock.expect_send().times(1).returning(|_, _, _| {
+            Ok(HttpResponse {
+                status: 429,
+                headers: vec![("retry-after".to_string(), "0".to_string())]
+                    .into_iter()
+                    .collect(),
+                body: r#"{"ok":false,"error":"ratelimited"}"#.to_string(),
+            })
+        });
+        mock.expect_send().times(1).returning(|_, _, _| {
+            Ok(HttpResponse {
+                status: 200,
+                body: r#"{"ok":true}"#.to_string(),
+                ..Default::default()
+            })
+        });
+
+        let metrics = Arc::new(RecordedMetrics::default());
+        let policy = RetryPolicy::new()
+            .initial_backoff(Duration::from_millis(10))
+            .jitter(0.0);
+        let client = MeteredClient::new(
+            RetryClient::new(mock, policy).metrics(metrics.clone()),
+            metrics.clone(),
+        );
+        client.call("users.list", "xoxb-token").await.unwrap();
+
+        assert_eq!(
+            metrics.lines(),
+            vec![
+                "rate_limited users.list 10ms",
+                "web_api_call users.list None"
+            ]
+        );
+    }
+
+    #[cfg(feature = "socket-mode")]
+    #[cfg_attr(feature = "tokio-runtime", tokio::test)]
+    #[cfg_attr(not(feature = "tokio-runtime"), async_std::test)]
+    async fn meter_socket_mode() {
+        use crate::socket::event::EventsAPI;
+        use crate::socket::socket_mode::{ack, EventHandler, SocketMode, Stream};
+        use crate::testing::fake_slack::FakeSlack;
+        use crate::testing::fake_socket_mode::FakeSocketMode;
+        use futures_util::future::join;
+        use serde_json::json;
+
+        struct Handler;
+
+        #[async_trait]
+        impl<S> EventHandler<S> for Handler
+        where
+            S: SlackWebAPIClient,
+        {
+            async fn on_events_api(&mut self, _: &SocketMode<S>, e: EventsAPI, s: &mut Stream) {
+                ack(&e.envelope_id, s).await.unwrap();
+            }
+        }
+
+        let slack = FakeSlack::new();
+        let server = FakeSocketMode::start().await.unwrap();
+        slack.serve_socket_mode(&server);
+
+        let metrics = Arc::new(RecordedMetrics::default());
+        let socket_mode = SocketMode::new(
+            slack.clone(),
+            "xapp-token".to_string(),
+            "xoxb-token".to_string(),
+        )
+        .metrics(metrics.clone());
+        let client = async move { socket_mode.run(&mut Handler).await.unwrap() };
+        let server_side = async {
+            server
+                .push_json(json!({
+                    "type": "events_api",
+                    "envelope_id": "0b5e8bb4-5d2e-4c53-9f43-6b3c5a8f4e1d",
+                    "accepts_response_payload": false,
+                    "payload": {
+                        "token": "bHKJ2n9AW6Ju3MjciOHfbA1b",
+                        "team_id": "T1234567890",
+                        "api_app_id": "A0000000000",
+                        "event_id": "Ev0000000000",
+                        "event_time": 1600000000,
+                        "type": "event_callback",
+                        "event": {
+                            "type": "app_home_opened",
+                            "user": "U061F7AUR",
+                            "channel": "D0LAN2Q65",
+                            "event_ts": "1515449522000016",
+                            "tab": "messages"
+                        }
+                    }
+                }))
+                .await
+                .unwrap();
+            server.next_ack().await.unwrap();
+            server
+                .push_json(json!({"type": "disconnect", "reason": "refresh_requested"}))
+                .await
+                .unwrap();
+            server.close().await.unwrap();
+        };
+        join(client, server_side).await;
+
+        assert_eq!(
+            metrics.lines(),
+            vec![
+                "socket_mode_connected",
+                "envelope_received events_api",
+                "envelope_acknowledged events_api",
+                "envelope_handled events_api",
+                "socket_mode_disconnect refresh_requested",
+                "envelope_received disconnect",
+                "envelope_handled disconnect",
+            ]
+        );
+    }
+}
@@ -0,0 +1,391 @@
+//! Safely compose Slack `mrkdwn` text.
+//!
+//! Every function that accepts `impl Into<Mrkdwn>` escapes plain strings, so user supplied
+//! text can never inject mentions or links. Values that are already `Mrkdwn` are used as-is,
+//! which allows formatting to be nested.
+//!
+//! ```
+//! use slack_rust::mrkdwn::format::{bold, link, user, Mrkdwn};
+//!
+//! let text = Mrkdwn::builder()
+//!     .push(user("U061F7AUR"))
+//!     .text(" opened ")
+//!     .push(bold(link("https://example.com/pr/1", "<script> & PR #1")))
+//!     .build();
+//!
+//! assert_eq!(
+//!     text.as_str(),
+//!     "<@U061F7AUR> opened *<https://example.com/pr/1|&lt;script&gt; &amp; PR #1>*"
+//! );
+//! ```
+
+#[cfg(feature = "block-kit")]
+use crate::block::block_object::{TextBlockObject, TextBlockType};
+use std::fmt;
+
+/// A string of `mrkdwn` that is safe to send to Slack.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Mrkdwn(String);
+
+impl Mrkdwn {
+    /// Escapes `text` so it is displayed literally.
+    pub fn text(text: &str) -> Mrkdwn {
+        Mrkdwn(escape(text))
+    }
+    /// Wraps already formatted `mrkdwn` without escaping it.
+    pub fn raw(mrkdwn: String) -> Mrkdwn {
+        Mrkdwn(mrkdwn)
+    }
+    pub fn builder() -> MrkdwnBuilder {
+        MrkdwnBuilder::new()
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_string(self) -> String {
+        self.0
+    }
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<&str> for Mrkdwn {
+    fn from(text: &str) -> Mrkdwn {
+        Mrkdwn::text(text)
+    }
+}
+
+impl From<String> for Mrkdwn {
+    fn from(text: String) -> Mrkdwn {
+        Mrkdwn::text(&text)
+    }
+}
+
+impl From<&String> for Mrkdwn {
+    fn from(text: &String) -> Mrkdwn {
+        Mrkdwn::text(text)
+    }
+}
+
+impl From<Mrkdwn> for String {
+    fn from(mrkdwn: Mrkdwn) -> String {
+        mrkdwn.0
+    }
+}
+
+#[cfg(feature = "block-kit")]
+impl From<Mrkdwn> for TextBlockObject {
+    fn from(mrkdwn: Mrkdwn) -> TextBlockObject {
+        TextBlockObject::builder(TextBlockType::Mrkdwn, mrkdwn.0).build()
+    }
+}
+
+impl fmt::Display for Mrkdwn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Composes `mrkdwn` piece by piece.
+#[derive(Debug, Default)]
+pub struct MrkdwnBuilder {
+    pub mrkdwn: String,
+}
+
+impl MrkdwnBuilder {
+    pub fn new() -> MrkdwnBuilder {
+        MrkdwnBuilder {
+            ..Default::default()
+        }
+    }
+    /// Appends escaped plain text.
+    pub fn text(mut self, text: &str) -> MrkdwnBuilder {
+        self.mrkdwn.push_str(&escape(text));
+        self
+    }
+    /// Appends `mrkdwn`, escaping plain strings.
+    pub fn push(mut self, mrkdwn: impl Into<Mrkdwn>) -> MrkdwnBuilder {
+        self.mrkdwn.push_str(mrkdwn.into().as_str());
+        self
+    }
+    pub fn bold(self, text: impl Into<Mrkdwn>) -> MrkdwnBuilder {
+        self.push(bold(text))
+    }
+    pub fn italic(self, text: impl Into<Mrkdwn>) -> MrkdwnBuilder {
+        self.push(italic(text))
+    }
+    pub fn strike(self, text: impl Into<Mrkdwn>) -> MrkdwnBuilder {
+        self.push(strike(text))
+    }
+    pub fn code(self, text: impl Into<Mrkdwn>) -> MrkdwnBuilder {
+        self.push(code(text))
+    }
+    pub fn user(self, user_id: &str) -> MrkdwnBuilder {
+        self.push(user(user_id))
+    }
+    pub fn channel(self, channel_id: &str) -> MrkdwnBuilder {
+        self.push(channel(channel_id))
+    }
+    pub fn usergroup(self, usergroup_id: &str) -> MrkdwnBuilder {
+        self.push(usergroup(usergroup_id))
+    }
+    pub fn special_mention(self, mention: SpecialMention) -> MrkdwnBuilder {
+        self.push(special_mention(mention))
+    }
+    pub fn link(self, url: &str, label: impl Into<Mrkdwn>) -> MrkdwnBuilder {
+        self.push(link(url, label))
+    }
+    pub fn newline(mut self) -> MrkdwnBuilder {
+        self.mrkdwn.push('\n');
+        self
+    }
+    pub fn build(self) -> Mrkdwn {
+        Mrkdwn(self.mrkdwn)
+    }
+}
+
+/// Special mentions that notify a group of people.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpecialMention {
+    Here,
+    Channel,
+    Everyone,
+}
+
+/// Escapes the control characters `&`, `<` and `>`.
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+pub fn bold(text: impl Into<Mrkdwn>) -> Mrkdwn {
+    Mrkdwn(format!("*{}*", text.into()))
+}
+
+pub fn italic(text: impl Into<Mrkdwn>) -> Mrkdwn {
+    Mrkdwn(format!("_{}_", text.into()))
+}
+
+pub fn strike(text: impl Into<Mrkdwn>) -> Mrkdwn {
+    Mrkdwn(format!("~{}~", text.into()))
+}
+
+pub fn code(text: impl Into<Mrkdwn>) -> Mrkdwn {
+    Mrkdwn(format!("`{}`", text.into()))
+}
+
+pub fn code_block(text: impl Into<Mrkdwn>) -> Mrkdwn {
+    Mrkdwn(format!("```\n{}\n```", text.into()))
+}
+
+/// Quotes every line of `text`.
+pub fn quote(text: impl Into<Mrkdwn>) -> Mrkdwn {
+    let text = text.into();
+    Mrkdwn(
+        text.as_str()
+            .split('\n')
+            .map(|line| format!("> {}", line))
+            .collect::<Vec<String>>()
+            .join("\n"),
+    )
+}
+
+pub fn bulleted_list<T: Into<Mrkdwn>>(items: impl IntoIterator<Item = T>) -> Mrkdwn {
+    Mrkdwn(
+        items
+            .into_iter()
+            .map(|item| format!("• {}", item.into()))
+            .collect::<Vec<String>>()
+            .join("\n"),
+    )
+}
+
+pub fn ordered_list<T: Into<Mrkdwn>>(items: impl IntoIterator<Item = T>) -> Mrkdwn {
+    Mrkdwn(
+        items
+            .into_iter()
+            .enumerate()
+            .map(|(i, item)| format!("{}. {}", i + 1, item.into()))
+            .collect::<Vec<String>>()
+            .join("\n"),
+    )
+}
+
+/// Mentions a user, e.g. `<@U061F7AUR>`.
+pub fn user(user_id: &str) -> Mrkdwn {
+    Mrkdwn(format!("<@{}>", escape_token(user_id)))
+}
+
+/// Links a channel, e.g. `<#C024BE7LR>`.
+pub fn channel(channel_id: &str) -> Mrkdwn {
+    Mrkdwn(format!("<#{}>", escape_token(channel_id)))
+}
+
+/// Mentions a user group, e.g. `<!subteam^SAZ94GDB8>`.
+pub fn usergroup(usergroup_id: &str) -> Mrkdwn {
+    Mrkdwn(format!("<!subteam^{}>", escape_token(usergroup_id)))
+}
+
+/// Mentions `@here`, `@channel` or `@everyone`.
+pub fn special_mention(mention: SpecialMention) -> Mrkdwn {
+    let name = match mention {
+        SpecialMention::Here => "here",
+        SpecialMention::Channel => "channel",
+        SpecialMention::Everyone => "everyone",
+    };
+    Mrkdwn(format!("<!{}>", name))
+}
+
+/// Links `url` with a label, e.g. `<https://example.com|Example>`.
+pub fn link(url: &str, label: impl Into<Mrkdwn>) -> Mrkdwn {
+    let label = label.into();
+    if label.is_empty() {
+        Mrkdwn(format!("<{}>", escape_url(url)))
+    } else {
+        Mrkdwn(format!(
+            "<{}|{}>",
+            escape_url(url),
+            label.as_str().replace('|', "\u{2223}")
+        ))
+    }
+}
+
+/// Formats a UNIX timestamp in the reader's timezone, e.g. `<!date^1392734382^{date_short}|Feb 18, 2014>`.
+/// See: <https://api.slack.com/reference/surfaces/formatting#date-formatting>
+pub fn date(
+    timestamp: i64,
+    format: &str,
+    link: Option<&str>,
+    fallback: impl Into<Mrkdwn>,
+) -> Mrkdwn {
+    let link = link
+        .map(|url| format!("^{}", escape_url(url)))
+        .unwrap_or_default();
+    Mrkdwn(format!(
+        "<!date^{}^{}{}|{}>",
+        timestamp,
+        escape_token(format),
+        link,
+        fallback.into().as_str().replace('|', "\u{2223}")
+    ))
+}
+
+fn escape_token(token: &str) -> String {
+    escape(token).replace(['|', '^'], "")
+}
+
+fn escape_url(url: &str) -> String {
+    escape(url).replace('|', "%7C")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(
+            escape("<@U123> & <!channel>"),
+            "&lt;@U123&gt; &amp; &lt;!channel&gt;"
+        );
+        assert_eq!(Mrkdwn::from("a > b").as_str(), "a &gt; b");
+        assert_eq!(Mrkdwn::raw("<!here>".to_string()).as_str(), "<!here>");
+    }
+
+    #[test]
+    fn test_mentions() {
+        assert_eq!(user("U061F7AUR").as_str(), "<@U061F7AUR>");
+        assert_eq!(channel("C024BE7LR").as_str(), "<#C024BE7LR>");
+        assert_eq!(usergroup("SAZ94GDB8").as_str(), "<!subteam^SAZ94GDB8>");
+        assert_eq!(special_mention(SpecialMention::Here).as_str(), "<!here>");
+        assert_eq!(
+            special_mention(SpecialMention::Channel).as_str(),
+            "<!channel>"
+        );
+        assert_eq!(
+            special_mention(SpecialMention::Everyone).as_str(),
+            "<!everyone>"
+        );
+        assert_eq!(user("U1>|<!here").as_str(), "<@U1&gt;&lt;!here>");
+    }
+
+    #[test]
+    fn test_link() {
+        assert_eq!(
+            link("https://example.com/?a=1&b=2", "A | B").as_str(),
+            "<https://example.com/?a=1&amp;b=2|A \u{2223} B>"
+        );
+        assert_eq!(
+            link("https://example.com/a|b", "").as_str(),
+            "<https://example.com/a%7Cb>"
+        );
+    }
+
+    #[test]
+    fn test_date() {
+        assert_eq!(
+            date(1392734382, "{date_short} at {time}", None, "Feb 18, 2014").as_str(),
+            "<!date^1392734382^{date_short} at {time}|Feb 18, 2014>"
+        );
+        assert_eq!(
+            date(
+                1392734382,
+                "{date_num}",
+                Some("https://example.com"),
+                "2014-02-18"
+            )
+            .as_str(),
+            "<!date^1392734382^{date_num}^https://example.com|2014-02-18>"
+        );
+    }
+
+    #[test]
+    fn test_styles() {
+        assert_eq!(bold("a*b").as_str(), "*a*b*");
+        assert_eq!(italic("a").as_str(), "_a_");
+        assert_eq!(strike("a").as_str(), "~a~");
+        assert_eq!(code("<a>").as_str(), "`&lt;a&gt;`");
+        assert_eq!(
+            code_block("fn main() {}").as_str(),
+            "```\nfn main() {}\n```"
+        );
+        assert_eq!(quote("a\n<b>").as_str(), "> a\n> &lt;b&gt;");
+        assert_eq!(bulleted_list(vec!["a", "b"]).as_str(), "• a\n• b");
+        assert_eq!(ordered_list(vec!["a", "b"]).as_str(), "1. a\n2. b");
+        assert_eq!(bold(italic("a")).as_str(), "*_a_*");
+    }
+
+    #[test]
+    fn test_builder() {
+        let text = Mrkdwn::builder()
+            .special_mention(SpecialMention::Here)
+            .text(" deploy of ")
+            .code("<main>")
+            .text(" finished by ")
+            .user("U061F7AUR")
+            .newline()
+            .bold("Status:")
+            .text(" ok")
+            .build();
+
+        assert_eq!(
+            text.as_str(),
+            "<!here> deploy of `&lt;main&gt;` finished by <@U061F7AUR>\n*Status:* ok"
+        );
+
+        #[cfg(feature = "block-kit")]
+        {
+            let block = TextBlockObject::from(text);
+            assert_eq!(block.type_filed, TextBlockType::Mrkdwn);
+        }
+    }
+}
@@ -0,0 +1,5 @@
+//! [Formatting text for app surfaces.](https://api.slack.com/reference/surfaces/formatting)
+
+pub mod format;
+pub mod parser;
+pub mod render;
@@ -0,0 +1,412 @@
+//! Parses Slack encoded `mrkdwn` text into an AST.
+//!
+//! ```
+//! use slack_rust::mrkdwn::parser::{parse, Node};
+//!
+//! let nodes = parse("Hi <@U061F7AUR>, see <https://example.com|*this*> &amp; more");
+//! assert_eq!(nodes[1], Node::User { id: "U061F7AUR".to_string(), label: None });
+//! ```
+
+/// A node of parsed `mrkdwn`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node {
+    /// Plain text with entities (`&amp;`, `&lt;`, `&gt;`) decoded.
+    Text(String),
+    Bold(Vec<Node>),
+    Italic(Vec<Node>),
+    Strike(Vec<Node>),
+    Code(String),
+    CodeBlock(String),
+    Quote(Vec<Node>),
+    LineBreak,
+    Link {
+        url: String,
+        label: Option<String>,
+    },
+    User {
+        id: String,
+        label: Option<String>,
+    },
+    Channel {
+        id: String,
+        label: Option<String>,
+    },
+    Usergroup {
+        id: String,
+        label: Option<String>,
+    },
+    /// `@here`, `@channel` or `@everyone`.
+    SpecialMention(String),
+    Date {
+        timestamp: i64,
+        format: String,
+        link: Option<String>,
+        fallback: String,
+    },
+    /// An emoji shortcode such as `:wave:`, without the colons.
+    Emoji(String),
+}
+
+/// Parses `mrkdwn` text as it is received from Slack.
+pub fn parse(text: &str) -> Vec<Node> {
+    let mut nodes = Vec::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find("```") {
+        let body = &rest[start + 3..];
+        let end = match body.find("```") {
+            Some(end) => end,
+            None => break,
+        };
+        let before = &rest[..start];
+        parse_lines(before.strip_suffix('\n').unwrap_or(before), &mut nodes);
+        let code = &body[..end];
+        let code = code.strip_prefix('\n').unwrap_or(code);
+        let code = code.strip_suffix('\n').unwrap_or(code);
+        nodes.push(Node::CodeBlock(decode_entities(code)));
+
+        let after = &body[end + 3..];
+        rest = after.strip_prefix('\n').unwrap_or(after);
+    }
+    parse_lines(rest, &mut nodes);
+
+    nodes
+}
+
+/// Decodes the entities Slack uses to escape control characters.
+pub fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+fn parse_lines(text: &str, nodes: &mut Vec<Node>) {
+    if text.is_empty() {
+        return;
+    }
+
+    let mut quote: Option<Vec<Node>> = None;
+    let mut first = true;
+    for line in text.split('\n') {
+        let quoted = line
+            .strip_prefix("&gt;")
+            .or_else(|| line.strip_prefix('>'))
+            .map(|l| l.strip_prefix(' ').unwrap_or(l));
+
+        match quoted {
+            Some(content) => {
+                let children = quote.get_or_insert_with(Vec::new);
+                if !children.is_empty() {
+                    children.push(Node::LineBreak);
+                }
+                children.extend(parse_inline(&content.chars().collect::<Vec<char>>()));
+            }
+            None => {
+                if let Some(children) = quote.take() {
+                    nodes.push(Node::Quote(children));
+                } else if !first {
+                    nodes.push(Node::LineBreak);
+                }
+                nodes.extend(parse_inline(&line.chars().collect::<Vec<char>>()));
+            }
+        }
+        first = false;
+    }
+    if let Some(children) = quote.take() {
+        nodes.push(Node::Quote(children));
+    }
+}
+
+fn parse_inline(chars: &[char]) -> Vec<Node> {
+    let mut nodes = Vec::new();
+    let mut buffer = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let parsed = match c {
+            '<' => find(chars, i + 1, '>').map(|end| {
+                let inner: String = chars[i + 1..end].iter().collect();
+                (parse_entity(&inner), end + 1)
+            }),
+            '`' => find(chars, i + 1, '`')
+                .filter(|end| *end > i + 1)
+                .map(|end| {
+                    let code: String = chars[i + 1..end].iter().collect();
+                    (Node::Code(decode_entities(&code)), end + 1)
+                }),
+            '*' | '_' | '~' if opens(chars, i) => find_closing(chars, i).map(|end| {
+                let children = parse_inline(&chars[i + 1..end]);
+                let node = match c {
+                    '*' => Node::Bold(children),
+                    '_' => Node::Italic(children),
+                    _ => Node::Strike(children),
+                };
+                (node, end + 1)
+            }),
+            ':' if i == 0 || !chars[i - 1].is_alphanumeric() => emoji_end(chars, i).map(|end| {
+                let name: String = chars[i + 1..end].iter().collect();
+                (Node::Emoji(name), end + 1)
+            }),
+            _ => None,
+        };
+
+        match parsed {
+            Some((node, next)) => {
+                if !buffer.is_empty() {
+                    nodes.push(Node::Text(decode_entities(&buffer)));
+                    buffer.clear();
+                }
+                nodes.push(node);
+                i = next;
+            }
+            None => {
+                buffer.push(c);
+                i += 1;
+            }
+        }
+    }
+    if !buffer.is_empty() {
+        nodes.push(Node::Text(decode_entities(&buffer)));
+    }
+
+    nodes
+}
+
+fn parse_entity(inner: &str) -> Node {
+    let (target, label) = match inner.find('|') {
+        Some(i) => (&inner[..i], Some(decode_entities(&inner[i + 1..]))),
+        None => (inner, None),
+    };
+
+    if let Some(id) = target.strip_prefix('@') {
+        return Node::User {
+            id: id.to_string(),
+            label,
+        };
+    }
+    if let Some(id) = target.strip_prefix('#') {
+        return Node::Channel {
+            id: id.to_string(),
+            label,
+        };
+    }
+    if let Some(command) = target.strip_prefix('!') {
+        if let Some(id) = command.strip_prefix("subteam^") {
+            return Node::Usergroup {
+                id: id.to_string(),
+                label,
+            };
+        }
+        if let Some(date) = command.strip_prefix("date^") {
+            let mut parts = date.splitn(3, '^');
+            let timestamp = parts.next().and_then(|ts| ts.parse::<i64>().ok());
+            let format = parts.next();
+            if let (Some(timestamp), Some(format)) = (timestamp, format) {
+                return Node::Date {
+                    timestamp,
+                    format: decode_entities(format),
+                    link: parts.next().map(decode_entities),
+                    fallback: label.unwrap_or_default(),
+                };
+            }
+        }
+        return match command {
+            "here" | "channel" | "everyone" => Node::SpecialMention(command.to_string()),
+            _ => Node::Text(label.unwrap_or_else(|| format!("<{}>", decode_entities(inner)))),
+        };
+    }
+
+    Node::Link {
+        url: decode_entities(target),
+        label,
+    }
+}
+
+/// Returns the index of the first `target` at or after `start`.
+fn find(chars: &[char], start: usize, target: char) -> Option<usize> {
+    (start..chars.len()).find(|&j| chars[j] == target)
+}
+
+fn is_boundary(c: Option<&char>) -> bool {
+    match c {
+        None => true,
+        Some(c) => !c.is_alphanumeric(),
+    }
+}
+
+fn opens(chars: &[char], i: usize) -> bool {
+    let before = if i == 0 { None } else { chars.get(i - 1) };
+    let after = chars.get(i + 1);
+    is_boundary(before) && after.is_some_and(|c| !c.is_whitespace())
+}
+
+/// Finds the marker closing the one at `start`, skipping over `<...>` and `` `...` `` spans.
+fn find_closing(chars: &[char], start: usize) -> Option<usize> {
+    let marker = chars[start];
+    let mut j = start + 1;
+    while j < chars.len() {
+        match chars[j] {
+            '<' | '`' => {
+                let close = if chars[j] == '<' { '>' } else { '`' };
+                j = find(chars, j + 1, close).map_or(j + 1, |end| end + 1);
+                continue;
+            }
+            c if c == marker
+                && j > start + 1
+                && !chars[j - 1].is_whitespace()
+                && is_boundary(chars.get(j + 1)) =>
+            {
+                return Some(j);
+            }
+            _ => {}
+        }
+        j += 1;
+    }
+    None
+}
+
+fn emoji_end(chars: &[char], start: usize) -> Option<usize> {
+    let mut j = start + 1;
+    while j < chars.len() {
+        let c = chars[j];
+        if c == ':' {
+            return if j > start + 1 && is_boundary(chars.get(j + 1)) {
+                Some(j)
+            } else {
+                None
+            };
+        }
+        if !(c.is_ascii_lowercase() || c.is_ascii_digit() || "_+-'".contains(c)) {
+            return None;
+        }
+        j += 1;
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn text(text: &str) -> Node {
+        Node::Text(text.to_string())
+    }
+
+    #[test]
+    fn parse_entities() {
+        assert_eq!(
+            parse("<@U123> <@U456|bob> <#C1|general> <!subteam^S1|@team> <!here> <!channel>"),
+            vec![
+                Node::User {
+                    id: "U123".to_string(),
+                    label: None
+                },
+                text(" "),
+                Node::User {
+                    id: "U456".to_string(),
+                    label: Some("bob".to_string())
+                },
+                text(" "),
+                Node::Channel {
+                    id: "C1".to_string(),
+                    label: Some("general".to_string())
+                },
+                text(" "),
+                Node::Usergroup {
+                    id: "S1".to_string(),
+                    label: Some("@team".to_string())
+                },
+                text(" "),
+                Node::SpecialMention("here".to_string()),
+                text(" "),
+                Node::SpecialMention("channel".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_links_and_escapes() {
+        assert_eq!(
+            parse("a &amp; b &lt;c&gt; <https://x.com/?a=1&amp;b=2|x &amp; y> <mailto:a@b.com>"),
+            vec![
+                text("a & b <c> "),
+                Node::Link {
+                    url: "https://x.com/?a=1&b=2".to_string(),
+                    label: Some("x & y".to_string())
+                },
+                text(" "),
+                Node::Link {
+                    url: "mailto:a@b.com".to_string(),
+                    label: None
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_date() {
+        assert_eq!(
+            parse("<!date^1392734382^{date_short}^https://x.com|Feb 18, 2014>"),
+            vec![Node::Date {
+                timestamp: 1392734382,
+                format: "{date_short}".to_string(),
+                link: Some("https://x.com".to_string()),
+                fallback: "Feb 18, 2014".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_styles() {
+        assert_eq!(
+            parse("*bold _italic_* ~strike~ `co*de*` snake_case_word 2*3*4"),
+            vec![
+                Node::Bold(vec![text("bold "), Node::Italic(vec![text("italic")])]),
+                text(" "),
+                Node::Strike(vec![text("strike")]),
+                text(" "),
+                Node::Code("co*de*".to_string()),
+                text(" snake_case_word 2*3*4"),
+            ]
+        );
+        assert_eq!(
+            parse("_<https://a_b.com|link>_"),
+            vec![Node::Italic(vec![Node::Link {
+                url: "https://a_b.com".to_string(),
+                label: Some("link".to_string())
+            }])]
+        );
+    }
+
+    #[test]
+    fn parse_emoji() {
+        assert_eq!(
+            parse(":wave: hi :+1: at 10:30:45"),
+            vec![
+                Node::Emoji("wave".to_string()),
+                text(" hi "),
+                Node::Emoji("+1".to_string()),
+                text(" at 10:30:45"),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_blocks() {
+        assert_eq!(
+            parse("first\n&gt; quoted\n&gt; *more*\nlast\n```\nlet a = 1 &amp;&amp; 2;\n```\nend"),
+            vec![
+                text("first"),
+                Node::Quote(vec![
+                    text("quoted"),
+                    Node::LineBreak,
+                    Node::Bold(vec![text("more")]),
+                ]),
+                text("last"),
+                Node::CodeBlock("let a = 1 && 2;".to_string()),
+                text("end"),
+            ]
+        );
+    }
+}
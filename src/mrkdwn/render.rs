@@ -0,0 +1,436 @@
+//! Renders parsed `mrkdwn` as plain text, CommonMark or HTML.
+//!
+//! ```
+//! use slack_rust::mrkdwn::parser::parse;
+//! use slack_rust::mrkdwn::render::{to_html, to_markdown, to_plain_text, Resolver};
+//!
+//! struct Names;
+//!
+//! impl Resolver for Names {
+//!     fn user(&self, user_id: &str) -> Option<String> {
+//!         Some(format!("user-{}", user_id))
+//!     }
+//! }
+//!
+//! let nodes = parse("*Hi* <@U1> &amp; <https://example.com|docs>");
+//! assert_eq!(to_plain_text(&nodes, &Names), "Hi @user-U1 & docs");
+//! assert_eq!(to_markdown(&nodes, &Names), "**Hi** @user\\-U1 & [docs](https://example.com)");
+//! assert_eq!(
+//!     to_html(&nodes, &Names),
+//!     r#"<strong>Hi</strong> <span class="mention">@user-U1</span> &amp; <a href="https://example.com">docs</a>"#
+//! );
+//! ```
+
+use crate::mrkdwn::parser::Node;
+
+/// Resolves IDs and emoji shortcodes into display text while rendering.
+///
+/// Every method returns `None` by default, in which case the raw ID or shortcode is used.
+pub trait Resolver {
+    fn user(&self, user_id: &str) -> Option<String> {
+        let _ = user_id;
+        None
+    }
+    fn channel(&self, channel_id: &str) -> Option<String> {
+        let _ = channel_id;
+        None
+    }
+    fn usergroup(&self, usergroup_id: &str) -> Option<String> {
+        let _ = usergroup_id;
+        None
+    }
+    fn emoji(&self, name: &str) -> Option<String> {
+        let _ = name;
+        None
+    }
+}
+
+/// A resolver that leaves every ID and shortcode as-is.
+pub struct NoopResolver;
+
+impl Resolver for NoopResolver {}
+
+/// Renders nodes as plain text, suitable for search indexing.
+pub fn to_plain_text(nodes: &[Node], resolver: &dyn Resolver) -> String {
+    let mut out = String::new();
+    plain_text(nodes, resolver, &mut out);
+    out.trim_end_matches('\n').to_string()
+}
+
+/// Renders nodes as CommonMark.
+///
+/// Like [`to_html`], only `http`, `https` and `mailto` links stay links.
+pub fn to_markdown(nodes: &[Node], resolver: &dyn Resolver) -> String {
+    let mut out = String::new();
+    markdown(nodes, resolver, &mut out);
+    out.trim_end_matches('\n').to_string()
+}
+
+/// Renders nodes as an HTML fragment.
+///
+/// Only `http`, `https` and `mailto` links become anchors; any other link is rendered as its text.
+pub fn to_html(nodes: &[Node], resolver: &dyn Resolver) -> String {
+    let mut out = String::new();
+    html(nodes, resolver, &mut out);
+    out
+}
+
+fn mention(node: &Node, resolver: &dyn Resolver) -> Option<String> {
+    let name = match node {
+        Node::User { id, label } => format!(
+            "@{}",
+            resolver
+                .user(id)
+                .or_else(|| label.clone())
+                .unwrap_or_else(|| id.clone())
+        ),
+        Node::Channel { id, label } => format!(
+            "#{}",
+            resolver
+                .channel(id)
+                .or_else(|| label.clone())
+                .unwrap_or_else(|| id.clone())
+        ),
+        Node::Usergroup { id, label } => {
+            let name = resolver
+                .usergroup(id)
+                .or_else(|| label.clone())
+                .unwrap_or_else(|| id.clone());
+            if name.starts_with('@') {
+                name
+            } else {
+                format!("@{}", name)
+            }
+        }
+        Node::SpecialMention(name) => format!("@{}", name),
+        _ => return None,
+    };
+    Some(name)
+}
+
+fn emoji(name: &str, resolver: &dyn Resolver) -> String {
+    resolver
+        .emoji(name)
+        .unwrap_or_else(|| format!(":{}:", name))
+}
+
+fn start_block(out: &mut String) {
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push('\n');
+    }
+}
+
+fn plain_text(nodes: &[Node], resolver: &dyn Resolver, out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) | Node::Code(text) => out.push_str(text),
+            Node::Bold(children) | Node::Italic(children) | Node::Strike(children) => {
+                plain_text(children, resolver, out)
+            }
+            Node::CodeBlock(code) => {
+                start_block(out);
+                out.push_str(code);
+                out.push('\n');
+            }
+            Node::Quote(children) => {
+                start_block(out);
+                plain_text(children, resolver, out);
+                out.push('\n');
+            }
+            Node::LineBreak => out.push('\n'),
+            Node::Link { url, label } => out.push_str(label.as_ref().unwrap_or(url)),
+            Node::Date { fallback, .. } => out.push_str(fallback),
+            Node::Emoji(name) => out.push_str(&emoji(name, resolver)),
+            node => out.push_str(&mention(node, resolver).unwrap_or_default()),
+        }
+    }
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if "\\`*_[]<>~#|-!".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+fn markdown(nodes: &[Node], resolver: &dyn Resolver, out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(&escape_markdown(text)),
+            Node::Bold(children) => {
+                out.push_str("**");
+                markdown(children, resolver, out);
+                out.push_str("**");
+            }
+            Node::Italic(children) => {
+                out.push('*');
+                markdown(children, resolver, out);
+                out.push('*');
+            }
+            Node::Strike(children) => {
+                out.push_str("~~");
+                markdown(children, resolver, out);
+                out.push_str("~~");
+            }
+            Node::Code(code) => {
+                if code.contains('`') {
+                    out.push_str(&format!("`` {} ``", code));
+                } else {
+                    out.push_str(&format!("`{}`", code));
+                }
+            }
+            Node::CodeBlock(code) => {
+                start_block(out);
+                out.push_str(&format!("```\n{}\n```\n", code));
+            }
+            Node::Quote(children) => {
+                start_block(out);
+                let mut quoted = String::new();
+                markdown(children, resolver, &mut quoted);
+                for line in quoted.trim_end_matches('\n').split('\n') {
+                    out.push_str("> ");
+                    out.push_str(line);
+                    out.push('\n');
+                }
+            }
+            Node::LineBreak => {
+                if out.is_empty() || out.ends_with('\n') {
+                    out.push('\n');
+                } else {
+                    out.push_str("  \n");
+                }
+            }
+            Node::Link { url, label } => markdown_link(Some(url), label.as_deref(), out),
+            Node::Date { fallback, link, .. } => markdown_link(link.as_ref(), Some(fallback), out),
+            Node::Emoji(name) => out.push_str(&escape_markdown(&emoji(name, resolver))),
+            node => out.push_str(&escape_markdown(
+                &mention(node, resolver).unwrap_or_default(),
+            )),
+        }
+    }
+}
+
+/// Escapes text for use in HTML content and attribute values.
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn html(nodes: &[Node], resolver: &dyn Resolver, out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(&escape_html(text)),
+            Node::Bold(children) => {
+                out.push_str("<strong>");
+                html(children, resolver, out);
+                out.push_str("</strong>");
+            }
+            Node::Italic(children) => {
+                out.push_str("<em>");
+                html(children, resolver, out);
+                out.push_str("</em>");
+            }
+            Node::Strike(children) => {
+                out.push_str("<del>");
+                html(children, resolver, out);
+                out.push_str("</del>");
+            }
+            Node::Code(code) => out.push_str(&format!("<code>{}</code>", escape_html(code))),
+            Node::CodeBlock(code) => {
+                out.push_str(&format!("<pre><code>{}</code></pre>", escape_html(code)))
+            }
+            Node::Quote(children) => {
+                out.push_str("<blockquote>");
+                html(children, resolver, out);
+                out.push_str("</blockquote>");
+            }
+            Node::LineBreak => out.push_str("<br>"),
+            Node::Link { url, label } => html_link(Some(url), label.as_ref().unwrap_or(url), out),
+            Node::Date { fallback, link, .. } => html_link(link.as_ref(), fallback, out),
+            Node::Emoji(name) => out.push_str(&escape_html(&emoji(name, resolver))),
+            node => out.push_str(&format!(
+                r#"<span class="mention">{}</span>"#,
+                escape_html(&mention(node, resolver).unwrap_or_default())
+            )),
+        }
+    }
+}
+
+/// Writes a link with `text` as its label, or as an autolink without one.
+fn markdown_link(url: Option<&String>, text: Option<&str>, out: &mut String) {
+    match (url.filter(|url| is_safe_link(url)), text) {
+        (Some(url), Some(text)) => out.push_str(&format!(
+            "[{}]({})",
+            escape_markdown(text),
+            escape_link_destination(url)
+        )),
+        (Some(url), None) => out.push_str(&format!("<{}>", escape_link_destination(url))),
+        (None, text) => out.push_str(&escape_markdown(
+            text.or(url.map(String::as_str)).unwrap_or_default(),
+        )),
+    }
+}
+
+/// Percent-encodes the characters that would end a Markdown link destination early.
+fn escape_link_destination(url: &str) -> String {
+    let mut escaped = String::with_capacity(url.len());
+    for c in url.chars() {
+        match c {
+            ' ' => escaped.push_str("%20"),
+            '(' => escaped.push_str("%28"),
+            ')' => escaped.push_str("%29"),
+            '<' => escaped.push_str("%3C"),
+            '>' => escaped.push_str("%3E"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn html_link(url: Option<&String>, text: &str, out: &mut String) {
+    match url.filter(|url| is_safe_link(url)) {
+        Some(url) => out.push_str(&format!(
+            r#"<a href="{}">{}</a>"#,
+            escape_html(url),
+            escape_html(text)
+        )),
+        None => out.push_str(&escape_html(text)),
+    }
+}
+
+/// Whether a link can be an HTML anchor, rather than run script like `javascript:` and `data:` do.
+pub(crate) fn is_safe_link(url: &str) -> bool {
+    url::Url::parse(url)
+        .map(|url| matches!(url.scheme(), "http" | "https" | "mailto"))
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mrkdwn::parser::parse;
+
+    struct Names;
+
+    impl Resolver for Names {
+        fn user(&self, user_id: &str) -> Option<String> {
+            match user_id {
+                "U1" => Some("alice".to_string()),
+                _ => None,
+            }
+        }
+        fn channel(&self, channel_id: &str) -> Option<String> {
+            match channel_id {
+                "C1" => Some("general".to_string()),
+                _ => None,
+            }
+        }
+        fn emoji(&self, name: &str) -> Option<String> {
+            match name {
+                "wave" => Some("\u{1f44b}".to_string()),
+                _ => None,
+            }
+        }
+    }
+
+    const TEXT: &str = "*Deploy* <@U1> in <#C1> :wave: :tada:\n&gt; _note_ &lt;b&gt;\n```\nif a &lt; b {}\n```\n<!here> <!date^1392734382^{date}|Feb 18> <https://x.com>";
+
+    #[test]
+    fn render_plain_text() {
+        assert_eq!(
+            to_plain_text(&parse(TEXT), &Names),
+            "Deploy @alice in #general \u{1f44b} :tada:\nnote <b>\nif a < b {}\n@here Feb 18 https://x.com"
+        );
+        assert_eq!(
+            to_plain_text(&parse("<@U2> <#C2|random>"), &NoopResolver),
+            "@U2 #random"
+        );
+    }
+
+    #[test]
+    fn render_markdown() {
+        assert_eq!(
+            to_markdown(&parse(TEXT), &Names),
+            "**Deploy** @alice in \\#general \u{1f44b} :tada:\n> *note* \\<b\\>\n```\nif a < b {}\n```\n@here Feb 18 <https://x.com>"
+        );
+    }
+
+    #[test]
+    fn render_html() {
+        assert_eq!(
+            to_html(&parse(TEXT), &Names),
+            "<strong>Deploy</strong> <span class=\"mention\">@alice</span> in <span class=\"mention\">#general</span> \u{1f44b} :tada:<blockquote><em>note</em> &lt;b&gt;</blockquote><pre><code>if a &lt; b {}</code></pre><span class=\"mention\">@here</span> Feb 18 <a href=\"https://x.com\">https://x.com</a>"
+        );
+    }
+
+    #[test]
+    fn render_unsafe_links_as_text() {
+        assert_eq!(
+            to_html(&parse("<javascript:alert(1)|click>"), &Names),
+            "click"
+        );
+        assert_eq!(
+            to_html(&parse("<JavaScript:alert(document.cookie)>"), &Names),
+            "JavaScript:alert(document.cookie)"
+        );
+        assert_eq!(
+            to_html(&parse("<data:text/html;base64,PHNjcmlwdD4=|x>"), &Names),
+            "x"
+        );
+        assert_eq!(
+            to_html(&parse("<mailto:ops@example.com|ops>"), &Names),
+            "<a href=\"mailto:ops@example.com\">ops</a>"
+        );
+        assert_eq!(
+            to_html(
+                &parse("<!date^1392734382^{date_short}^javascript:alert(1)|Feb 18>"),
+                &Names
+            ),
+            "Feb 18"
+        );
+    }
+
+    #[test]
+    fn render_unsafe_markdown_links_as_text() {
+        assert_eq!(
+            to_markdown(&parse("<javascript:alert(1)|click>"), &Names),
+            "click"
+        );
+        assert_eq!(
+            to_markdown(&parse("<javascript:alert(1)>"), &Names),
+            "javascript:alert(1)"
+        );
+        assert_eq!(
+            to_markdown(
+                &parse("<!date^1392734382^{date_short}^javascript:alert(1)|Feb 18>"),
+                &Names
+            ),
+            "Feb 18"
+        );
+        assert_eq!(
+            to_markdown(
+                &parse("<https://en.wikipedia.org/wiki/Rust_(programming_language)|Rust>"),
+                &Names
+            ),
+            "[Rust](https://en.wikipedia.org/wiki/Rust_%28programming_language%29)"
+        );
+        assert_eq!(
+            to_markdown(&parse("<https://x.com/a b>"), &Names),
+            "<https://x.com/a%20b>"
+        );
+    }
+}
@@ -1,7 +1,9 @@
 use crate::block::block_object::TextBlockObject;
 use crate::channels::channel::Channel;
 use crate::chat::message::Message;
+use crate::id::{AppId, ChannelId, TeamId, UserId};
 use crate::team::teams::Team;
+use crate::ts::Ts;
 use crate::users::user::User;
 use crate::views::view::View;
 use serde::{Deserialize, Serialize};
@@ -11,11 +13,11 @@ use serde_with::skip_serializing_none;
 #[derive(Deserialize, Serialize, Debug, PartialEq)]
 pub struct SlashPayload {
     pub token: Option<String>,
-    pub team_id: Option<String>,
+    pub team_id: Option<TeamId>,
     pub team_domain: Option<String>,
-    pub channel_id: Option<String>,
+    pub channel_id: Option<ChannelId>,
     pub channel_name: Option<String>,
-    pub user_id: Option<String>,
+    pub user_id: Option<UserId>,
     pub user_name: Option<String>,
     pub command: Option<String>,
     pub text: Option<String>,
@@ -30,7 +32,7 @@ pub struct InteractivePayload {
     pub type_filed: InteractiveEventType,
     pub team: Option<Team>,
     pub user: Option<User>,
-    pub api_app_id: Option<String>,
+    pub api_app_id: Option<AppId>,
     pub token: Option<String>,
     pub container: Option<Container>,
     pub trigger_id: Option<String>,
@@ -60,6 +62,9 @@ pub enum InteractiveEventType {
     ViewSubmission,
     ViewClosed,
     Shortcut,
+    /// A value this crate doesn't know yet.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[skip_serializing_none]
@@ -67,9 +72,9 @@ pub enum InteractiveEventType {
 pub struct Container {
     #[serde(rename = "type")]
     pub type_filed: String,
-    pub message_ts: Option<String>,
+    pub message_ts: Option<Ts>,
     pub attachment_id: Option<i32>,
-    pub channel_id: Option<String>,
+    pub channel_id: Option<ChannelId>,
     pub is_ephemeral: Option<bool>,
     pub is_app_unfurl: Option<bool>,
 }
@@ -1,12 +1,14 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, DefaultResponse, SlackWebAPIClient};
+use crate::http_client::{DefaultResponse, SlackWebAPIClient};
+use crate::id::ChannelId;
+use crate::ts::Ts;
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct AddRequest {
-    pub channel: String,
+    pub channel: ChannelId,
     pub name: String,
-    pub timestamp: String,
+    pub timestamp: Ts,
 }
 
 pub async fn add<T>(
@@ -17,11 +19,10 @@ pub async fn add<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("reactions.add");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("reactions.add", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<DefaultResponse>(&result).map_err(Error::SerdeJsonError)
@@ -36,9 +37,9 @@ mod test {
     #[test]
     fn convert_request() {
         let request = AddRequest {
-            channel: "C1234567890".to_string(),
+            channel: "C1234567890".into(),
             name: "thumbsup".to_string(),
-            timestamp: "1234567890.123456".to_string(),
+            timestamp: "1234567890.123456".into(),
         };
         let json = r##"{
   "channel": "C1234567890",
@@ -56,13 +57,13 @@ mod test {
     #[async_std::test]
     async fn test_add() {
         let param = AddRequest {
-            channel: "C1234567890".to_string(),
+            channel: "C1234567890".into(),
             name: "thumbsup".to_string(),
-            timestamp: "1234567890.123456".to_string(),
+            timestamp: "1234567890.123456".into(),
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true
 }"##
@@ -1,17 +1,19 @@
 use crate::error::Error;
 use crate::files::file::File;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::ChannelId;
+use crate::ts::Ts;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct GetRequest {
-    pub channel: Option<String>,
+    pub channel: Option<ChannelId>,
     pub file: Option<String>,
     pub file_comment: Option<String>,
     pub full: Option<bool>,
-    pub timestamp: Option<String>,
+    pub timestamp: Option<Ts>,
 }
 
 #[skip_serializing_none]
@@ -29,11 +31,10 @@ pub async fn get<T>(client: &T, param: &GetRequest, bot_token: &str) -> Result<G
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("reactions.get");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("reactions.get", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<GetResponse>(&result).map_err(Error::SerdeJsonError)
@@ -49,11 +50,11 @@ mod test {
     #[test]
     fn convert_request() {
         let request = GetRequest {
-            channel: Some("C0NF841BK".to_string()),
+            channel: Some("C0NF841BK".into()),
             file: Some("F1234567890".to_string()),
             file_comment: Some("Fc1234567890".to_string()),
             full: Some(true),
-            timestamp: Some("1524523204.000192".to_string()),
+            timestamp: Some("1524523204.000192".into()),
         };
         let json = r##"{
   "channel": "C0NF841BK",
@@ -75,21 +76,22 @@ mod test {
         let response = GetResponse {
             ok: true,
             file: Some(File {
-                channels: Some(vec!["C2U7V2YA2".to_string()]),
+                channels: Some(vec!["C2U7V2YA2".into()]),
                 comments_count: Some(1),
                 created: Some(1507850315),
                 groups: Some(vec![]),
-                id: Some("F7H0D7ZA4".to_string()),
+                id: Some("F7H0D7ZA4".into()),
                 ims: Some(vec![]),
                 name: Some("computer.gif".to_string()),
                 reactions: Some(vec![Reaction {
                     count: Some(1),
                     name: Some("stuck_out_tongue_winking_eye".to_string()),
-                    users: Some(vec!["U2U85N1RV".to_string()]),
+                    users: Some(vec!["U2U85N1RV".into()]),
                 }]),
                 timestamp: Some(1507850315),
                 title: Some("computer.gif".to_string()),
-                user: Some("U2U85N1RV".to_string()),
+                user: Some("U2U85N1RV".into()),
+                ..Default::default()
             }),
             type_filed: Some("file".to_string()),
             ..Default::default()
@@ -132,15 +134,15 @@ mod test {
     #[async_std::test]
     async fn test_get() {
         let param = GetRequest {
-            channel: Some("C0NF841BK".to_string()),
+            channel: Some("C0NF841BK".into()),
             file: Some("F1234567890".to_string()),
             file_comment: Some("Fc1234567890".to_string()),
             full: Some(true),
-            timestamp: Some("1524523204.000192".to_string()),
+            timestamp: Some("1524523204.000192".into()),
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "file": {
@@ -175,21 +177,22 @@ mod test {
         let expect = GetResponse {
             ok: true,
             file: Some(File {
-                channels: Some(vec!["C0NF841BK".to_string()]),
+                channels: Some(vec!["C0NF841BK".into()]),
                 comments_count: Some(1),
                 created: Some(1507850315),
                 groups: Some(vec![]),
-                id: Some("F1234567890".to_string()),
+                id: Some("F1234567890".into()),
                 ims: Some(vec![]),
                 name: Some("computer.gif".to_string()),
                 reactions: Some(vec![Reaction {
                     count: Some(1),
                     name: Some("stuck_out_tongue_winking_eye".to_string()),
-                    users: Some(vec!["U2U85N1RV".to_string()]),
+                    users: Some(vec!["U2U85N1RV".into()]),
                 }]),
                 timestamp: Some(1524523204),
                 title: Some("computer.gif".to_string()),
-                user: Some("U2U85N1RV".to_string()),
+                user: Some("U2U85N1RV".into()),
+                ..Default::default()
             }),
             type_filed: Some("file".to_string()),
             ..Default::default()
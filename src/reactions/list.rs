@@ -1,5 +1,6 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::{TeamId, UserId};
 use crate::items::item::Item;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -12,8 +13,8 @@ pub struct ListRequest {
     pub full: Option<bool>,
     pub limit: Option<i32>,
     pub page: Option<i32>,
-    pub team_id: Option<String>,
-    pub user: Option<String>,
+    pub team_id: Option<TeamId>,
+    pub user: Option<UserId>,
 }
 
 #[skip_serializing_none]
@@ -35,11 +36,10 @@ pub async fn list<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("reactions.list");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("reactions.list", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<ListResponse>(&result).map_err(Error::SerdeJsonError)
@@ -49,7 +49,7 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::chat::message::Message;
+    use crate::chat::message::{Message, MessageSubtype};
     use crate::http_client::MockSlackWebAPIClient;
     use crate::reactions::reaction::Reaction;
 
@@ -60,9 +60,9 @@ mod test {
             full: Some(true),
             limit: Some(20),
             page: Some(2),
-            team_id: Some("T1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
             cursor: Some("dXNlcjpVMDYxTkZUVDI=".to_string()),
-            user: Some("W1234567890".to_string()),
+            user: Some("W1234567890".into()),
         };
         let json = r##"{
   "count": 20,
@@ -87,17 +87,20 @@ mod test {
             ok: true,
             items: Some(vec![Item {
                 type_filed: Some("message".to_string()),
-                channel: Some("C3UKJTQAC".to_string()),
+                channel: Some("C3UKJTQAC".into()),
                 message: Some(Message {
-                    bot_id: Some("B4VLRLMKJ".to_string()),
+                    bot_id: Some("B4VLRLMKJ".into()),
                     text: Some("Hello from Python! :tada:".to_string()),
                     username: Some("Shipit Notifications".to_string()),
-                    ts: Some("1507849573.000090".to_string()),
-                    subtype: Some("bot_message".to_string()),
+                    ts: Some("1507849573.000090".into()),
+                    subtype: Some(MessageSubtype::BotMessage {
+                        bot_profile: None,
+                        icons: None,
+                    }),
                     reactions: Some(vec![Reaction {
                         count: Some(1),
                         name: Some("robot_face".to_string()),
-                        users: Some(vec!["U2U85N1RV".to_string()]),
+                        users: Some(vec!["U2U85N1RV".into()]),
                     }]),
                     ..Default::default()
                 }),
@@ -145,13 +148,13 @@ mod test {
             full: Some(true),
             limit: Some(20),
             page: Some(2),
-            team_id: Some("T1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
             cursor: Some("dXNlcjpVMDYxTkZUVDI=".to_string()),
-            user: Some("W1234567890".to_string()),
+            user: Some("W1234567890".into()),
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "items": [
@@ -187,17 +190,20 @@ mod test {
             ok: true,
             items: Some(vec![Item {
                 type_filed: Some("message".to_string()),
-                channel: Some("C3UKJTQAC".to_string()),
+                channel: Some("C3UKJTQAC".into()),
                 message: Some(Message {
-                    bot_id: Some("B4VLRLMKJ".to_string()),
+                    bot_id: Some("B4VLRLMKJ".into()),
                     text: Some("Hello from Python! :tada:".to_string()),
                     username: Some("Shipit Notifications".to_string()),
-                    ts: Some("1507849573.000090".to_string()),
-                    subtype: Some("bot_message".to_string()),
+                    ts: Some("1507849573.000090".into()),
+                    subtype: Some(MessageSubtype::BotMessage {
+                        bot_profile: None,
+                        icons: None,
+                    }),
                     reactions: Some(vec![Reaction {
                         count: Some(1),
                         name: Some("robot_face".to_string()),
-                        users: Some(vec!["U2U85N1RV".to_string()]),
+                        users: Some(vec!["U2U85N1RV".into()]),
                     }]),
                     ..Default::default()
                 }),
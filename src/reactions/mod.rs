@@ -1,5 +1,9 @@
+#[cfg(feature = "web-api-reactions")]
 pub mod add;
+#[cfg(feature = "web-api-reactions")]
 pub mod get;
+#[cfg(feature = "web-api-reactions")]
 pub mod list;
 pub mod reaction;
+#[cfg(feature = "web-api-reactions")]
 pub mod remove;
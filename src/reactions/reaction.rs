@@ -1,3 +1,4 @@
+use crate::id::UserId;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
@@ -6,5 +7,5 @@ use serde_with::skip_serializing_none;
 pub struct Reaction {
     pub count: Option<i32>,
     pub name: Option<String>,
-    pub users: Option<Vec<String>>,
+    pub users: Option<Vec<UserId>>,
 }
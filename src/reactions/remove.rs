@@ -1,5 +1,7 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, DefaultResponse, SlackWebAPIClient};
+use crate::http_client::{DefaultResponse, SlackWebAPIClient};
+use crate::id::ChannelId;
+use crate::ts::Ts;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
@@ -7,10 +9,10 @@ use serde_with::skip_serializing_none;
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct RemoveRequest {
     pub name: String,
-    pub channel: Option<String>,
+    pub channel: Option<ChannelId>,
     pub file: Option<String>,
     pub file_comment: Option<String>,
-    pub timestamp: Option<String>,
+    pub timestamp: Option<Ts>,
 }
 
 pub async fn remove<T>(
@@ -21,11 +23,10 @@ pub async fn remove<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("reactions.remove");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("reactions.remove", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<DefaultResponse>(&result).map_err(Error::SerdeJsonError)
@@ -41,10 +42,10 @@ mod test {
     fn convert_request() {
         let request = RemoveRequest {
             name: "thumbsup".to_string(),
-            channel: Some("C1234567890".to_string()),
+            channel: Some("C1234567890".into()),
             file: Some("F1234567890".to_string()),
             file_comment: Some("Fc1234567890".to_string()),
-            timestamp: Some("1234567890.123456".to_string()),
+            timestamp: Some("1234567890.123456".into()),
         };
         let json = r##"{
   "name": "thumbsup",
@@ -65,14 +66,14 @@ mod test {
     async fn test_remove() {
         let param = RemoveRequest {
             name: "thumbsup".to_string(),
-            channel: Some("C1234567890".to_string()),
+            channel: Some("C1234567890".into()),
             file: Some("F1234567890".to_string()),
             file_comment: Some("Fc1234567890".to_string()),
-            timestamp: Some("1234567890.123456".to_string()),
+            timestamp: Some("1234567890.123456".into()),
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true
 }"##
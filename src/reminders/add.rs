@@ -1,5 +1,6 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::{TeamId, UserId};
 use crate::reminders::recurrence::Recurrence;
 use crate::reminders::reminder::Reminder;
 use serde::{Deserialize, Serialize};
@@ -11,8 +12,8 @@ pub struct AddRequest {
     pub text: String,
     pub time: String,
     pub recurrence: Option<Recurrence>,
-    pub team_id: Option<String>,
-    pub user: Option<String>,
+    pub team_id: Option<TeamId>,
+    pub user: Option<UserId>,
 }
 
 #[skip_serializing_none]
@@ -28,11 +29,10 @@ pub async fn add<T>(client: &T, param: &AddRequest, bot_token: &str) -> Result<A
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("reminders.add");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("reminders.add", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<AddResponse>(&result).map_err(Error::SerdeJsonError)
@@ -57,8 +57,8 @@ mod test {
                     "friday".to_string(),
                 ]),
             }),
-            team_id: Some("T1234567890".to_string()),
-            user: Some("U18888888".to_string()),
+            team_id: Some("T1234567890".into()),
+            user: Some("U18888888".into()),
         };
         let json = r##"{
   "text": "eat a banana",
@@ -88,8 +88,8 @@ mod test {
             ok: true,
             reminder: Some(Reminder {
                 id: Some("Rm12345678".to_string()),
-                creator: Some("U18888888".to_string()),
-                user: Some("U18888888".to_string()),
+                creator: Some("U18888888".into()),
+                user: Some("U18888888".into()),
                 text: Some("eat a banana".to_string()),
                 recurring: Some(false),
                 time: Some(1602288000),
@@ -130,11 +130,11 @@ mod test {
                     "friday".to_string(),
                 ]),
             }),
-            team_id: Some("T1234567890".to_string()),
-            user: Some("U18888888".to_string()),
+            team_id: Some("T1234567890".into()),
+            user: Some("U18888888".into()),
         };
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "reminder": {
@@ -155,8 +155,8 @@ mod test {
             ok: true,
             reminder: Some(Reminder {
                 id: Some("Rm12345678".to_string()),
-                creator: Some("U18888888".to_string()),
-                user: Some("U18888888".to_string()),
+                creator: Some("U18888888".into()),
+                user: Some("U18888888".into()),
                 text: Some("eat a banana".to_string()),
                 recurring: Some(false),
                 time: Some(1602288000),
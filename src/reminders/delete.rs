@@ -1,5 +1,6 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, DefaultResponse, SlackWebAPIClient};
+use crate::http_client::{DefaultResponse, SlackWebAPIClient};
+use crate::id::TeamId;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
@@ -7,7 +8,7 @@ use serde_with::skip_serializing_none;
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct DeleteRequest {
     pub reminder: String,
-    pub team_id: Option<String>,
+    pub team_id: Option<TeamId>,
 }
 
 pub async fn delete<T>(
@@ -18,11 +19,10 @@ pub async fn delete<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("reminders.delete");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("reminders.delete", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<DefaultResponse>(&result).map_err(Error::SerdeJsonError)
@@ -38,7 +38,7 @@ mod test {
     fn convert_request() {
         let request = DeleteRequest {
             reminder: "Rm12345678".to_string(),
-            team_id: Some("T1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
         };
         let json = r##"{
   "reminder": "Rm12345678",
@@ -56,11 +56,11 @@ mod test {
     async fn test_delete() {
         let param = DeleteRequest {
             reminder: "Rm12345678".to_string(),
-            team_id: Some("T1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true
 }"##
@@ -1,5 +1,6 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::TeamId;
 use crate::reminders::reminder::Reminder;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -8,7 +9,7 @@ use serde_with::skip_serializing_none;
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct InfoRequest {
     pub reminder: String,
-    pub team_id: Option<String>,
+    pub team_id: Option<TeamId>,
 }
 
 #[skip_serializing_none]
@@ -28,11 +29,10 @@ pub async fn info<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("reminders.info");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("reminders.info", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<InfoResponse>(&result).map_err(Error::SerdeJsonError)
@@ -48,7 +48,7 @@ mod test {
     fn convert_request() {
         let request = InfoRequest {
             reminder: "Rm12345678".to_string(),
-            team_id: Some("T1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
         };
         let json = r##"{
   "reminder": "Rm12345678",
@@ -68,8 +68,8 @@ mod test {
             ok: true,
             reminder: Some(Reminder {
                 id: Some("Rm12345678".to_string()),
-                creator: Some("U18888888".to_string()),
-                user: Some("U18888888".to_string()),
+                creator: Some("U18888888".into()),
+                user: Some("U18888888".into()),
                 text: Some("eat a banana".to_string()),
                 recurring: Some(false),
                 time: Some(1602288000),
@@ -101,10 +101,10 @@ mod test {
     async fn test_info() {
         let param = InfoRequest {
             reminder: "Rm12345678".to_string(),
-            team_id: Some("T1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
         };
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "reminder": {
@@ -127,8 +127,8 @@ mod test {
             ok: true,
             reminder: Some(Reminder {
                 id: Some("Rm12345678".to_string()),
-                creator: Some("U18888888".to_string()),
-                user: Some("U18888888".to_string()),
+                creator: Some("U18888888".into()),
+                user: Some("U18888888".into()),
                 text: Some("eat a banana".to_string()),
                 recurring: Some(false),
                 time: Some(1602288000),
@@ -1,5 +1,6 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::TeamId;
 use crate::reminders::reminder::Reminder;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -7,7 +8,7 @@ use serde_with::skip_serializing_none;
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct ListRequest {
-    pub team_id: Option<String>,
+    pub team_id: Option<TeamId>,
 }
 
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
@@ -26,11 +27,10 @@ pub async fn list<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("reminders.list");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("reminders.list", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<ListResponse>(&result).map_err(Error::SerdeJsonError)
@@ -45,7 +45,7 @@ mod test {
     #[test]
     fn convert_request() {
         let request = ListRequest {
-            team_id: Some("T1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
         };
         let json = r##"{
   "team_id": "T1234567890"
@@ -65,8 +65,8 @@ mod test {
             reminders: Some(vec![
                 Reminder {
                     id: Some("Rm12345678".to_string()),
-                    creator: Some("U18888888".to_string()),
-                    user: Some("U18888888".to_string()),
+                    creator: Some("U18888888".into()),
+                    user: Some("U18888888".into()),
                     text: Some("eat a banana".to_string()),
                     recurring: Some(false),
                     time: Some(1602288000),
@@ -74,8 +74,8 @@ mod test {
                 },
                 Reminder {
                     id: Some("Rm12345678".to_string()),
-                    creator: Some("U18888888".to_string()),
-                    user: Some("U18888888".to_string()),
+                    creator: Some("U18888888".into()),
+                    user: Some("U18888888".into()),
                     text: Some("eat a banana".to_string()),
                     recurring: Some(false),
                     time: Some(1602288000),
@@ -120,10 +120,10 @@ mod test {
     #[async_std::test]
     async fn test_list() {
         let param = ListRequest {
-            team_id: Some("T1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
         };
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "error": null,
@@ -160,8 +160,8 @@ mod test {
             reminders: Some(vec![
                 Reminder {
                     id: Some("Rm12345678".to_string()),
-                    creator: Some("U18888888".to_string()),
-                    user: Some("U18888888".to_string()),
+                    creator: Some("U18888888".into()),
+                    user: Some("U18888888".into()),
                     text: Some("eat a banana".to_string()),
                     recurring: Some(false),
                     time: Some(1602288000),
@@ -169,8 +169,8 @@ mod test {
                 },
                 Reminder {
                     id: Some("Rm12345678".to_string()),
-                    creator: Some("U18888888".to_string()),
-                    user: Some("U18888888".to_string()),
+                    creator: Some("U18888888".into()),
+                    user: Some("U18888888".into()),
                     text: Some("eat a banana".to_string()),
                     recurring: Some(false),
                     time: Some(1602288000),
@@ -1,7 +1,12 @@
+#[cfg(feature = "web-api-reminders")]
 pub mod add;
+#[cfg(feature = "web-api-reminders")]
 pub mod complete;
+#[cfg(feature = "web-api-reminders")]
 pub mod delete;
+#[cfg(feature = "web-api-reminders")]
 pub mod info;
+#[cfg(feature = "web-api-reminders")]
 pub mod list;
 pub mod recurrence;
 pub mod reminder;
@@ -1,3 +1,4 @@
+use crate::id::UserId;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
@@ -5,10 +6,10 @@ use serde_with::skip_serializing_none;
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct Reminder {
     pub id: Option<String>,
-    pub creator: Option<String>,
-    pub user: Option<String>,
+    pub creator: Option<UserId>,
+    pub user: Option<UserId>,
     pub text: Option<String>,
     pub recurring: Option<bool>,
-    pub time: Option<i32>,
-    pub complete_ts: Option<i32>,
+    pub time: Option<i64>,
+    pub complete_ts: Option<i64>,
 }
@@ -0,0 +1,134 @@
+//! The async runtime Socket Mode, retries and the testing fakes run on.
+//!
+//! `tokio-runtime` takes precedence when both runtime features are enabled.
+
+#[cfg(not(any(feature = "async-std-runtime", feature = "tokio-runtime")))]
+compile_error!("either the `async-std-runtime` or the `tokio-runtime` feature must be enabled");
+
+#[cfg(all(feature = "async-std-runtime", not(feature = "tokio-runtime")))]
+mod imp {
+    #[cfg(feature = "socket-mode")]
+    use crate::error::Error;
+    #[cfg(any(test, feature = "socket-mode"))]
+    use std::io;
+    #[cfg(feature = "socket-mode")]
+    use std::path::Path;
+
+    #[cfg(any(test, feature = "socket-mode"))]
+    pub type TcpStream = async_std::net::TcpStream;
+
+    #[cfg(any(test, feature = "socket-mode"))]
+    pub async fn connect(host: &str, port: u16) -> io::Result<TcpStream> {
+        TcpStream::connect((host, port)).await
+    }
+
+    #[cfg(feature = "socket-mode")]
+    pub async fn read(path: impl AsRef<Path>) -> io::Result<Vec<u8>> {
+        async_std::fs::read(path.as_ref()).await
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    pub fn spawn<F>(future: F)
+    where
+        F: std::future::Future<Output = ()> + Send + 'static,
+    {
+        async_std::task::spawn(future);
+    }
+
+    pub async fn sleep(duration: std::time::Duration) {
+        async_std::task::sleep(duration).await
+    }
+
+    #[cfg(feature = "socket-mode")]
+    pub async fn timeout<F>(duration: std::time::Duration, future: F) -> Result<F::Output, Error>
+    where
+        F: std::future::Future,
+    {
+        async_std::future::timeout(duration, future)
+            .await
+            .map_err(|_| Error::TimeoutError)
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    pub struct TcpListener(async_std::net::TcpListener);
+
+    #[cfg(any(test, feature = "testing"))]
+    impl TcpListener {
+        pub async fn bind(addr: &str) -> io::Result<TcpListener> {
+            Ok(TcpListener(async_std::net::TcpListener::bind(addr).await?))
+        }
+        pub fn local_addr(&self) -> io::Result<std::net::SocketAddr> {
+            self.0.local_addr()
+        }
+        pub async fn accept(&self) -> io::Result<TcpStream> {
+            Ok(self.0.accept().await?.0)
+        }
+    }
+}
+
+#[cfg(feature = "tokio-runtime")]
+mod imp {
+    #[cfg(feature = "socket-mode")]
+    use crate::error::Error;
+    #[cfg(any(test, feature = "socket-mode"))]
+    use std::io;
+    #[cfg(feature = "socket-mode")]
+    use std::path::Path;
+    #[cfg(any(test, feature = "socket-mode"))]
+    use tokio_util::compat::{Compat, TokioAsyncReadCompatExt};
+
+    #[cfg(any(test, feature = "socket-mode"))]
+    pub type TcpStream = Compat<tokio::net::TcpStream>;
+
+    #[cfg(any(test, feature = "socket-mode"))]
+    pub async fn connect(host: &str, port: u16) -> io::Result<TcpStream> {
+        Ok(tokio::net::TcpStream::connect((host, port)).await?.compat())
+    }
+
+    #[cfg(feature = "socket-mode")]
+    pub async fn read(path: impl AsRef<Path>) -> io::Result<Vec<u8>> {
+        tokio::fs::read(path).await
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    pub fn spawn<F>(future: F)
+    where
+        F: std::future::Future<Output = ()> + Send + 'static,
+    {
+        tokio::spawn(future);
+    }
+
+    pub async fn sleep(duration: std::time::Duration) {
+        tokio::time::sleep(duration).await
+    }
+
+    #[cfg(feature = "socket-mode")]
+    pub async fn timeout<F>(duration: std::time::Duration, future: F) -> Result<F::Output, Error>
+    where
+        F: std::future::Future,
+    {
+        tokio::time::timeout(duration, future)
+            .await
+            .map_err(|_| Error::TimeoutError)
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    pub struct TcpListener(tokio::net::TcpListener);
+
+    #[cfg(any(test, feature = "testing"))]
+    impl TcpListener {
+        pub async fn bind(addr: &str) -> io::Result<TcpListener> {
+            Ok(TcpListener(tokio::net::TcpListener::bind(addr).await?))
+        }
+        pub fn local_addr(&self) -> io::Result<std::net::SocketAddr> {
+            self.0.local_addr()
+        }
+        pub async fn accept(&self) -> io::Result<TcpStream> {
+            Ok(self.0.accept().await?.0.compat())
+        }
+    }
+}
+
+// Without a runtime there is nothing to export, and the compile error above is the only one.
+#[cfg(any(feature = "async-std-runtime", feature = "tokio-runtime"))]
+pub use imp::*;
@@ -1,4 +1,5 @@
 use crate::event_api::event::Event;
+use crate::id::AppId;
 use crate::payloads::interactive::{InteractivePayload, SlashPayload};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -34,6 +35,15 @@ impl SocketModeEvent {
             }
         }
     }
+    /// The id to acknowledge the envelope with. `hello` and `disconnect` don't need acks.
+    pub fn envelope_id(&self) -> Option<&str> {
+        match self {
+            SocketModeEvent::HelloEvent(_) | SocketModeEvent::DisconnectEvent(_) => None,
+            SocketModeEvent::EventsAPI(e) => Some(&e.envelope_id),
+            SocketModeEvent::InteractiveEvent(e) => Some(&e.envelope_id),
+            SocketModeEvent::SlashCommandsEvent(e) => Some(&e.envelope_id),
+        }
+    }
 }
 
 #[derive(Deserialize, Serialize, Debug, PartialEq)]
@@ -44,6 +54,23 @@ pub enum SocketModeEventType {
     EventsAPI,
     Interactive,
     SlashCommands,
+    /// A value this crate doesn't know yet.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl SocketModeEventType {
+    /// The `type` Slack sends.
+    pub fn as_str(&self) -> &str {
+        match self {
+            SocketModeEventType::Hello => "hello",
+            SocketModeEventType::Disconnect => "disconnect",
+            SocketModeEventType::EventsAPI => "events_api",
+            SocketModeEventType::Interactive => "interactive",
+            SocketModeEventType::SlashCommands => "slash_commands",
+            SocketModeEventType::Unknown(t) => t,
+        }
+    }
 }
 
 #[skip_serializing_none]
@@ -66,7 +93,7 @@ pub struct HelloEvent {
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct ConnectionInfo {
-    pub app_id: Option<String>,
+    pub app_id: Option<AppId>,
 }
 
 #[skip_serializing_none]
@@ -82,6 +109,21 @@ pub enum DisconnectReason {
     LinkDisabled,
     Warning,
     RefreshRequested,
+    /// A value this crate doesn't know yet.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl DisconnectReason {
+    /// The `reason` Slack sends.
+    pub fn as_str(&self) -> &str {
+        match self {
+            DisconnectReason::LinkDisabled => "link_disabled",
+            DisconnectReason::Warning => "warning",
+            DisconnectReason::RefreshRequested => "refresh_requested",
+            DisconnectReason::Unknown(reason) => reason,
+        }
+    }
 }
 
 #[skip_serializing_none]
@@ -166,6 +208,28 @@ mod test {
         }
     }
 
+    #[test]
+    fn deserialize_unknown_disconnect_reason() {
+        let json = r##"{
+  "type": "disconnect",
+  "reason": "too_many_websockets"
+}"##;
+        let event = serde_json::from_str::<SocketModeEvent>(json).unwrap();
+        match event {
+            SocketModeEvent::DisconnectEvent(DisconnectEvent { reason, .. }) => {
+                assert_eq!(
+                    reason,
+                    DisconnectReason::Unknown("too_many_websockets".to_string())
+                );
+                assert_eq!(
+                    serde_json::to_string(&reason).unwrap(),
+                    r#""too_many_websockets""#
+                );
+            }
+            _ => panic!("Event deserialize into incorrect variant"),
+        }
+    }
+
     #[test]
     fn deserialize_events_api() {
         let json = r##"{
@@ -237,6 +301,28 @@ mod test {
         }
     }
 
+    #[test]
+    fn deserialize_unknown_interactive_event_type() {
+        let json = r##"{
+  "type": "interactive",
+  "envelope_id": "dbdd0ef3-1543-4f94-bfb4-133d0e6c1545",
+  "accepts_response_payload": false,
+  "payload": {
+    "type": "brand_new_interaction"
+  }
+}"##;
+        let event = serde_json::from_str::<SocketModeEvent>(json).unwrap();
+        match event {
+            SocketModeEvent::InteractiveEvent(InteractiveEvent { payload, .. }) => {
+                assert_eq!(
+                    payload.type_filed,
+                    InteractiveEventType::Unknown("brand_new_interaction".to_string())
+                );
+            }
+            _ => panic!("Event deserialize into incorrect variant"),
+        }
+    }
+
     #[test]
     fn deserialize_slash_commands_event() {
         let json = r##"{
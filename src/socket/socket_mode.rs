@@ -1,25 +1,65 @@
 use crate::apps::connections_open::connections_open;
+use crate::connection::{self, ConnectionConfig};
 use crate::error::Error;
 use crate::http_client::SlackWebAPIClient;
+use crate::instrument::{Envelope, PendingAcks};
+use crate::metrics::{Metrics, NoopMetrics};
+use crate::runtime::{self, TcpStream};
 use crate::socket::event::{
     AcknowledgeMessage, DisconnectEvent, EventsAPI, HelloEvent, InteractiveEvent,
     SlashCommandsEvent, SocketModeEvent,
 };
-use async_std::fs::read;
-use async_std::net::TcpStream;
 use async_tls::client::TlsStream;
 use async_tls::TlsConnector;
 use async_trait::async_trait;
-use async_tungstenite::tungstenite::Message;
+use async_tungstenite::stream::Stream as MaybeTlsStream;
+use async_tungstenite::tungstenite::client::IntoClientRequest;
+use async_tungstenite::tungstenite::http::header::{HeaderValue, USER_AGENT};
+use async_tungstenite::tungstenite::{self, Message};
 use async_tungstenite::{client_async, WebSocketStream};
-use futures_util::{SinkExt, StreamExt};
-use rustls::ClientConfig;
+use futures_util::{Sink, SinkExt, StreamExt};
+use rustls::{Certificate, ClientConfig, PrivateKey};
 use std::collections::HashMap;
 use std::io::Cursor;
+use std::pin::Pin;
 use std::sync::Arc;
+use std::task::{Context, Poll};
 use url::Url;
 
-pub type Stream = WebSocketStream<TlsStream<TcpStream>>;
+/// A Socket Mode connection. [`EventHandler`] methods get it to [`ack`] envelopes on.
+pub struct Stream {
+    ws: WebSocketStream<MaybeTlsStream<TcpStream, TlsStream<TcpStream>>>,
+    /// Envelopes of this connection that are being handled and haven't been acknowledged yet.
+    pending: PendingAcks,
+}
+
+impl futures_util::Stream for Stream {
+    type Item = Result<Message, tungstenite::Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.ws).poll_next(cx)
+    }
+}
+
+impl Sink<Message> for Stream {
+    type Error = tungstenite::Error;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut self.ws).poll_ready(cx)
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: Message) -> Result<(), Self::Error> {
+        Pin::new(&mut self.ws).start_send(item)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut self.ws).poll_flush(cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut self.ws).poll_close(cx)
+    }
+}
 
 /// Implement this trait in your code to handle slack events.
 #[allow(unused_variables)]
@@ -34,6 +74,9 @@ where
     async fn on_connect(&mut self, socket_mode: &SocketMode<S>) {
         log::info!("websocket connect");
     }
+    /// Called with the text of every envelope before it is parsed and handed to one of the
+    /// handlers below.
+    async fn on_envelope(&mut self, socket_mode: &SocketMode<S>, text: &str) {}
     async fn on_hello(&mut self, socket_mode: &SocketMode<S>, e: HelloEvent, s: &mut Stream) {
         log::info!("hello event: {:?}", e);
     }
@@ -46,7 +89,7 @@ where
         log::info!("disconnect event: {:?}", e);
     }
     async fn on_events_api(&mut self, socket_mode: &SocketMode<S>, e: EventsAPI, s: &mut Stream) {
-        log::info!("events api event: {:?}", e);
+        log::trace!("events api event: {:?}", e);
     }
     async fn on_interactive(
         &mut self,
@@ -54,7 +97,7 @@ where
         e: InteractiveEvent,
         s: &mut Stream,
     ) {
-        log::info!("interactive event: {:?}", e);
+        log::trace!("interactive event: {:?}", e);
     }
     async fn on_slash_commands(
         &mut self,
@@ -62,7 +105,7 @@ where
         e: SlashCommandsEvent,
         s: &mut Stream,
     ) {
-        log::info!("slash commands event: {:?}", e);
+        log::trace!("slash commands event: {:?}", e);
     }
 }
 
@@ -77,6 +120,8 @@ where
     pub option_parameter: HashMap<String, String>,
     pub web_socket_port: u16,
     pub ca_file_path: Option<String>,
+    pub connection: ConnectionConfig,
+    pub metrics: Arc<dyn Metrics>,
 }
 
 impl<S> SocketMode<S>
@@ -91,6 +136,8 @@ where
             option_parameter: HashMap::new(),
             web_socket_port: 443,
             ca_file_path: None,
+            connection: ConnectionConfig::default(),
+            metrics: Arc::new(NoopMetrics),
         }
     }
     pub fn option_parameter(mut self, key: String, value: String) -> Self {
@@ -101,10 +148,26 @@ where
         self.web_socket_port = port;
         self
     }
+    /// Trusts the certificates in `ca_file_path` instead of the built-in roots, unless `connection`
+    /// sets root or client certificates.
     pub fn ca_file_path(mut self, ca_file_path: String) -> Self {
         self.ca_file_path = Some(ca_file_path);
         self
     }
+    /// Connects to the WebSocket with the proxy, certificates, connect timeout and user agent of
+    /// `connection`. The Web API calls go through `api_client`, configured separately.
+    pub fn connection(mut self, connection: ConnectionConfig) -> Self {
+        self.connection = connection;
+        self
+    }
+    /// Reports connections and envelope handling to `metrics`.
+    pub fn metrics<M>(mut self, metrics: M) -> Self
+    where
+        M: Metrics + 'static,
+    {
+        self.metrics = Arc::new(metrics);
+        self
+    }
     /// Run slack and websocket communication.
     pub async fn run<T>(self, handler: &mut T) -> Result<(), Error>
     where
@@ -112,18 +175,13 @@ where
     {
         let response = connections_open(&self.api_client, &self.app_token).await?;
         let ws_url = response.url.ok_or(Error::SocketModeOpenConnectionError)?;
-        let ws_url_parsed = Url::parse(&ws_url)?;
-        let ws_domain = ws_url_parsed.domain().ok_or(Error::NotFoundDomain)?;
-
-        let tcp_stream = TcpStream::connect((ws_domain, self.web_socket_port)).await?;
-        let connector = if let Some(ca_file_path) = &self.ca_file_path {
-            connector_for_ca_file(ca_file_path).await?
-        } else {
-            TlsConnector::default()
+        // Boxed, as the TLS handshake would otherwise bloat the future of the whole session.
+        let connect = Box::pin(self.connect(&ws_url));
+        let mut ws = match self.connection.connect_timeout {
+            Some(connect_timeout) => runtime::timeout(connect_timeout, connect).await??,
+            None => connect.await?,
         };
-        let tls_stream = connector.connect(ws_domain, tcp_stream).await?;
-
-        let (mut ws, _) = client_async(&ws_url, tls_stream).await?;
+        self.metrics.socket_mode_connected();
 
         handler.on_connect(&self).await;
 
@@ -132,23 +190,48 @@ where
 
             match message? {
                 Message::Text(t) => {
-                    log::info!("{}", t);
-                    let event = serde_json::from_str::<SocketModeEvent>(&t)?;
-                    match event {
-                        SocketModeEvent::HelloEvent(e) => handler.on_hello(&self, e, &mut ws).await,
-                        SocketModeEvent::DisconnectEvent(e) => {
-                            handler.on_disconnect(&self, e, &mut ws).await
-                        }
-                        SocketModeEvent::EventsAPI(e) => {
-                            handler.on_events_api(&self, e, &mut ws).await
-                        }
-                        SocketModeEvent::InteractiveEvent(e) => {
-                            handler.on_interactive(&self, e, &mut ws).await
-                        }
-                        SocketModeEvent::SlashCommandsEvent(e) => {
-                            handler.on_slash_commands(&self, e, &mut ws).await
+                    handler.on_envelope(&self, &t).await;
+                    let event = match serde_json::from_str::<SocketModeEvent>(&t) {
+                        Ok(event) => event,
+                        Err(e) => {
+                            log::warn!("skipping unparseable envelope: {}", e);
+                            // Ack it anyway, or Slack keeps redelivering it.
+                            let envelope_id = serde_json::from_str::<serde_json::Value>(&t)
+                                .ok()
+                                .and_then(|v| v.get("envelope_id")?.as_str().map(str::to_string));
+                            if let Some(envelope_id) = envelope_id {
+                                if let Err(e) = ack(&envelope_id, &mut ws).await {
+                                    log::warn!("failed to ack envelope {}: {}", envelope_id, e);
+                                }
+                            }
+                            continue;
                         }
+                    };
+                    if let SocketModeEvent::DisconnectEvent(e) = &event {
+                        self.metrics.socket_mode_disconnect(e.reason.as_str());
                     }
+                    let envelope = Envelope::received(&event, &t, &self.metrics, &mut ws.pending);
+                    let handled = async {
+                        match event {
+                            SocketModeEvent::HelloEvent(e) => {
+                                handler.on_hello(&self, e, &mut ws).await
+                            }
+                            SocketModeEvent::DisconnectEvent(e) => {
+                                handler.on_disconnect(&self, e, &mut ws).await
+                            }
+                            SocketModeEvent::EventsAPI(e) => {
+                                handler.on_events_api(&self, e, &mut ws).await
+                            }
+                            SocketModeEvent::InteractiveEvent(e) => {
+                                handler.on_interactive(&self, e, &mut ws).await
+                            }
+                            SocketModeEvent::SlashCommandsEvent(e) => {
+                                handler.on_slash_commands(&self, e, &mut ws).await
+                            }
+                        }
+                    };
+                    envelope.handle(handled).await;
+                    envelope.handled(&mut ws.pending);
                 }
                 Message::Ping(p) => log::info!("ping: {:?}", p),
                 Message::Close(_) => {
@@ -160,19 +243,72 @@ where
         }
         Ok(())
     }
+
+    /// Opens the WebSocket at `ws_url`, tunneling through the proxy if there is one.
+    async fn connect(&self, ws_url: &str) -> Result<Stream, Error> {
+        let ws_url_parsed = Url::parse(ws_url)?;
+        let ws_host = ws_url_parsed.host_str().ok_or(Error::NotFoundDomain)?;
+        let ws_port = ws_url_parsed.port().unwrap_or(self.web_socket_port);
+
+        let tcp_stream = match self.connection.proxy_for(ws_host) {
+            Some(proxy) => {
+                let (proxy_host, proxy_port) = connection::proxy_address(proxy)?;
+                let mut tcp_stream = runtime::connect(&proxy_host, proxy_port).await?;
+                connection::tunnel(&mut tcp_stream, ws_host, ws_port, proxy).await?;
+                tcp_stream
+            }
+            None => runtime::connect(ws_host, ws_port).await?,
+        };
+        // Slack always hands out wss URLs; plain ws is for local fake servers.
+        let stream = if ws_url_parsed.scheme() == "ws" {
+            MaybeTlsStream::Plain(tcp_stream)
+        } else {
+            let ws_domain = ws_url_parsed.domain().ok_or(Error::NotFoundDomain)?;
+            let connector = self.tls_connector().await?;
+            MaybeTlsStream::Tls(connector.connect(ws_domain, tcp_stream).await?)
+        };
+
+        let mut request = ws_url.into_client_request()?;
+        if let Some(user_agent) = &self.connection.user_agent {
+            let user_agent =
+                HeaderValue::from_str(user_agent).map_err(|_| Error::InvalidInputError)?;
+            request.headers_mut().insert(USER_AGENT, user_agent);
+        }
+        let (ws, _) = client_async(request, stream).await?;
+        Ok(Stream {
+            ws,
+            pending: PendingAcks::default(),
+        })
+    }
+
+    async fn tls_connector(&self) -> Result<TlsConnector, Error> {
+        if self.connection.root_certificates.is_some()
+            || self.connection.client_certificate.is_some()
+        {
+            return connector_for_connection(&self.connection);
+        }
+        match &self.ca_file_path {
+            Some(ca_file_path) => connector_for_ca_file(ca_file_path).await,
+            None => Ok(TlsConnector::default()),
+        }
+    }
 }
 
+/// Acknowledges an envelope. The ack latency is only reported for acks sent while the handler of
+/// the envelope runs, not for later ones, e.g. from a task the handler spawned.
 pub async fn ack(envelope_id: &str, stream: &mut Stream) -> Result<(), Error> {
     let json = serde_json::to_string(&AcknowledgeMessage { envelope_id })?;
     stream
         .send(Message::Text(json))
         .await
-        .map_err(Error::WebSocketError)
+        .map_err(Error::WebSocketError)?;
+    stream.pending.acknowledged(envelope_id);
+    Ok(())
 }
 
 pub async fn connector_for_ca_file(ca_file_path: &str) -> Result<TlsConnector, Error> {
     let mut config = ClientConfig::new();
-    let file = read(ca_file_path).await?;
+    let file = runtime::read(ca_file_path).await?;
     let mut pem = Cursor::new(file);
     config
         .root_store
@@ -181,6 +317,41 @@ pub async fn connector_for_ca_file(ca_file_path: &str) -> Result<TlsConnector, E
     Ok(TlsConnector::from(Arc::new(config)))
 }
 
+/// Trusts the root certificates of `connection`, or the built-in ones if it has none, and presents
+/// its client certificate.
+pub fn connector_for_connection(connection: &ConnectionConfig) -> Result<TlsConnector, Error> {
+    let mut config = ClientConfig::new();
+    match &connection.root_certificates {
+        Some(roots) => {
+            for certificate in roots.certificates() {
+                config
+                    .root_store
+                    .add(&Certificate(certificate))
+                    .map_err(|_| Error::InvalidInputError)?;
+            }
+        }
+        None => config
+            .root_store
+            .add_server_trust_anchors(&webpki_roots_021::TLS_SERVER_ROOTS),
+    }
+    if let Some(client_certificate) = &connection.client_certificate {
+        let chain = client_certificate
+            .certificate
+            .certificates()
+            .into_iter()
+            .map(Certificate)
+            .collect();
+        let private_key = client_certificate
+            .private_key
+            .private_key()
+            .ok_or(Error::InvalidInputError)?;
+        config
+            .set_single_client_cert(chain, PrivateKey(private_key))
+            .map_err(|_| Error::InvalidInputError)?;
+    }
+    Ok(TlsConnector::from(Arc::new(config)))
+}
+
 #[cfg(test)]
 mod test {
     use crate::event_api::event::*;
@@ -270,7 +441,8 @@ mod test {
         }
     }
 
-    #[async_std::test]
+    #[cfg_attr(feature = "tokio-runtime", tokio::test)]
+    #[cfg_attr(not(feature = "tokio-runtime"), async_std::test)]
     async fn test_socket_mode() {
         env_logger::init();
 
@@ -339,7 +511,7 @@ mod test {
         ];
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post().times(1).returning(|_, _| {
+        mock.expect_call().times(1).returning(|_, _| {
             Ok(r##"{
                   "ok": true,
                   "url": "wss://localhost"
@@ -364,6 +536,69 @@ mod test {
         .unwrap_or_else(|_| panic!("socket mode run error."));
     }
 
+    #[cfg_attr(feature = "tokio-runtime", tokio::test)]
+    #[cfg_attr(not(feature = "tokio-runtime"), async_std::test)]
+    async fn run_through_proxy() {
+        use crate::connection::ConnectionConfig;
+        use crate::testing::fake_slack::FakeSlack;
+        use crate::testing::fake_socket_mode::FakeSocketMode;
+        use crate::testing::http_proxy::HttpProxy;
+        use futures_util::future::join;
+        use std::time::Duration;
+
+        let slack = FakeSlack::new();
+        let server = FakeSocketMode::start().await.unwrap();
+        slack.serve_socket_mode(&server);
+        let proxy = HttpProxy::start().await.unwrap();
+
+        let connection = ConnectionConfig::new()
+            .proxy(&proxy.url().replace("http://", "http://user:secret@"))
+            .unwrap()
+            .connect_timeout(Duration::from_secs(1))
+            .user_agent("slack-rust-test/1.0");
+        let socket_mode = SocketMode::new(
+            slack.clone(),
+            "xapp-token".to_string(),
+            "xoxb-token".to_string(),
+        )
+        .connection(connection);
+        let client = async move { socket_mode.run(&mut Handler).await.unwrap() };
+        join(client, async { server.close().await.unwrap() }).await;
+
+        let requests = proxy.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "CONNECT");
+        let server_url = url::Url::parse(server.url()).unwrap();
+        assert_eq!(
+            requests[0].target,
+            format!(
+                "{}:{}",
+                server_url.host_str().unwrap(),
+                server_url.port().unwrap()
+            )
+        );
+        assert_eq!(
+            requests[0].headers["proxy-authorization"],
+            "Basic dXNlcjpzZWNyZXQ="
+        );
+    }
+
+    #[test]
+    fn connect_with_certificates() {
+        use crate::connection::test::certificate_files;
+        use crate::connection::ConnectionConfig;
+        use crate::socket::socket_mode::connector_for_connection;
+
+        let (certificate, private_key) = certificate_files();
+        let connection = ConnectionConfig::new()
+            .root_ca_file(&certificate)
+            .unwrap()
+            .client_certificate(&certificate, &private_key)
+            .unwrap();
+        assert!(connector_for_connection(&connection).is_ok());
+        assert!(connector_for_connection(&ConnectionConfig::new()).is_ok());
+    }
+
     async fn mock_web_socket(event: Vec<String>) -> Result<u16, Box<dyn Error>> {
         let listener = TcpListener::bind("localhost:0").await?;
         let port = listener.local_addr()?.port();
@@ -1,5 +1,6 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::TeamId;
 use crate::team::log::{Login, Paging};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -10,7 +11,7 @@ pub struct AccessLogsRequest {
     pub before: Option<String>,
     pub count: Option<String>,
     pub page: Option<String>,
-    pub team_id: Option<String>,
+    pub team_id: Option<TeamId>,
 }
 
 #[skip_serializing_none]
@@ -31,11 +32,10 @@ pub async fn access_logs<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("team.accessLogs");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("team.accessLogs", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<AccessLogsResponse>(&result).map_err(Error::SerdeJsonError)
@@ -53,7 +53,7 @@ mod test {
             before: Some("1457989166".to_string()),
             count: Some("20".to_string()),
             page: Some("2".to_string()),
-            team_id: Some("T1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
         };
         let json = r##"{
   "before": "1457989166",
@@ -74,7 +74,7 @@ mod test {
         let response = AccessLogsResponse {
             ok: true,
             logins: Some(vec![Login {
-                user_id: Some("U45678".to_string()),
+                user_id: Some("U45678".into()),
                 username: Some("alice".to_string()),
                 date_first: Some(1422922864),
                 date_last: Some(1422922864),
@@ -130,10 +130,10 @@ mod test {
             before: Some("1457989166".to_string()),
             count: Some("20".to_string()),
             page: Some("2".to_string()),
-            team_id: Some("T1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
         };
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "logins": [
@@ -166,7 +166,7 @@ mod test {
         let expect = AccessLogsResponse {
             ok: true,
             logins: Some(vec![Login {
-                user_id: Some("U45678".to_string()),
+                user_id: Some("U45678".into()),
                 username: Some("alice".to_string()),
                 date_first: Some(1422922864),
                 date_last: Some(1422922864),
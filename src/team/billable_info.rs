@@ -1,5 +1,6 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::{TeamId, UserId};
 use crate::team::billing::BillableInfo;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -8,8 +9,8 @@ use std::collections::HashMap;
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct BillableInfoRequest {
-    pub team_id: Option<String>,
-    pub user: Option<String>,
+    pub team_id: Option<TeamId>,
+    pub user: Option<UserId>,
 }
 
 #[skip_serializing_none]
@@ -29,11 +30,10 @@ pub async fn billable_info<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("team.billableInfo");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("team.billableInfo", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<BillableInfoResponse>(&result).map_err(Error::SerdeJsonError)
@@ -48,8 +48,8 @@ mod test {
     #[test]
     fn convert_request() {
         let request = BillableInfoRequest {
-            team_id: Some("T1234567890".to_string()),
-            user: Some("W1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
+            user: Some("W1234567890".into()),
         };
         let json = r##"{
   "team_id": "T1234567890",
@@ -97,11 +97,11 @@ mod test {
     #[async_std::test]
     async fn test_billable_info() {
         let param = BillableInfoRequest {
-            team_id: Some("T1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
             ..Default::default()
         };
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "billable_info": {
@@ -1,5 +1,5 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
@@ -16,11 +16,12 @@ pub async fn billing_info<T>(client: &T, bot_token: &str) -> Result<BillingInfoR
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("team.billing.info");
-
-    client.post(&url, bot_token).await.and_then(|result| {
-        serde_json::from_str::<BillingInfoResponse>(&result).map_err(Error::SerdeJsonError)
-    })
+    client
+        .call("team.billing.info", bot_token)
+        .await
+        .and_then(|result| {
+            serde_json::from_str::<BillingInfoResponse>(&result).map_err(Error::SerdeJsonError)
+        })
 }
 
 #[cfg(test)]
@@ -50,7 +51,7 @@ mod test {
     #[async_std::test]
     async fn test_info() {
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post().returning(|_, _| {
+        mock.expect_call().returning(|_, _| {
             Ok(r##"{
   "ok": true,
   "plan": "free"
@@ -1,5 +1,6 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::TeamId;
 use crate::team::teams::Team;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -7,7 +8,7 @@ use serde_with::skip_serializing_none;
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct InfoRequest {
-    pub team: Option<String>,
+    pub team: Option<TeamId>,
 }
 
 #[skip_serializing_none]
@@ -27,11 +28,10 @@ pub async fn info<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("team.info");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("team.info", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<InfoResponse>(&result).map_err(Error::SerdeJsonError)
@@ -47,7 +47,7 @@ mod test {
     #[test]
     fn convert_request() {
         let request = InfoRequest {
-            team: Some("T1234567890".to_string()),
+            team: Some("T1234567890".into()),
         };
         let json = r##"{
   "team": "T1234567890"
@@ -65,7 +65,7 @@ mod test {
         let response = InfoResponse {
             ok: true,
             team: Some(Team {
-                id: Some("T12345".to_string()),
+                id: Some("T12345".into()),
                 name: Some("My Team".to_string()),
                 domain: Some("example".to_string()),
                 email_domain: Some("example.com".to_string()),
@@ -78,8 +78,9 @@ mod test {
                     image_132: Some("https://...".to_string()),
                     image_default: Some(true),
                 }),
-                enterprise_id: Some("E1234A12AB".to_string()),
+                enterprise_id: Some("E1234A12AB".into()),
                 enterprise_name: Some("Umbrella Corporation".to_string()),
+                ..Default::default()
             }),
             ..Default::default()
         };
@@ -114,10 +115,10 @@ mod test {
     #[async_std::test]
     async fn test_info() {
         let param = InfoRequest {
-            team: Some("T12345".to_string()),
+            team: Some("T12345".into()),
         };
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "team": {
@@ -148,7 +149,7 @@ mod test {
         let expect = InfoResponse {
             ok: true,
             team: Some(Team {
-                id: Some("T12345".to_string()),
+                id: Some("T12345".into()),
                 name: Some("My Team".to_string()),
                 domain: Some("example".to_string()),
                 email_domain: Some("example.com".to_string()),
@@ -161,8 +162,9 @@ mod test {
                     image_132: Some("https://...".to_string()),
                     image_default: Some(true),
                 }),
-                enterprise_id: Some("E1234A12AB".to_string()),
+                enterprise_id: Some("E1234A12AB".into()),
                 enterprise_name: Some("Umbrella Corporation".to_string()),
+                ..Default::default()
             }),
             ..Default::default()
         };
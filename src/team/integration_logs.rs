@@ -1,5 +1,6 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::{AppId, TeamId, UserId};
 use crate::team::log::{Log, Paging};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -7,13 +8,13 @@ use serde_with::skip_serializing_none;
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct IntegrationLogsRequest {
-    pub app_id: Option<String>,
+    pub app_id: Option<AppId>,
     pub change_type: Option<String>,
     pub count: Option<String>,
     pub page: Option<String>,
     pub service_id: Option<String>,
-    pub team_id: Option<String>,
-    pub user: Option<String>,
+    pub team_id: Option<TeamId>,
+    pub user: Option<UserId>,
 }
 
 #[skip_serializing_none]
@@ -34,11 +35,10 @@ pub async fn integration_logs<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("team.integrationLogs");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("team.integrationLogs", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<IntegrationLogsResponse>(&result).map_err(Error::SerdeJsonError)
@@ -53,13 +53,13 @@ mod test {
     #[test]
     fn convert_request() {
         let request = IntegrationLogsRequest {
-            app_id: Some("xxxxxxxxxxx".to_string()),
+            app_id: Some("xxxxxxxxxxx".into()),
             change_type: Some("added".to_string()),
             count: Some("20".to_string()),
             page: Some("2".to_string()),
             service_id: Some("xxxxxxxxxxx".to_string()),
-            team_id: Some("T1234567890".to_string()),
-            user: Some("W1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
+            user: Some("W1234567890".into()),
         };
         let json = r##"{
   "app_id": "xxxxxxxxxxx",
@@ -86,20 +86,20 @@ mod test {
                 Log {
                     service_id: Some(1234567890),
                     service_type: Some("Google Calendar".to_string()),
-                    user_id: Some("U1234ABCD".to_string()),
+                    user_id: Some("U1234ABCD".into()),
                     user_name: Some("Johnny".to_string()),
-                    channel: Some("C1234567890".to_string()),
+                    channel: Some("C1234567890".into()),
                     date: Some("1392163200".to_string()),
                     change_type: Some("enabled".to_string()),
                     scope: Some("incoming-webhook".to_string()),
                     ..Default::default()
                 },
                 Log {
-                    app_id: Some("2345678901".to_string()),
+                    app_id: Some("2345678901".into()),
                     app_type: Some("Johnny App".to_string()),
-                    user_id: Some("U1234ABCD".to_string()),
+                    user_id: Some("U1234ABCD".into()),
                     user_name: Some("Johnny".to_string()),
-                    channel: Some("C1234567890".to_string()),
+                    channel: Some("C1234567890".into()),
                     date: Some("1392163200".to_string()),
                     change_type: Some("enabled".to_string()),
                     scope: Some("incoming-webhook".to_string()),
@@ -156,16 +156,16 @@ mod test {
     #[async_std::test]
     async fn test_integration_logs() {
         let param = IntegrationLogsRequest {
-            app_id: Some("xxxxxxxxxxx".to_string()),
+            app_id: Some("xxxxxxxxxxx".into()),
             change_type: Some("added".to_string()),
             count: Some("20".to_string()),
             page: Some("2".to_string()),
             service_id: Some("xxxxxxxxxxx".to_string()),
-            team_id: Some("T1234567890".to_string()),
-            user: Some("W1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
+            user: Some("W1234567890".into()),
         };
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "logs": [
@@ -210,20 +210,20 @@ mod test {
                 Log {
                     service_id: Some(1234567890),
                     service_type: Some("Google Calendar".to_string()),
-                    user_id: Some("U1234ABCD".to_string()),
+                    user_id: Some("U1234ABCD".into()),
                     user_name: Some("Johnny".to_string()),
-                    channel: Some("C1234567890".to_string()),
+                    channel: Some("C1234567890".into()),
                     date: Some("1392163200".to_string()),
                     change_type: Some("enabled".to_string()),
                     scope: Some("incoming-webhook".to_string()),
                     ..Default::default()
                 },
                 Log {
-                    app_id: Some("2345678901".to_string()),
+                    app_id: Some("2345678901".into()),
                     app_type: Some("Johnny App".to_string()),
-                    user_id: Some("U1234ABCD".to_string()),
+                    user_id: Some("U1234ABCD".into()),
                     user_name: Some("Johnny".to_string()),
-                    channel: Some("C1234567890".to_string()),
+                    channel: Some("C1234567890".into()),
                     date: Some("1392163200".to_string()),
                     change_type: Some("enabled".to_string()),
                     scope: Some("incoming-webhook".to_string()),
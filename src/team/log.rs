@@ -1,13 +1,14 @@
+use crate::id::{AppId, ChannelId, UserId};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct Login {
-    pub user_id: Option<String>,
+    pub user_id: Option<UserId>,
     pub username: Option<String>,
-    pub date_first: Option<i32>,
-    pub date_last: Option<i32>,
+    pub date_first: Option<i64>,
+    pub date_last: Option<i64>,
     pub count: Option<i32>,
     pub ip: Option<String>,
     pub user_agent: Option<String>,
@@ -28,13 +29,13 @@ pub struct Paging {
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct Log {
-    pub app_id: Option<String>,
+    pub app_id: Option<AppId>,
     pub app_type: Option<String>,
     pub service_id: Option<i32>,
     pub service_type: Option<String>,
-    pub user_id: Option<String>,
+    pub user_id: Option<UserId>,
     pub user_name: Option<String>,
-    pub channel: Option<String>,
+    pub channel: Option<ChannelId>,
     pub date: Option<String>,
     pub change_type: Option<String>,
     pub scope: Option<String>,
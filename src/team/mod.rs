@@ -1,10 +1,17 @@
+#[cfg(feature = "web-api-team")]
 pub mod access_logs;
+#[cfg(feature = "web-api-team")]
 pub mod billable_info;
 pub mod billing;
+#[cfg(feature = "web-api-team")]
 pub mod billing_info;
+#[cfg(feature = "web-api-team")]
 pub mod info;
+#[cfg(feature = "web-api-team")]
 pub mod integration_logs;
 pub mod log;
+#[cfg(feature = "web-api-team")]
 pub mod preferences_list;
+#[cfg(feature = "web-api-team")]
 pub mod profile_get;
 pub mod teams;
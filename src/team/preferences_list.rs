@@ -1,5 +1,5 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
@@ -23,11 +23,12 @@ pub async fn preferences_list<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("team.preferences.list");
-
-    client.post(&url, bot_token).await.and_then(|result| {
-        serde_json::from_str::<PreferencesListResponse>(&result).map_err(Error::SerdeJsonError)
-    })
+    client
+        .call("team.preferences.list", bot_token)
+        .await
+        .and_then(|result| {
+            serde_json::from_str::<PreferencesListResponse>(&result).map_err(Error::SerdeJsonError)
+        })
 }
 
 #[cfg(test)]
@@ -65,7 +66,7 @@ mod test {
     #[async_std::test]
     async fn test_preferences_list() {
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post().returning(|_, _| {
+        mock.expect_call().returning(|_, _| {
             Ok(r##"{
   "ok": true,
   "allow_message_deletion": true,
@@ -1,5 +1,5 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
 use crate::profiles::profile::Profile;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -27,11 +27,10 @@ pub async fn profile_get<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("team.profile.get");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("team.profile.get", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<ProfileGetResponse>(&result).map_err(Error::SerdeJsonError)
@@ -108,7 +107,7 @@ mod test {
             visibility: Some("all".to_string()),
         };
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "profile": {
@@ -1,16 +1,20 @@
+use crate::id::{EnterpriseId, TeamId};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use std::collections::HashMap;
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct Team {
-    pub id: Option<String>,
+    pub id: Option<TeamId>,
     pub name: Option<String>,
     pub domain: Option<String>,
     pub email_domain: Option<String>,
     pub icon: Option<Icon>,
-    pub enterprise_id: Option<String>,
+    pub enterprise_id: Option<EnterpriseId>,
     pub enterprise_name: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 // TODO
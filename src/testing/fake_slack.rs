@@ -0,0 +1,186 @@
+//! A fake Slack WEB API.
+
+use crate::error::Error;
+use crate::http_client::SlackWebAPIClient;
+use crate::testing::fake_socket_mode::FakeSocketMode;
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// A call [`FakeSlack`] received.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedCall {
+    pub method: String,
+    /// The parsed JSON body, or `None` for calls without one.
+    pub body: Option<Value>,
+    pub token: String,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    calls: Vec<RecordedCall>,
+    once: HashMap<String, VecDeque<Value>>,
+    always: HashMap<String, Value>,
+}
+
+/// A [`SlackWebAPIClient`] that records every call and answers with scripted responses.
+///
+/// Methods without a scripted response answer `{"ok": true}`. Clones share their calls and
+/// responses, so a test can keep one while the code under test owns another.
+///
+/// ```
+/// use serde_json::json;
+/// use slack_rust::chat::post_message::{post_message, PostMessageRequest};
+/// use slack_rust::testing::fake_slack::FakeSlack;
+///
+/// # async_std::task::block_on(async {
+/// let slack = FakeSlack::new();
+/// slack.respond(
+///     "chat.postMessage",
+///     json!({"ok": true, "channel": "C1234567890", "ts": "1503435956.000247"}),
+/// );
+///
+/// let request = PostMessageRequest::builder("C1234567890".into())
+///     .text("Hello".to_string())
+///     .build();
+/// let response = post_message(&slack, &request, "xoxb-token").await.unwrap();
+/// assert_eq!(response.ts.unwrap(), "1503435956.000247");
+///
+/// let call = slack.last_call("chat.postMessage").unwrap();
+/// assert_eq!(call.body.unwrap()["text"], "Hello");
+/// # });
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FakeSlack {
+    state: Arc<Mutex<State>>,
+}
+
+impl FakeSlack {
+    pub fn new() -> FakeSlack {
+        FakeSlack::default()
+    }
+    /// Answers every call to `method` with `response`.
+    pub fn respond(&self, method: &str, response: Value) {
+        self.state().always.insert(method.to_string(), response);
+    }
+    /// Answers the next call to `method` with `response`, before any [`FakeSlack::respond`]
+    /// response. Responses queue up in the order they were given.
+    pub fn respond_once(&self, method: &str, response: Value) {
+        self.state()
+            .once
+            .entry(method.to_string())
+            .or_default()
+            .push_back(response);
+    }
+    /// Answers `apps.connections.open` with the url of `server`.
+    pub fn serve_socket_mode(&self, server: &FakeSocketMode) {
+        self.respond(
+            "apps.connections.open",
+            json!({"ok": true, "url": server.url()}),
+        );
+    }
+    /// All calls received so far, oldest first.
+    pub fn calls(&self) -> Vec<RecordedCall> {
+        self.state().calls.clone()
+    }
+    /// The calls to `method` received so far, oldest first.
+    pub fn calls_to(&self, method: &str) -> Vec<RecordedCall> {
+        self.state()
+            .calls
+            .iter()
+            .filter(|call| call.method == method)
+            .cloned()
+            .collect()
+    }
+    pub fn last_call(&self, method: &str) -> Option<RecordedCall> {
+        self.calls_to(method).pop()
+    }
+    /// Forgets recorded calls and scripted responses.
+    pub fn reset(&self) {
+        *self.state() = State::default();
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        // A test that panicked while holding the lock shouldn't hide the calls from others.
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+    fn call(&self, method: &str, body: Option<Value>, token: &str) -> String {
+        let mut state = self.state();
+        state.calls.push(RecordedCall {
+            method: method.to_string(),
+            body,
+            token: token.to_string(),
+        });
+
+        let response = match state.once.get_mut(method).and_then(VecDeque::pop_front) {
+            Some(response) => response,
+            None => state
+                .always
+                .get(method)
+                .cloned()
+                .unwrap_or_else(|| json!({"ok": true})),
+        };
+        response.to_string()
+    }
+}
+
+#[async_trait]
+impl SlackWebAPIClient for FakeSlack {
+    async fn call_json(&self, method: &str, body: &str, token: &str) -> Result<String, Error> {
+        let body = serde_json::from_str(body)?;
+        Ok(self.call(method, Some(body), token))
+    }
+    async fn call(&self, method: &str, token: &str) -> Result<String, Error> {
+        Ok(self.call(method, None, token))
+    }
+}
+
+#[cfg(all(test, feature = "web-api-chat"))]
+mod test {
+    use super::*;
+    use crate::apps::connections_open::connections_open;
+    use crate::chat::delete::{delete, DeleteRequest};
+
+    #[async_std::test]
+    async fn script_responses() {
+        let slack = FakeSlack::new();
+        slack.respond(
+            "chat.delete",
+            json!({"ok": false, "error": "message_not_found"}),
+        );
+        slack.respond_once("chat.delete", json!({"ok": true, "channel": "C1234567890"}));
+
+        let request = DeleteRequest {
+            channel: "C1234567890".into(),
+            ts: "1401383885.000061".into(),
+            ..Default::default()
+        };
+        let first = delete(&slack, &request, "xoxb-token").await.unwrap();
+        assert!(first.ok);
+        let second = delete(&slack, &request, "xoxb-token").await.unwrap();
+        assert_eq!(second.error.unwrap(), "message_not_found");
+
+        let response = connections_open(&slack, "xapp-token").await.unwrap();
+        assert!(response.ok);
+
+        let calls = slack.calls();
+        assert_eq!(calls.len(), 3);
+        assert_eq!(
+            calls[0].body,
+            Some(json!({"channel": "C1234567890", "ts": "1401383885.000061"}))
+        );
+        assert_eq!(
+            calls[2],
+            RecordedCall {
+                method: "apps.connections.open".to_string(),
+                body: None,
+                token: "xapp-token".to_string(),
+            }
+        );
+        assert_eq!(slack.calls_to("chat.delete").len(), 2);
+
+        slack.reset();
+        assert!(slack.calls().is_empty());
+    }
+}
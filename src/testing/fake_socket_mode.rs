@@ -0,0 +1,302 @@
+//! A fake Socket Mode WebSocket server.
+
+use crate::error::Error;
+use crate::runtime::{self, TcpListener};
+use crate::socket::event::SocketModeEvent;
+use crate::testing::cassette::Cassette;
+use async_channel::{unbounded, Receiver, Sender};
+use async_tungstenite::tungstenite::Message;
+use futures_util::{SinkExt, StreamExt};
+use serde_json::Value;
+
+/// A plain `ws://` server on localhost that pushes events to a
+/// [`SocketMode`](crate::socket::socket_mode::SocketMode) client and receives its acks.
+///
+/// Events pushed before the client connects are sent once it does. Hand the server's url to the
+/// client with [`FakeSlack::serve_socket_mode`](crate::testing::fake_slack::FakeSlack::serve_socket_mode).
+pub struct FakeSocketMode {
+    url: String,
+    outgoing: Sender<Message>,
+    received: Receiver<Value>,
+}
+
+impl FakeSocketMode {
+    /// Starts listening on a free port.
+    pub async fn start() -> Result<FakeSocketMode, Error> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("ws://{}/", listener.local_addr()?);
+        let (outgoing, outgoing_receiver) = unbounded();
+        let (received_sender, received) = unbounded();
+
+        runtime::spawn(serve(listener, outgoing_receiver, received_sender));
+
+        Ok(FakeSocketMode {
+            url,
+            outgoing,
+            received,
+        })
+    }
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+    pub async fn push(&self, event: &SocketModeEvent) -> Result<(), Error> {
+        self.push_json(serde_json::to_value(event)?).await
+    }
+    /// Pushes a raw envelope, such as one this crate can't parse.
+    pub async fn push_json(&self, event: Value) -> Result<(), Error> {
+        self.send(Message::Text(event.to_string())).await
+    }
+    /// Pushes the envelopes of `cassette` in the order they were recorded.
+    pub async fn replay(&self, cassette: &Cassette) -> Result<(), Error> {
+        for envelope in &cassette.envelopes {
+            match envelope {
+                Value::String(text) => self.send(Message::Text(text.clone())).await?,
+                envelope => self.push_json(envelope.clone()).await?,
+            }
+        }
+        Ok(())
+    }
+    /// Closes the connection, which ends the client's `run`.
+    pub async fn close(&self) -> Result<(), Error> {
+        self.send(Message::Close(None)).await
+    }
+    /// Waits for the next JSON message from the client, or `None` once the server stopped.
+    pub async fn next_message(&self) -> Option<Value> {
+        self.received.recv().await.ok()
+    }
+    /// Waits for the next message from the client and returns the envelope id it acks.
+    pub async fn next_ack(&self) -> Option<String> {
+        let message = self.next_message().await?;
+        message["envelope_id"].as_str().map(str::to_string)
+    }
+
+    async fn send(&self, message: Message) -> Result<(), Error> {
+        self.outgoing
+            .send(message)
+            .await
+            .map_err(|_| Error::NotFoundStream)
+    }
+}
+
+/// Serves one client at a time, so a client that reconnects picks up the remaining events.
+async fn serve(listener: TcpListener, outgoing: Receiver<Message>, received: Sender<Value>) {
+    while let Ok(tcp_stream) = listener.accept().await {
+        let ws = match async_tungstenite::accept_async(tcp_stream).await {
+            Ok(ws) => ws,
+            Err(e) => {
+                log::warn!("fake socket mode handshake error: {}", e);
+                continue;
+            }
+        };
+        let (mut sink, mut stream) = ws.split();
+
+        let received = received.clone();
+        runtime::spawn(async move {
+            while let Some(Ok(message)) = stream.next().await {
+                if let Message::Text(text) = message {
+                    match serde_json::from_str(&text) {
+                        Ok(value) => {
+                            let _ = received.send(value).await;
+                        }
+                        Err(e) => log::warn!("fake socket mode received invalid json: {}", e),
+                    }
+                }
+            }
+        });
+
+        while let Ok(message) = outgoing.recv().await {
+            let close = matches!(message, Message::Close(_));
+            if sink.send(message).await.is_err() || close {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::http_client::SlackWebAPIClient;
+    use crate::socket::event::{EventsAPI, HelloEvent};
+    use crate::socket::socket_mode::{ack, EventHandler, SocketMode, Stream};
+    use crate::testing::fake_slack::FakeSlack;
+    use async_trait::async_trait;
+    use futures_util::future::join;
+    use serde_json::json;
+
+    struct Handler;
+
+    #[async_trait]
+    impl<S> EventHandler<S> for Handler
+    where
+        S: SlackWebAPIClient,
+    {
+        async fn on_events_api(&mut self, _: &SocketMode<S>, e: EventsAPI, s: &mut Stream) {
+            ack(&e.envelope_id, s).await.unwrap();
+        }
+    }
+
+    #[cfg_attr(feature = "tokio-runtime", tokio::test)]
+    #[cfg_attr(not(feature = "tokio-runtime"), async_std::test)]
+    async fn push_events_and_receive_acks() {
+        let slack = FakeSlack::new();
+        let server = FakeSocketMode::start().await.unwrap();
+        slack.serve_socket_mode(&server);
+
+        let socket_mode = SocketMode::new(
+            slack.clone(),
+            "xapp-token".to_string(),
+            "xoxb-token".to_string(),
+        );
+        let client = async move { socket_mode.run(&mut Handler).await.unwrap() };
+        let server_side = async {
+            server
+                .push(&SocketModeEvent::HelloEvent(HelloEvent {
+                    connection_info: None,
+                    num_connections: Some(1),
+                    debug_info: None,
+                }))
+                .await
+                .unwrap();
+            server
+                .push_json(json!({"type": "not_a_real_envelope"}))
+                .await
+                .unwrap();
+            server
+                .push_json(json!({
+                    "type": "events_api",
+                    "envelope_id": "0b5a4c2e-6f3e-4c4b-9d8e-1f2a3b4c5d6e",
+                    "payload": {"type": "event_callback"}
+                }))
+                .await
+                .unwrap();
+            assert_eq!(
+                server.next_ack().await.unwrap(),
+                "0b5a4c2e-6f3e-4c4b-9d8e-1f2a3b4c5d6e"
+            );
+            server
+                .push_json(json!({
+                    "type": "events_api",
+                    "envelope_id": "57d6a792-4d35-4d0b-b6aa-3361493e1caf",
+                    "accepts_response_payload": false,
+                    "payload": {
+                        "token": "bHKJ2n9AW6Ju3MjciOHfbA1b",
+                        "team_id": "T1234567890",
+                        "api_app_id": "A0000000000",
+                        "event_id": "Ev0000000000",
+                        "event_time": 1600000000,
+                        "type": "event_callback",
+                        "event": {
+                            "type": "app_home_opened",
+                            "user": "U061F7AUR",
+                            "channel": "D0LAN2Q65",
+                            "event_ts": "1515449522000016",
+                            "tab": "messages"
+                        }
+                    }
+                }))
+                .await
+                .unwrap();
+
+            assert_eq!(
+                server.next_ack().await.unwrap(),
+                "57d6a792-4d35-4d0b-b6aa-3361493e1caf"
+            );
+
+            server.close().await.unwrap();
+        };
+        join(client, server_side).await;
+
+        let call = slack.last_call("apps.connections.open").unwrap();
+        assert_eq!(call.token, "xapp-token");
+    }
+
+    #[derive(Default)]
+    struct Recorder {
+        event_ids: Vec<String>,
+    }
+
+    #[async_trait]
+    impl<S> EventHandler<S> for Recorder
+    where
+        S: SlackWebAPIClient,
+    {
+        async fn on_events_api(&mut self, _: &SocketMode<S>, e: EventsAPI, s: &mut Stream) {
+            self.event_ids.push(e.payload.event_id);
+            ack(&e.envelope_id, s).await.unwrap();
+        }
+    }
+
+    #[cfg_attr(feature = "tokio-runtime", tokio::test)]
+    #[cfg_attr(not(feature = "tokio-runtime"), async_std::test)]
+    async fn ack_unparseable_envelopes_and_keep_handling() {
+        let slack = FakeSlack::new();
+        let server = FakeSocketMode::start().await.unwrap();
+        slack.serve_socket_mode(&server);
+
+        let socket_mode = SocketMode::new(
+            slack.clone(),
+            "xapp-token".to_string(),
+            "xoxb-token".to_string(),
+        );
+        let mut handler = Recorder::default();
+        let client = async { socket_mode.run(&mut handler).await.unwrap() };
+        let server_side = async {
+            // An `app_mention` without its fields: the envelope can't be parsed.
+            server
+                .push_json(json!({
+                    "type": "events_api",
+                    "envelope_id": "3f1c2b7a-5d4e-4f6a-8b9c-0d1e2f3a4b5c",
+                    "accepts_response_payload": false,
+                    "payload": {
+                        "token": "bHKJ2n9AW6Ju3MjciOHfbA1b",
+                        "team_id": "T1234567890",
+                        "api_app_id": "A0000000000",
+                        "event_id": "Ev0000000001",
+                        "event_time": 1600000000,
+                        "type": "event_callback",
+                        "event": {"type": "app_mention"}
+                    }
+                }))
+                .await
+                .unwrap();
+            assert_eq!(
+                server.next_ack().await.unwrap(),
+                "3f1c2b7a-5d4e-4f6a-8b9c-0d1e2f3a4b5c"
+            );
+
+            server
+                .push_json(json!({
+                    "type": "events_api",
+                    "envelope_id": "57d6a792-4d35-4d0b-b6aa-3361493e1caf",
+                    "accepts_response_payload": false,
+                    "payload": {
+                        "token": "bHKJ2n9AW6Ju3MjciOHfbA1b",
+                        "team_id": "T1234567890",
+                        "api_app_id": "A0000000000",
+                        "event_id": "Ev0000000002",
+                        "event_time": 1600000000,
+                        "type": "event_callback",
+                        "event": {
+                            "type": "app_home_opened",
+                            "user": "U061F7AUR",
+                            "channel": "D0LAN2Q65",
+                            "event_ts": "1515449522000016",
+                            "tab": "messages"
+                        }
+                    }
+                }))
+                .await
+                .unwrap();
+            assert_eq!(
+                server.next_ack().await.unwrap(),
+                "57d6a792-4d35-4d0b-b6aa-3361493e1caf"
+            );
+
+            server.close().await.unwrap();
+        };
+        join(client, server_side).await;
+
+        assert_eq!(handler.event_ids, vec!["Ev0000000002".to_string()]);
+    }
+}
@@ -0,0 +1,152 @@
+//! A local HTTP proxy for testing clients configured with a
+//! [`ConnectionConfig`](crate::connection::ConnectionConfig) proxy.
+
+use crate::error::Error;
+use crate::runtime::{self, TcpListener, TcpStream};
+use futures_util::future::{self, Either};
+use futures_util::{io, AsyncReadExt, AsyncWriteExt};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// A request [`HttpProxy`] received.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProxyRequest {
+    /// `CONNECT` for tunnels, the request method otherwise.
+    pub method: String,
+    /// `host:port` for tunnels, the absolute url otherwise.
+    pub target: String,
+    /// Header names are lowercase.
+    pub headers: HashMap<String, String>,
+}
+
+/// Tunnels `CONNECT` requests and forwards absolute-form requests, on localhost.
+///
+/// Every connection goes to the target of its first request.
+pub struct HttpProxy {
+    url: String,
+    requests: Arc<Mutex<Vec<ProxyRequest>>>,
+}
+
+impl HttpProxy {
+    pub async fn start() -> Result<HttpProxy, Error> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}", listener.local_addr()?);
+        let requests = Arc::new(Mutex::new(vec![]));
+
+        runtime::spawn(serve(listener, requests.clone()));
+
+        Ok(HttpProxy { url, requests })
+    }
+    /// The url to hand to [`ConnectionConfig::proxy`](crate::connection::ConnectionConfig::proxy).
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+    /// All requests received so far, oldest first.
+    pub fn requests(&self) -> Vec<ProxyRequest> {
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+}
+
+async fn serve(listener: TcpListener, requests: Arc<Mutex<Vec<ProxyRequest>>>) {
+    while let Ok(stream) = listener.accept().await {
+        let requests = requests.clone();
+        runtime::spawn(async move {
+            if let Err(e) = handle(stream, requests).await {
+                log::warn!("http proxy error: {}", e);
+            }
+        });
+    }
+}
+
+async fn handle(
+    mut client: TcpStream,
+    requests: Arc<Mutex<Vec<ProxyRequest>>>,
+) -> std::io::Result<()> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0; 4096];
+    let head_end = loop {
+        if let Some(i) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+            break i;
+        }
+        let n = client.read(&mut chunk).await?;
+        if n == 0 {
+            return Ok(());
+        }
+        buffer.extend_from_slice(&chunk[..n]);
+    };
+
+    let head = String::from_utf8_lossy(&buffer[..head_end]).into_owned();
+    let mut lines = head.split("\r\n");
+    let request_line: Vec<&str> = lines.next().unwrap_or_default().split(' ').collect();
+    let (method, target, version) = match request_line[..] {
+        [method, target, version] => (method, target, version),
+        _ => return Ok(()),
+    };
+    let request = ProxyRequest {
+        method: method.to_string(),
+        target: target.to_string(),
+        headers: lines
+            .filter_map(|line| line.split_once(':'))
+            .map(|(name, value)| (name.trim().to_ascii_lowercase(), value.trim().to_string()))
+            .collect(),
+    };
+    requests
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .push(request);
+
+    let mut upstream;
+    if method == "CONNECT" {
+        upstream = match connect(target).await {
+            Ok(upstream) => upstream,
+            Err(_) => return client.write_all(b"HTTP/1.1 502 Bad Gateway\r\n\r\n").await,
+        };
+        client
+            .write_all(b"HTTP/1.1 200 Connection Established\r\n\r\n")
+            .await?;
+        // Anything sent after the CONNECT request already belongs to the tunnel.
+        upstream.write_all(&buffer[head_end + 4..]).await?;
+    } else {
+        let url = match url::Url::parse(target) {
+            Ok(url) => url,
+            Err(_) => return client.write_all(b"HTTP/1.1 400 Bad Request\r\n\r\n").await,
+        };
+        let authority = format!(
+            "{}:{}",
+            url.host_str().unwrap_or_default(),
+            url.port_or_known_default().unwrap_or(80)
+        );
+        upstream = match connect(&authority).await {
+            Ok(upstream) => upstream,
+            Err(_) => return client.write_all(b"HTTP/1.1 502 Bad Gateway\r\n\r\n").await,
+        };
+        let path = match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_string(),
+        };
+        let rest = &head[head.find("\r\n").unwrap_or(head.len())..];
+        let origin_form = format!("{} {} {}{}\r\n\r\n", method, path, version, rest);
+        upstream.write_all(origin_form.as_bytes()).await?;
+        upstream.write_all(&buffer[head_end + 4..]).await?;
+    }
+
+    let (client_reader, mut client_writer) = client.split();
+    let (upstream_reader, mut upstream_writer) = upstream.split();
+    let to_upstream = io::copy(client_reader, &mut upstream_writer);
+    let to_client = io::copy(upstream_reader, &mut client_writer);
+    futures_util::pin_mut!(to_upstream, to_client);
+    match future::select(to_upstream, to_client).await {
+        Either::Left((result, _)) | Either::Right((result, _)) => result.map(|_| ()),
+    }
+}
+
+async fn connect(authority: &str) -> std::io::Result<TcpStream> {
+    let (host, port) = authority
+        .rsplit_once(':')
+        .and_then(|(host, port)| Some((host, port.parse().ok()?)))
+        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidInput, authority))?;
+    runtime::connect(host.trim_start_matches('[').trim_end_matches(']'), port).await
+}
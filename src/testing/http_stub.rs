@@ -0,0 +1,196 @@
+//! A local HTTP server standing in for the Slack WEB API.
+
+use crate::error::Error;
+use crate::runtime::{self, TcpListener, TcpStream};
+use futures_util::{AsyncReadExt, AsyncWriteExt};
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+/// A response [`HttpStub`] sends.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StubResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+    /// How long to wait before responding.
+    pub delay: Option<Duration>,
+}
+
+impl StubResponse {
+    /// A 200 response with a JSON body.
+    pub fn json(body: Value) -> StubResponse {
+        StubResponse {
+            status: 200,
+            headers: vec![("Content-Type".to_string(), "application/json".to_string())],
+            body: body.to_string(),
+            delay: None,
+        }
+    }
+    pub fn status(mut self, status: u16) -> StubResponse {
+        self.status = status;
+        self
+    }
+    pub fn header(mut self, name: &str, value: &str) -> StubResponse {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+    pub fn delay(mut self, delay: Duration) -> StubResponse {
+        self.delay = Some(delay);
+        self
+    }
+}
+
+/// A request [`HttpStub`] received.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StubRequest {
+    pub path: String,
+    /// Header names are lowercase.
+    pub headers: HashMap<String, String>,
+    pub body: String,
+}
+
+#[derive(Debug)]
+struct State {
+    requests: Vec<StubRequest>,
+    once: VecDeque<StubResponse>,
+    always: StubResponse,
+}
+
+/// Serves scripted responses over plain HTTP on localhost, one request per connection.
+///
+/// Point a client at it with [`ClientBuilder::base_url`](crate::http_client::ClientBuilder::base_url).
+/// Unscripted requests get `{"ok": true}`.
+pub struct HttpStub {
+    base_url: String,
+    state: Arc<Mutex<State>>,
+}
+
+impl HttpStub {
+    pub async fn start() -> Result<HttpStub, Error> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let base_url = format!("http://{}/api/", listener.local_addr()?);
+        let state = Arc::new(Mutex::new(State {
+            requests: vec![],
+            once: VecDeque::new(),
+            always: StubResponse::json(serde_json::json!({"ok": true})),
+        }));
+
+        runtime::spawn(serve(listener, state.clone()));
+
+        Ok(HttpStub { base_url, state })
+    }
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+    /// Answers every request with `response`.
+    pub fn respond(&self, response: StubResponse) {
+        self.state().always = response;
+    }
+    /// Answers the next request with `response`, before any [`HttpStub::respond`] response.
+    pub fn respond_once(&self, response: StubResponse) {
+        self.state().once.push_back(response);
+    }
+    /// All requests received so far, oldest first.
+    pub fn requests(&self) -> Vec<StubRequest> {
+        self.state().requests.clone()
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+async fn serve(listener: TcpListener, state: Arc<Mutex<State>>) {
+    while let Ok(stream) = listener.accept().await {
+        let state = state.clone();
+        runtime::spawn(async move {
+            if let Err(e) = handle(stream, state).await {
+                log::warn!("http stub error: {}", e);
+            }
+        });
+    }
+}
+
+async fn handle(mut stream: TcpStream, state: Arc<Mutex<State>>) -> std::io::Result<()> {
+    let request = match read_request(&mut stream).await? {
+        Some(request) => request,
+        None => return Ok(()),
+    };
+
+    let response = {
+        let mut state = state.lock().unwrap_or_else(|e| e.into_inner());
+        state.requests.push(request);
+        match state.once.pop_front() {
+            Some(response) => response,
+            None => state.always.clone(),
+        }
+    };
+
+    if let Some(delay) = response.delay {
+        runtime::sleep(delay).await;
+    }
+
+    let mut head = format!(
+        "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n",
+        response.status,
+        response.body.len()
+    );
+    for (name, value) in &response.headers {
+        head.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    head.push_str("\r\n");
+
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(response.body.as_bytes()).await?;
+    stream.flush().await?;
+    stream.close().await
+}
+
+async fn read_request(stream: &mut TcpStream) -> std::io::Result<Option<StubRequest>> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0; 4096];
+
+    let head_end = loop {
+        if let Some(i) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+            break i;
+        }
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            return Ok(None);
+        }
+        buffer.extend_from_slice(&chunk[..n]);
+    };
+
+    let head = String::from_utf8_lossy(&buffer[..head_end]).into_owned();
+    let mut lines = head.split("\r\n");
+    let path = lines
+        .next()
+        .and_then(|line| line.split(' ').nth(1))
+        .unwrap_or_default()
+        .to_string();
+    let headers: HashMap<String, String> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_ascii_lowercase(), value.trim().to_string()))
+        .collect();
+
+    let content_length = headers
+        .get("content-length")
+        .and_then(|length| length.parse::<usize>().ok())
+        .unwrap_or(0);
+    let mut body = buffer.split_off(head_end + 4);
+    while body.len() < content_length {
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            break;
+        }
+        body.extend_from_slice(&chunk[..n]);
+    }
+
+    Ok(Some(StubRequest {
+        path,
+        headers,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    }))
+}
@@ -0,0 +1,17 @@
+//! In-process fakes of Slack for testing bots offline.
+//!
+//! [`FakeSlack`](fake_slack::FakeSlack) answers Web API calls and
+//! [`FakeSocketMode`](fake_socket_mode::FakeSocketMode) serves Socket Mode events.
+//! [`cassette`] records real traffic and replays it through them, and
+//! [`HttpStub`](http_stub::HttpStub) serves HTTP for testing the clients themselves, and
+//! [`HttpProxy`](http_proxy::HttpProxy) proxies it.
+//! Enable them with the `testing` feature.
+
+#[cfg(feature = "socket-mode")]
+pub mod cassette;
+#[cfg(feature = "socket-mode")]
+pub mod fake_slack;
+#[cfg(feature = "socket-mode")]
+pub mod fake_socket_mode;
+pub mod http_proxy;
+pub mod http_stub;
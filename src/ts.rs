@@ -0,0 +1,321 @@
+//! Message timestamps.
+//!
+//! Slack identifies messages by a `ts` such as `1355517523.000005`: seconds since the Unix
+//! epoch and a six digit fraction. [`Ts`] keeps the string exactly as Slack sent it, and orders
+//! and converts it by its numeric value.
+//!
+//! ```
+//! use slack_rust::ts::Ts;
+//! use std::time::Duration;
+//!
+//! let ts: Ts = "1355517523.000005".parse().unwrap();
+//! let later = ts.checked_add(Duration::from_micros(10)).unwrap();
+//! assert_eq!(later, "1355517523.000015");
+//! assert!(ts < later);
+//! assert_eq!(later.duration_since(&ts), Some(Duration::from_micros(10)));
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const MICROS_PER_SECOND: i128 = 1_000_000;
+
+/// A `ts` that isn't seconds optionally followed by a fraction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidTsError(pub String);
+
+impl fmt::Display for InvalidTsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid Ts Error: {}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidTsError {}
+
+/// A message timestamp.
+///
+/// It serializes as the original string. Timestamps that can't be parsed sort before all others.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq, Hash)]
+#[serde(transparent)]
+pub struct Ts(String);
+
+impl Ts {
+    pub fn new(ts: impl Into<String>) -> Ts {
+        Ts(ts.into())
+    }
+    pub fn from_parts(seconds: i64, micros: u32) -> Ts {
+        Ts(format!("{}.{:06}", seconds, micros))
+    }
+    pub fn now() -> Ts {
+        Ts::from(SystemTime::now())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_string(self) -> String {
+        self.0
+    }
+    /// Whole seconds since the Unix epoch.
+    pub fn seconds(&self) -> Option<i64> {
+        self.parts().map(|(seconds, _)| seconds)
+    }
+    /// The fraction in microseconds.
+    pub fn micros(&self) -> Option<u32> {
+        self.parts().map(|(_, micros)| micros)
+    }
+    pub fn to_system_time(&self) -> Option<SystemTime> {
+        let micros = u64::try_from(self.total_micros()?).ok()?;
+        UNIX_EPOCH.checked_add(Duration::from_micros(micros))
+    }
+    pub fn checked_add(&self, duration: Duration) -> Option<Ts> {
+        Ts::from_total_micros(
+            self.total_micros()?
+                .checked_add(duration.as_micros() as i128)?,
+        )
+    }
+    pub fn checked_sub(&self, duration: Duration) -> Option<Ts> {
+        Ts::from_total_micros(
+            self.total_micros()?
+                .checked_sub(duration.as_micros() as i128)?,
+        )
+    }
+    /// The time elapsed from `earlier` to this timestamp, or `None` if `earlier` is later.
+    pub fn duration_since(&self, earlier: &Ts) -> Option<Duration> {
+        let micros = self.total_micros()? - earlier.total_micros()?;
+        Some(Duration::from_micros(u64::try_from(micros).ok()?))
+    }
+
+    fn parts(&self) -> Option<(i64, u32)> {
+        let (seconds, fraction) = match self.0.split_once('.') {
+            Some((seconds, fraction)) => (seconds, fraction),
+            None => (self.0.as_str(), ""),
+        };
+        if seconds.is_empty()
+            || !seconds.chars().all(|c| c.is_ascii_digit())
+            || !fraction.chars().all(|c| c.is_ascii_digit())
+        {
+            return None;
+        }
+        let seconds = seconds.parse::<i64>().ok()?;
+        let micros = fraction
+            .chars()
+            .chain(std::iter::repeat('0'))
+            .take(6)
+            .collect::<String>()
+            .parse::<u32>()
+            .ok()?;
+        Some((seconds, micros))
+    }
+    fn total_micros(&self) -> Option<i128> {
+        let (seconds, micros) = self.parts()?;
+        Some(seconds as i128 * MICROS_PER_SECOND + micros as i128)
+    }
+    /// Timestamps before the Unix epoch can't be represented.
+    fn from_total_micros(micros: i128) -> Option<Ts> {
+        if micros < 0 {
+            return None;
+        }
+        let seconds = i64::try_from(micros / MICROS_PER_SECOND).ok()?;
+        Some(Ts::from_parts(seconds, (micros % MICROS_PER_SECOND) as u32))
+    }
+}
+
+impl Ord for Ts {
+    fn cmp(&self, other: &Ts) -> Ordering {
+        self.total_micros()
+            .cmp(&other.total_micros())
+            .then_with(|| self.0.cmp(&other.0))
+    }
+}
+
+impl PartialOrd for Ts {
+    fn partial_cmp(&self, other: &Ts) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for Ts {
+    type Err = InvalidTsError;
+
+    fn from_str(s: &str) -> Result<Ts, InvalidTsError> {
+        let ts = Ts::new(s);
+        match ts.parts() {
+            Some(_) => Ok(ts),
+            None => Err(InvalidTsError(s.to_string())),
+        }
+    }
+}
+
+impl From<&str> for Ts {
+    fn from(ts: &str) -> Ts {
+        Ts::new(ts)
+    }
+}
+
+impl From<String> for Ts {
+    fn from(ts: String) -> Ts {
+        Ts(ts)
+    }
+}
+
+impl From<Ts> for String {
+    fn from(ts: Ts) -> String {
+        ts.0
+    }
+}
+
+impl From<SystemTime> for Ts {
+    /// Times before the Unix epoch become the epoch.
+    fn from(time: SystemTime) -> Ts {
+        let duration = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+        Ts::from_parts(duration.as_secs() as i64, duration.subsec_micros())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Ts {
+    pub fn to_chrono(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.to_system_time().map(chrono::DateTime::from)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::Utc>> for Ts {
+    fn from(time: chrono::DateTime<chrono::Utc>) -> Ts {
+        Ts::from(SystemTime::from(time))
+    }
+}
+
+#[cfg(feature = "time")]
+impl Ts {
+    pub fn to_offset_date_time(&self) -> Option<time::OffsetDateTime> {
+        self.to_system_time().map(time::OffsetDateTime::from)
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for Ts {
+    fn from(time: time::OffsetDateTime) -> Ts {
+        Ts::from(SystemTime::from(time))
+    }
+}
+
+impl AsRef<str> for Ts {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Ts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl PartialEq<str> for Ts {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Ts {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<String> for Ts {
+    fn eq(&self, other: &String) -> bool {
+        &self.0 == other
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_ts() {
+        let ts: Ts = "1355517523.000005".parse().unwrap();
+        assert_eq!(ts.seconds(), Some(1355517523));
+        assert_eq!(ts.micros(), Some(5));
+
+        let ts: Ts = "1355517523.5".parse().unwrap();
+        assert_eq!(ts.micros(), Some(500000));
+
+        let ts: Ts = "1562180400".parse().unwrap();
+        assert_eq!(ts.seconds(), Some(1562180400));
+        assert_eq!(ts.micros(), Some(0));
+
+        assert!("".parse::<Ts>().is_err());
+        assert!("abc".parse::<Ts>().is_err());
+        assert!("1355517523.00x".parse::<Ts>().is_err());
+        assert!("-1.5".parse::<Ts>().is_err());
+    }
+
+    #[test]
+    fn order_ts() {
+        let mut timestamps: Vec<Ts> = vec![
+            "1355517523.000010".into(),
+            "1355517523.5".into(),
+            "999999999.999999".into(),
+            "1355517523.000005".into(),
+        ];
+        timestamps.sort();
+        assert_eq!(
+            timestamps,
+            vec![
+                "999999999.999999",
+                "1355517523.000005",
+                "1355517523.000010",
+                "1355517523.5"
+            ]
+        );
+    }
+
+    #[test]
+    fn convert_ts() {
+        let time = UNIX_EPOCH + Duration::new(4102444800, 123456000);
+        let ts = Ts::from(time);
+        assert_eq!(ts, "4102444800.123456");
+        assert_eq!(ts.to_system_time(), Some(time));
+
+        assert_eq!(
+            ts.checked_sub(Duration::from_secs(1)).unwrap(),
+            "4102444799.123456"
+        );
+        assert_eq!(
+            ts.duration_since(&"4102444800.000456".into()),
+            Some(Duration::from_millis(123))
+        );
+        assert_eq!(Ts::from("4102444799").duration_since(&ts), None);
+        assert_eq!(Ts::from("0.5").checked_sub(Duration::from_secs(1)), None);
+
+        assert_eq!(
+            serde_json::to_string(&ts).unwrap(),
+            r#""4102444800.123456""#
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn convert_chrono() {
+        let ts = Ts::from("4102444800.123456");
+        let time = ts.to_chrono().unwrap();
+        assert_eq!(time.timestamp(), 4102444800);
+        assert_eq!(Ts::from(time), ts);
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn convert_time() {
+        let ts = Ts::from("4102444800.123456");
+        let time = ts.to_offset_date_time().unwrap();
+        assert_eq!(time.unix_timestamp(), 4102444800);
+        assert_eq!(Ts::from(time), ts);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::{ChannelId, TeamId};
 use crate::usergroups::usergroup::Usergroup;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -8,11 +9,11 @@ use serde_with::skip_serializing_none;
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct CreateRequest {
     pub name: String,
-    pub channels: Option<Vec<String>>,
+    pub channels: Option<Vec<ChannelId>>,
     pub description: Option<String>,
     pub handle: Option<String>,
     pub include_count: Option<bool>,
-    pub team_id: Option<String>,
+    pub team_id: Option<TeamId>,
 }
 
 #[skip_serializing_none]
@@ -32,11 +33,10 @@ pub async fn create<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("usergroups.create");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("usergroups.create", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<CreateResponse>(&result).map_err(Error::SerdeJsonError)
@@ -53,10 +53,10 @@ mod test {
     fn convert_request() {
         let request = CreateRequest {
             name: "My Test Team".to_string(),
-            channels: Some(vec!["xxxxxxxxxx".to_string(), "xxxxxxxxxx".to_string()]),
+            channels: Some(vec!["xxxxxxxxxx".into(), "xxxxxxxxxx".into()]),
             description: Some("xxxxxxxxxx".to_string()),
             handle: Some("marketing".to_string()),
-            team_id: Some("T1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
             include_count: Some(true),
         };
         let json = r##"{
@@ -83,8 +83,8 @@ mod test {
         let response = CreateResponse {
             ok: true,
             usergroup: Some(Usergroup {
-                id: Some("S0615G0KT".to_string()),
-                team_id: Some("T060RNRCH".to_string()),
+                id: Some("S0615G0KT".into()),
+                team_id: Some("T060RNRCH".into()),
                 is_usergroup: Some(true),
                 name: Some("Marketing Team".to_string()),
                 description: Some("Marketing gurus, PR experts and product advocates.".to_string()),
@@ -102,6 +102,7 @@ mod test {
                     groups: Some(vec![]),
                 }),
                 user_count: Some("0".to_string()),
+                ..Default::default()
             }),
             ..Default::default()
         };
@@ -141,14 +142,14 @@ mod test {
     async fn test_create() {
         let param = CreateRequest {
             name: "My Test Team".to_string(),
-            channels: Some(vec!["xxxxxxxxxx".to_string(), "xxxxxxxxxx".to_string()]),
+            channels: Some(vec!["xxxxxxxxxx".into(), "xxxxxxxxxx".into()]),
             description: Some("xxxxxxxxxx".to_string()),
             handle: Some("marketing".to_string()),
-            team_id: Some("T1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
             include_count: Some(true),
         };
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "usergroup": {
@@ -182,8 +183,8 @@ mod test {
         let expect = CreateResponse {
             ok: true,
             usergroup: Some(Usergroup {
-                id: Some("S0615G0KT".to_string()),
-                team_id: Some("T1234567890".to_string()),
+                id: Some("S0615G0KT".into()),
+                team_id: Some("T1234567890".into()),
                 is_usergroup: Some(true),
                 name: Some("My Test Team".to_string()),
                 description: Some("Marketing gurus, PR experts and product advocates.".to_string()),
@@ -201,6 +202,7 @@ mod test {
                     groups: Some(vec![]),
                 }),
                 user_count: Some("0".to_string()),
+                ..Default::default()
             }),
             ..Default::default()
         };
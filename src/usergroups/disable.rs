@@ -1,5 +1,6 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::{TeamId, UsergroupId};
 use crate::usergroups::usergroup::Usergroup;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -7,9 +8,9 @@ use serde_with::skip_serializing_none;
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct DisableRequest {
-    pub usergroup: String,
+    pub usergroup: UsergroupId,
     pub include_count: Option<bool>,
-    pub team_id: Option<String>,
+    pub team_id: Option<TeamId>,
 }
 
 #[skip_serializing_none]
@@ -29,11 +30,10 @@ pub async fn disable<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("usergroups.disable");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("usergroups.disable", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<DisableResponse>(&result).map_err(Error::SerdeJsonError)
@@ -49,9 +49,9 @@ mod test {
     #[test]
     fn convert_request() {
         let request = DisableRequest {
-            usergroup: "S0604QSJC".to_string(),
+            usergroup: "S0604QSJC".into(),
             include_count: Some(true),
-            team_id: Some("T1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
         };
         let json = r##"{
   "usergroup": "S0604QSJC",
@@ -71,8 +71,8 @@ mod test {
         let response = DisableResponse {
             ok: true,
             usergroup: Some(Usergroup {
-                id: Some("S0615G0KT".to_string()),
-                team_id: Some("T060RNRCH".to_string()),
+                id: Some("S0615G0KT".into()),
+                team_id: Some("T060RNRCH".into()),
                 is_usergroup: Some(true),
                 name: Some("Marketing Team".to_string()),
                 description: Some("Marketing gurus, PR experts and product advocates.".to_string()),
@@ -90,6 +90,7 @@ mod test {
                     groups: Some(vec![]),
                 }),
                 user_count: Some("0".to_string()),
+                ..Default::default()
             }),
             ..Default::default()
         };
@@ -128,12 +129,12 @@ mod test {
     #[async_std::test]
     async fn test_disable() {
         let param = DisableRequest {
-            usergroup: "S0604QSJC".to_string(),
-            team_id: Some("T1234567890".to_string()),
+            usergroup: "S0604QSJC".into(),
+            team_id: Some("T1234567890".into()),
             include_count: Some(true),
         };
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "usergroup": {
@@ -167,8 +168,8 @@ mod test {
         let expect = DisableResponse {
             ok: true,
             usergroup: Some(Usergroup {
-                id: Some("S0604QSJC".to_string()),
-                team_id: Some("T1234567890".to_string()),
+                id: Some("S0604QSJC".into()),
+                team_id: Some("T1234567890".into()),
                 is_usergroup: Some(true),
                 name: Some("Marketing Team".to_string()),
                 description: Some("Marketing gurus, PR experts and product advocates.".to_string()),
@@ -186,6 +187,7 @@ mod test {
                     groups: Some(vec![]),
                 }),
                 user_count: Some("0".to_string()),
+                ..Default::default()
             }),
             ..Default::default()
         };
@@ -1,5 +1,6 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::TeamId;
 use crate::usergroups::usergroup::Usergroup;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -10,7 +11,7 @@ pub struct ListRequest {
     pub include_count: Option<bool>,
     pub include_disabled: Option<bool>,
     pub include_users: Option<bool>,
-    pub team_id: Option<String>,
+    pub team_id: Option<TeamId>,
 }
 
 #[skip_serializing_none]
@@ -30,11 +31,10 @@ pub async fn list<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("usergroups.list");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("usergroups.list", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<ListResponse>(&result).map_err(Error::SerdeJsonError)
@@ -53,7 +53,7 @@ mod test {
             include_count: Some(true),
             include_disabled: Some(true),
             include_users: Some(true),
-            team_id: Some("T1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
         };
         let json = r##"{
   "include_count": true,
@@ -75,8 +75,8 @@ mod test {
             ok: true,
             usergroups: Some(vec![
                 Usergroup {
-                    id: Some("S0615G0KT".to_string()),
-                    team_id: Some("T060RNRCH".to_string()),
+                    id: Some("S0615G0KT".into()),
+                    team_id: Some("T060RNRCH".into()),
                     is_usergroup: Some(true),
                     name: Some("Marketing Team".to_string()),
                     description: Some(
@@ -96,10 +96,11 @@ mod test {
                         groups: Some(vec![]),
                     }),
                     user_count: Some("0".to_string()),
+                    ..Default::default()
                 },
                 Usergroup {
-                    id: Some("S0615G0KT".to_string()),
-                    team_id: Some("T060RNRCH".to_string()),
+                    id: Some("S0615G0KT".into()),
+                    team_id: Some("T060RNRCH".into()),
                     is_usergroup: Some(true),
                     name: Some("Marketing Team".to_string()),
                     description: Some(
@@ -119,6 +120,7 @@ mod test {
                         groups: Some(vec![]),
                     }),
                     user_count: Some("0".to_string()),
+                    ..Default::default()
                 },
             ]),
             ..Default::default()
@@ -184,10 +186,10 @@ mod test {
             include_count: Some(true),
             include_disabled: Some(true),
             include_users: Some(true),
-            team_id: Some("T1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
         };
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "usergroups": [
@@ -245,8 +247,8 @@ mod test {
             ok: true,
             usergroups: Some(vec![
                 Usergroup {
-                    id: Some("S0615G0KT".to_string()),
-                    team_id: Some("T060RNRCH".to_string()),
+                    id: Some("S0615G0KT".into()),
+                    team_id: Some("T060RNRCH".into()),
                     is_usergroup: Some(true),
                     name: Some("Marketing Team".to_string()),
                     description: Some(
@@ -266,10 +268,11 @@ mod test {
                         groups: Some(vec![]),
                     }),
                     user_count: Some("0".to_string()),
+                    ..Default::default()
                 },
                 Usergroup {
-                    id: Some("S0615G0KT".to_string()),
-                    team_id: Some("T060RNRCH".to_string()),
+                    id: Some("S0615G0KT".into()),
+                    team_id: Some("T060RNRCH".into()),
                     is_usergroup: Some(true),
                     name: Some("Marketing Team".to_string()),
                     description: Some(
@@ -289,6 +292,7 @@ mod test {
                         groups: Some(vec![]),
                     }),
                     user_count: Some("0".to_string()),
+                    ..Default::default()
                 },
             ]),
             ..Default::default()
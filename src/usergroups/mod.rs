@@ -1,7 +1,13 @@
+#[cfg(feature = "web-api-usergroups")]
 pub mod create;
+#[cfg(feature = "web-api-usergroups")]
 pub mod disable;
+#[cfg(feature = "web-api-usergroups")]
 pub mod enable;
+#[cfg(feature = "web-api-usergroups")]
 pub mod list;
+#[cfg(feature = "web-api-usergroups")]
 pub mod update;
 pub mod usergroup;
+#[cfg(feature = "web-api-usergroups")]
 pub mod users;
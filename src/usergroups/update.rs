@@ -1,5 +1,6 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::{ChannelId, TeamId, UsergroupId};
 use crate::usergroups::usergroup::Usergroup;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -7,13 +8,13 @@ use serde_with::skip_serializing_none;
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct UpdateRequest {
-    pub usergroup: String,
-    pub channels: Option<Vec<String>>,
+    pub usergroup: UsergroupId,
+    pub channels: Option<Vec<ChannelId>>,
     pub description: Option<String>,
     pub handle: Option<String>,
     pub include_count: Option<bool>,
     pub name: Option<String>,
-    pub team_id: Option<String>,
+    pub team_id: Option<TeamId>,
 }
 
 #[skip_serializing_none]
@@ -33,11 +34,10 @@ pub async fn update<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("usergroups.update");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("usergroups.update", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<UpdateResponse>(&result).map_err(Error::SerdeJsonError)
@@ -53,13 +53,13 @@ mod test {
     #[test]
     fn convert_request() {
         let request = UpdateRequest {
-            usergroup: "S0604QSJC".to_string(),
-            channels: Some(vec!["xxxxxxxxxxx".to_string(), "xxxxxxxxxxx".to_string()]),
+            usergroup: "S0604QSJC".into(),
+            channels: Some(vec!["xxxxxxxxxxx".into(), "xxxxxxxxxxx".into()]),
             description: Some("xxxxxxxxxxx".to_string()),
             handle: Some("marketing".to_string()),
             include_count: Some(true),
             name: Some("My Test Team".to_string()),
-            team_id: Some("T1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
         };
         let json = r##"{
   "usergroup": "S0604QSJC",
@@ -86,8 +86,8 @@ mod test {
         let response = UpdateResponse {
             ok: true,
             usergroup: Some(Usergroup {
-                id: Some("S0615G0KT".to_string()),
-                team_id: Some("T060RNRCH".to_string()),
+                id: Some("S0615G0KT".into()),
+                team_id: Some("T060RNRCH".into()),
                 is_usergroup: Some(true),
                 name: Some("Marketing Team".to_string()),
                 description: Some("Marketing gurus, PR experts and product advocates.".to_string()),
@@ -105,6 +105,7 @@ mod test {
                     groups: Some(vec![]),
                 }),
                 user_count: Some("0".to_string()),
+                ..Default::default()
             }),
             ..Default::default()
         };
@@ -143,12 +144,12 @@ mod test {
     #[async_std::test]
     async fn test_update() {
         let param = UpdateRequest {
-            usergroup: "S0604QSJC".to_string(),
+            usergroup: "S0604QSJC".into(),
             description: Some("xxxxxxxxxxx".to_string()),
             ..Default::default()
         };
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "usergroup": {
@@ -182,8 +183,8 @@ mod test {
         let expect = UpdateResponse {
             ok: true,
             usergroup: Some(Usergroup {
-                id: Some("S0604QSJC".to_string()),
-                team_id: Some("T1234567890".to_string()),
+                id: Some("S0604QSJC".into()),
+                team_id: Some("T1234567890".into()),
                 is_usergroup: Some(true),
                 name: Some("Marketing Team".to_string()),
                 description: Some("xxxxxxxxxxx".to_string()),
@@ -201,6 +202,7 @@ mod test {
                     groups: Some(vec![]),
                 }),
                 user_count: Some("0".to_string()),
+                ..Default::default()
             }),
             ..Default::default()
         };
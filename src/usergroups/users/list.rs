@@ -1,14 +1,15 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::{TeamId, UserId, UsergroupId};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct ListRequest {
-    pub usergroup: String,
+    pub usergroup: UsergroupId,
     pub include_disabled: Option<bool>,
-    pub team_id: Option<String>,
+    pub team_id: Option<TeamId>,
 }
 
 #[skip_serializing_none]
@@ -17,7 +18,7 @@ pub struct ListResponse {
     pub ok: bool,
     pub error: Option<String>,
     pub response_metadata: Option<ResponseMetadata>,
-    pub users: Option<Vec<String>>,
+    pub users: Option<Vec<UserId>>,
 }
 
 pub async fn list<T>(
@@ -28,11 +29,10 @@ pub async fn list<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("usergroups.users.list");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("usergroups.users.list", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<ListResponse>(&result).map_err(Error::SerdeJsonError)
@@ -47,8 +47,8 @@ mod test {
     #[test]
     fn convert_request() {
         let request = ListRequest {
-            usergroup: "S0604QSJC".to_string(),
-            team_id: Some("T1234567890".to_string()),
+            usergroup: "S0604QSJC".into(),
+            team_id: Some("T1234567890".into()),
             include_disabled: Some(true),
         };
         let json = r##"{
@@ -68,7 +68,7 @@ mod test {
     fn convert_response() {
         let response = ListResponse {
             ok: true,
-            users: Some(vec!["xxxxxxxx".to_string(), "xxxxxxxx".to_string()]),
+            users: Some(vec!["xxxxxxxx".into(), "xxxxxxxx".into()]),
             ..Default::default()
         };
         let json = r##"{
@@ -89,12 +89,12 @@ mod test {
     #[async_std::test]
     async fn test_list() {
         let param = ListRequest {
-            usergroup: "S0604QSJC".to_string(),
-            team_id: Some("T1234567890".to_string()),
+            usergroup: "S0604QSJC".into(),
+            team_id: Some("T1234567890".into()),
             include_disabled: Some(true),
         };
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "users": [
@@ -110,7 +110,7 @@ mod test {
             .unwrap();
         let expect = ListResponse {
             ok: true,
-            users: Some(vec!["xxxxxxxx".to_string(), "xxxxxxxx".to_string()]),
+            users: Some(vec!["xxxxxxxx".into(), "xxxxxxxx".into()]),
             ..Default::default()
         };
 
@@ -1,5 +1,6 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::{TeamId, UsergroupId};
 use crate::usergroups::usergroup::Usergroup;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -7,10 +8,10 @@ use serde_with::skip_serializing_none;
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct UpdateRequest {
-    pub usergroup: String,
+    pub usergroup: UsergroupId,
     pub users: String,
     pub include_count: Option<bool>,
-    pub team_id: Option<String>,
+    pub team_id: Option<TeamId>,
 }
 
 #[skip_serializing_none]
@@ -30,11 +31,10 @@ pub async fn update<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("usergroups.users.update");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("usergroups.users.update", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<UpdateResponse>(&result).map_err(Error::SerdeJsonError)
@@ -50,9 +50,9 @@ mod test {
     #[test]
     fn convert_request() {
         let request = UpdateRequest {
-            usergroup: "S0604QSJC".to_string(),
+            usergroup: "S0604QSJC".into(),
             users: "U060R4BJ4,U060RNRCZ".to_string(),
-            team_id: Some("T1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
             include_count: Some(true),
         };
         let json = r##"{
@@ -74,8 +74,8 @@ mod test {
         let response = UpdateResponse {
             ok: true,
             usergroup: Some(Usergroup {
-                id: Some("S0615G0KT".to_string()),
-                team_id: Some("T060RNRCH".to_string()),
+                id: Some("S0615G0KT".into()),
+                team_id: Some("T060RNRCH".into()),
                 is_usergroup: Some(true),
                 name: Some("Marketing Team".to_string()),
                 description: Some("Marketing gurus, PR experts and product advocates.".to_string()),
@@ -93,6 +93,7 @@ mod test {
                     groups: Some(vec![]),
                 }),
                 user_count: Some("0".to_string()),
+                ..Default::default()
             }),
             ..Default::default()
         };
@@ -131,13 +132,13 @@ mod test {
     #[async_std::test]
     async fn test_update() {
         let param = UpdateRequest {
-            usergroup: "S0604QSJC".to_string(),
+            usergroup: "S0604QSJC".into(),
             users: "U060R4BJ4,U060RNRCZ".to_string(),
-            team_id: Some("T1234567890".to_string()),
+            team_id: Some("T1234567890".into()),
             include_count: Some(true),
         };
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "usergroup": {
@@ -171,8 +172,8 @@ mod test {
         let expect = UpdateResponse {
             ok: true,
             usergroup: Some(Usergroup {
-                id: Some("S0615G0KT".to_string()),
-                team_id: Some("T1234567890".to_string()),
+                id: Some("S0615G0KT".into()),
+                team_id: Some("T1234567890".into()),
                 is_usergroup: Some(true),
                 name: Some("My Test Team".to_string()),
                 description: Some("Marketing gurus, PR experts and product advocates.".to_string()),
@@ -190,6 +191,7 @@ mod test {
                     groups: Some(vec![]),
                 }),
                 user_count: Some("0".to_string()),
+                ..Default::default()
             }),
             ..Default::default()
         };
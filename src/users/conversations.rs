@@ -1,6 +1,7 @@
 use crate::channels::channel::Channel;
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::{TeamId, UserId};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
@@ -10,10 +11,10 @@ pub struct ConversationsRequest {
     pub cursor: Option<String>,
     pub exclude_archived: Option<bool>,
     pub limit: Option<i32>,
-    pub team_id: Option<String>,
+    pub team_id: Option<TeamId>,
     #[serde(rename = "type")]
     pub type_filed: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<UserId>,
 }
 
 #[skip_serializing_none]
@@ -33,11 +34,10 @@ pub async fn conversations<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("users.conversations");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("users.conversations", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<ConversationsResponse>(&result).map_err(Error::SerdeJsonError)
@@ -55,10 +55,10 @@ mod test {
         let request = ConversationsRequest {
             cursor: Some("xxxxxxxxxx".to_string()),
             exclude_archived: Some(true),
-            team_id: Some("xxxxxxxxxx".to_string()),
+            team_id: Some("xxxxxxxxxx".into()),
             type_filed: Some("xxxxxxxxxx".to_string()),
             limit: Some(1),
-            user: Some("xxxxxxxxxx".to_string()),
+            user: Some("xxxxxxxxxx".into()),
         };
         let json = r##"{
   "cursor": "xxxxxxxxxx",
@@ -81,11 +81,11 @@ mod test {
         let response = ConversationsResponse {
             ok: true,
             channels: Some(vec![Channel {
-                id: Some("C0EAQDV4Z".to_string()),
+                id: Some("C0EAQDV4Z".into()),
                 name: Some("endeavor".to_string()),
                 is_channel: Some(true),
                 created: Some(1504554479),
-                creator: Some("U0123456".to_string()),
+                creator: Some("U0123456".into()),
                 is_archived: Some(false),
                 is_general: Some(false),
                 name_normalized: Some("endeavor".to_string()),
@@ -94,17 +94,17 @@ mod test {
                 is_member: Some(false),
                 is_private: Some(false),
                 is_mpim: Some(false),
-                last_read: Some("0000000000.000000".to_string()),
+                last_read: Some("0000000000.000000".into()),
                 unread_count: Some(0),
                 unread_count_display: Some(0),
                 topic: Some(Topic {
                     value: Some("".to_string()),
-                    creator: Some("".to_string()),
+                    creator: Some("".into()),
                     last_set: Some(0),
                 }),
                 purpose: Some(Purpose {
                     value: Some("".to_string()),
-                    creator: Some("".to_string()),
+                    creator: Some("".into()),
                     last_set: Some(0),
                 }),
                 previous_names: Some(vec![]),
@@ -161,13 +161,13 @@ mod test {
         let param = ConversationsRequest {
             cursor: Some("xxxxxxxxxx".to_string()),
             exclude_archived: Some(true),
-            team_id: Some("xxxxxxxxxx".to_string()),
+            team_id: Some("xxxxxxxxxx".into()),
             type_filed: Some("xxxxxxxxxx".to_string()),
             limit: Some(1),
-            user: Some("xxxxxxxxxx".to_string()),
+            user: Some("xxxxxxxxxx".into()),
         };
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "channels": [
@@ -212,11 +212,11 @@ mod test {
         let expect = ConversationsResponse {
             ok: true,
             channels: Some(vec![Channel {
-                id: Some("C0EAQDV4Z".to_string()),
+                id: Some("C0EAQDV4Z".into()),
                 name: Some("endeavor".to_string()),
                 is_channel: Some(true),
                 created: Some(1504554479),
-                creator: Some("U0123456".to_string()),
+                creator: Some("U0123456".into()),
                 is_archived: Some(false),
                 is_general: Some(false),
                 name_normalized: Some("endeavor".to_string()),
@@ -225,17 +225,17 @@ mod test {
                 is_member: Some(false),
                 is_private: Some(false),
                 is_mpim: Some(false),
-                last_read: Some("0000000000.000000".to_string()),
+                last_read: Some("0000000000.000000".into()),
                 unread_count: Some(0),
                 unread_count_display: Some(0),
                 topic: Some(Topic {
                     value: Some("".to_string()),
-                    creator: Some("".to_string()),
+                    creator: Some("".into()),
                     last_set: Some(0),
                 }),
                 purpose: Some(Purpose {
                     value: Some("".to_string()),
-                    creator: Some("".to_string()),
+                    creator: Some("".into()),
                     last_set: Some(0),
                 }),
                 previous_names: Some(vec![]),
@@ -1,15 +1,16 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, DefaultResponse, SlackWebAPIClient};
+use crate::http_client::{DefaultResponse, SlackWebAPIClient};
 
 pub async fn delete_photo<T>(client: &T, bot_token: &str) -> Result<DefaultResponse, Error>
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("users.deletePhoto");
-
-    client.post(&url, bot_token).await.and_then(|result| {
-        serde_json::from_str::<DefaultResponse>(&result).map_err(Error::SerdeJsonError)
-    })
+    client
+        .call("users.deletePhoto", bot_token)
+        .await
+        .and_then(|result| {
+            serde_json::from_str::<DefaultResponse>(&result).map_err(Error::SerdeJsonError)
+        })
 }
 
 #[cfg(test)]
@@ -20,7 +21,7 @@ mod test {
     #[async_std::test]
     async fn test_delete_photo() {
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post().returning(|_, _| {
+        mock.expect_call().returning(|_, _| {
             Ok(r##"{
   "ok": true
 }"##
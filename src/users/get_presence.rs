@@ -1,12 +1,13 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::UserId;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct GetPresenceRequest {
-    pub user: Option<String>,
+    pub user: Option<UserId>,
 }
 
 #[skip_serializing_none]
@@ -20,7 +21,7 @@ pub struct GetPresenceResponse {
     pub auto_away: Option<bool>,
     pub manual_away: Option<bool>,
     pub connection_count: Option<i8>,
-    pub last_activity: Option<i32>,
+    pub last_activity: Option<i64>,
 }
 
 pub async fn get_presence<T>(
@@ -31,11 +32,10 @@ pub async fn get_presence<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("users.getPresence");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("users.getPresence", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<GetPresenceResponse>(&result).map_err(Error::SerdeJsonError)
@@ -50,7 +50,7 @@ mod test {
     #[test]
     fn convert_request() {
         let request = GetPresenceRequest {
-            user: Some("xxxxxxxxxx".to_string()),
+            user: Some("xxxxxxxxxx".into()),
         };
         let json = r##"{
   "user": "xxxxxxxxxx"
@@ -95,10 +95,10 @@ mod test {
     #[async_std::test]
     async fn test_get_presence() {
         let param = GetPresenceRequest {
-            user: Some("xxxxxxxxxx".to_string()),
+            user: Some("xxxxxxxxxx".into()),
         };
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "presence": "active",
@@ -1,5 +1,5 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
 use crate::team::teams::Team;
 use crate::users::user::User;
 use serde::{Deserialize, Serialize};
@@ -19,11 +19,12 @@ pub async fn identity<T>(client: &T, bot_token: &str) -> Result<IdentityResponse
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("users.identity");
-
-    client.post(&url, bot_token).await.and_then(|result| {
-        serde_json::from_str::<IdentityResponse>(&result).map_err(Error::SerdeJsonError)
-    })
+    client
+        .call("users.identity", bot_token)
+        .await
+        .and_then(|result| {
+            serde_json::from_str::<IdentityResponse>(&result).map_err(Error::SerdeJsonError)
+        })
 }
 
 #[cfg(test)]
@@ -36,12 +37,12 @@ mod test {
         let response = IdentityResponse {
             ok: true,
             user: Some(User {
-                id: Some("Sonny Whether".to_string()),
+                id: Some("Sonny Whether".into()),
                 name: Some("U0G9QF9C6".to_string()),
                 ..Default::default()
             }),
             team: Some(Team {
-                id: Some("T0G9PQBBK".to_string()),
+                id: Some("T0G9PQBBK".into()),
                 ..Default::default()
             }),
             ..Default::default()
@@ -67,7 +68,7 @@ mod test {
     #[async_std::test]
     async fn test_identity() {
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post().returning(|_, _| {
+        mock.expect_call().returning(|_, _| {
             Ok(r##"{
   "ok": true,
   "user": {
@@ -85,12 +86,12 @@ mod test {
         let expect = IdentityResponse {
             ok: true,
             user: Some(User {
-                id: Some("Sonny Whether".to_string()),
+                id: Some("Sonny Whether".into()),
                 name: Some("U0G9QF9C6".to_string()),
                 ..Default::default()
             }),
             team: Some(Team {
-                id: Some("T0G9PQBBK".to_string()),
+                id: Some("T0G9PQBBK".into()),
                 ..Default::default()
             }),
             ..Default::default()
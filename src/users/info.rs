@@ -1,5 +1,6 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::UserId;
 use crate::users::user::User;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -7,7 +8,7 @@ use serde_with::skip_serializing_none;
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct InfoRequest {
-    pub user: String,
+    pub user: UserId,
     pub include_locale: Option<bool>,
 }
 
@@ -28,11 +29,10 @@ pub async fn info<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("users.info");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("users.info", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<InfoResponse>(&result).map_err(Error::SerdeJsonError)
@@ -47,7 +47,7 @@ mod test {
     #[test]
     fn convert_request() {
         let request = InfoRequest {
-            user: "W1234567890".to_string(),
+            user: "W1234567890".into(),
             include_locale: Some(true),
         };
         let json = r##"{
@@ -67,7 +67,7 @@ mod test {
         let response = InfoResponse {
             ok: true,
             user: Some(User {
-                id: Some("Sonny Whether".to_string()),
+                id: Some("Sonny Whether".into()),
                 name: Some("U0G9QF9C6".to_string()),
                 ..Default::default()
             }),
@@ -91,11 +91,11 @@ mod test {
     #[async_std::test]
     async fn test_info() {
         let param = InfoRequest {
-            user: "W1234567890".to_string(),
+            user: "W1234567890".into(),
             include_locale: Some(true),
         };
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "user": {
@@ -115,7 +115,7 @@ mod test {
         let expect = InfoResponse {
             ok: true,
             user: Some(User {
-                id: Some("W1234567890".to_string()),
+                id: Some("W1234567890".into()),
                 name: Some("xxxxxxxx".to_string()),
                 ..Default::default()
             }),
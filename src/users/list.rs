@@ -1,5 +1,6 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::TeamId;
 use crate::users::user::User;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -10,7 +11,7 @@ pub struct ListRequest {
     pub cursor: Option<String>,
     pub include_locale: Option<bool>,
     pub limit: Option<i32>,
-    pub team_id: Option<String>,
+    pub team_id: Option<TeamId>,
 }
 
 #[skip_serializing_none]
@@ -20,7 +21,7 @@ pub struct ListResponse {
     pub error: Option<String>,
     pub response_metadata: Option<ResponseMetadata>,
     pub members: Option<Vec<User>>,
-    pub cache_ts: Option<i32>,
+    pub cache_ts: Option<i64>,
 }
 
 pub async fn list<T>(
@@ -31,11 +32,10 @@ pub async fn list<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("users.list");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("users.list", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<ListResponse>(&result).map_err(Error::SerdeJsonError)
@@ -56,7 +56,7 @@ mod test {
             cursor: Some("test".to_string()),
             include_locale: Some(true),
             limit: Some(1),
-            team_id: Some("test".to_string()),
+            team_id: Some("test".into()),
         };
         let json = r##"{
   "cursor": "test",
@@ -77,8 +77,8 @@ mod test {
         let response = ListResponse {
             ok: true,
             members: Some(vec![User {
-                id: Some("USLAOCKO".to_string()),
-                team_id: Some("T02H783QNL9".to_string()),
+                id: Some("USLAOCKO".into()),
+                team_id: Some("T02H783QNL9".into()),
                 name: Some("slackbot".to_string()),
                 deleted: Some(false),
                 color: Some("757575".to_string()),
@@ -107,7 +107,7 @@ mod test {
                     image_192: Some("https://example.com/img/avatar-slackbot.png".to_string()),
                     image_512: Some("https://example.com/img/slackbot_512.png".to_string()),
                     status_text_canonical: Some("".to_string()),
-                    team: Some("T02H7RHQNL9".to_string()),
+                    team: Some("T02H7RHQNL9".into()),
                     ..Default::default()
                 }),
                 is_admin: Some(false),
@@ -186,12 +186,12 @@ mod test {
     #[async_std::test]
     async fn test_list() {
         let param = ListRequest {
-            team_id: Some("test_list".to_string()),
+            team_id: Some("test_list".into()),
             ..Default::default()
         };
 
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "members": [
@@ -251,8 +251,8 @@ mod test {
         let expect = ListResponse {
             ok: true,
             members: Some(vec![User {
-                id: Some("USLAOCKO".to_string()),
-                team_id: Some("test_list".to_string()),
+                id: Some("USLAOCKO".into()),
+                team_id: Some("test_list".into()),
                 name: Some("slackbot".to_string()),
                 deleted: Some(false),
                 color: Some("757575".to_string()),
@@ -281,7 +281,7 @@ mod test {
                     image_192: Some("https://example.com/img/avatar-slackbot.png".to_string()),
                     image_512: Some("https://example.com/img/slackbot_512.png".to_string()),
                     status_text_canonical: Some("".to_string()),
-                    team: Some("T02H7RHQNL9".to_string()),
+                    team: Some("T02H7RHQNL9".into()),
                     ..Default::default()
                 }),
                 is_admin: Some(false),
@@ -1,5 +1,5 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
 use crate::users::user::User;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -26,11 +26,10 @@ pub async fn lookup_by_email<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("users.lookupByEmail");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("users.lookupByEmail", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<LookupByEmailResponse>(&result).map_err(Error::SerdeJsonError)
@@ -64,7 +63,7 @@ mod test {
         let response = LookupByEmailResponse {
             ok: true,
             user: Some(User {
-                id: Some("Sonny Whether".to_string()),
+                id: Some("Sonny Whether".into()),
                 name: Some("U0G9QF9C6".to_string()),
                 profile: Some(UserProfile {
                     email: Some("spengler@ghostbusters.example.com".to_string()),
@@ -98,7 +97,7 @@ mod test {
             email: "spengler@ghostbusters.example.com".to_string(),
         };
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "user": {
@@ -118,7 +117,7 @@ mod test {
         let expect = LookupByEmailResponse {
             ok: true,
             user: Some(User {
-                id: Some("Sonny Whether".to_string()),
+                id: Some("Sonny Whether".into()),
                 name: Some("U0G9QF9C6".to_string()),
                 profile: Some(UserProfile {
                     email: Some("spengler@ghostbusters.example.com".to_string()),
@@ -1,10 +1,19 @@
+#[cfg(feature = "web-api-users")]
 pub mod conversations;
+#[cfg(feature = "web-api-users")]
 pub mod delete_photo;
+#[cfg(feature = "web-api-users")]
 pub mod get_presence;
+#[cfg(feature = "web-api-users")]
 pub mod identity;
+#[cfg(feature = "web-api-users")]
 pub mod info;
+#[cfg(feature = "web-api-users")]
 pub mod list;
+#[cfg(feature = "web-api-users")]
 pub mod lookup_by_email;
+#[cfg(feature = "web-api-users")]
 pub mod profile;
+#[cfg(feature = "web-api-users")]
 pub mod set_presence;
 pub mod user;
@@ -1,5 +1,6 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, SlackWebAPIClient};
+use crate::http_client::SlackWebAPIClient;
+use crate::id::UserId;
 use crate::profiles::profile::Profile;
 
 use serde::{Deserialize, Serialize};
@@ -7,7 +8,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct GetRequest {
     pub include_labels: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<UserId>,
 }
 
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
@@ -21,11 +22,10 @@ pub async fn get<T>(client: &T, param: &GetRequest, bot_token: &str) -> Result<G
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("users.profile.get");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("users.profile.get", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<GetResponse>(&result).map_err(Error::SerdeJsonError)
@@ -1,5 +1,6 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, SlackWebAPIClient};
+use crate::http_client::SlackWebAPIClient;
+use crate::id::UserId;
 use crate::profiles::profile::Profile;
 
 use serde::{Deserialize, Serialize};
@@ -8,7 +9,7 @@ use serde::{Deserialize, Serialize};
 pub struct SetRequest {
     pub name: Option<String>,
     pub profile: Option<Profile>,
-    pub user: Option<String>,
+    pub user: Option<UserId>,
     pub value: Option<String>,
 }
 
@@ -23,11 +24,10 @@ pub async fn set<T>(client: &T, param: &SetRequest, bot_token: &str) -> Result<S
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("users.profile.set");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("users.profile.set", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<SetResponse>(&result).map_err(Error::SerdeJsonError)
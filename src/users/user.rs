@@ -1,5 +1,7 @@
+use crate::id::{AppId, BotId, TeamId, UserId};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use std::collections::HashMap;
 
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
@@ -21,12 +23,12 @@ pub struct UserProfile {
     pub image_512: Option<String>,
     pub mage_original: Option<String>,
     pub title: Option<String>,
-    pub bot_id: Option<String>,
-    pub api_app_id: Option<String>,
+    pub bot_id: Option<BotId>,
+    pub api_app_id: Option<AppId>,
     pub status_text: Option<String>,
     pub status_emoji: Option<String>,
-    pub status_expiration: Option<i32>,
-    pub team: Option<String>,
+    pub status_expiration: Option<i64>,
+    pub team: Option<TeamId>,
     pub always_active: Option<bool>,
     pub status_text_canonical: Option<String>,
 }
@@ -34,8 +36,8 @@ pub struct UserProfile {
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct User {
-    pub id: Option<String>,
-    pub team_id: Option<String>,
+    pub id: Option<UserId>,
+    pub team_id: Option<TeamId>,
     pub name: Option<String>,
     pub deleted: Option<bool>,
     pub color: Option<String>,
@@ -53,7 +55,9 @@ pub struct User {
     pub is_stranger: Option<bool>,
     pub is_app_user: Option<bool>,
     pub is_invited_user: Option<bool>,
-    pub updated: Option<i32>,
+    pub updated: Option<i64>,
     pub is_email_confirmed: Option<bool>,
     pub who_can_share_contact_card: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
@@ -1,5 +1,9 @@
+#[cfg(feature = "web-api-views")]
 pub mod open;
+#[cfg(feature = "web-api-views")]
 pub mod publish;
+#[cfg(feature = "web-api-views")]
 pub mod push;
+#[cfg(feature = "web-api-views")]
 pub mod update;
 pub mod view;
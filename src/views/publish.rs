@@ -1,5 +1,6 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::UserId;
 use crate::views::view::View;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -7,7 +8,7 @@ use serde_with::skip_serializing_none;
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct PublishRequest {
-    pub user_id: String,
+    pub user_id: UserId,
     pub view: View,
     pub hash: Option<String>,
 }
@@ -29,11 +30,10 @@ pub async fn publish<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("views.publish");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("views.publish", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<PublishResponse>(&result).map_err(Error::SerdeJsonError)
@@ -57,7 +57,7 @@ mod test {
     #[test]
     fn convert_request() {
         let request = PublishRequest {
-            user_id: "U0BPQUNTA".to_string(),
+            user_id: "U0BPQUNTA".into(),
             view: View {
                 type_filed: Some(ViewType::Modal),
                 title: Some(TextBlockObject {
@@ -368,7 +368,7 @@ mod test {
     #[async_std::test]
     async fn test_publish() {
         let param = PublishRequest {
-            user_id: "U0BPQUNTA".to_string(),
+            user_id: "U0BPQUNTA".into(),
             view: View {
                 type_filed: Some(ViewType::Modal),
                 title: Some(TextBlockObject {
@@ -445,7 +445,7 @@ mod test {
             hash: Some("156772938.1827394".to_string()),
         };
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "view": {
@@ -1,5 +1,6 @@
 use crate::error::Error;
-use crate::http_client::{get_slack_url, ResponseMetadata, SlackWebAPIClient};
+use crate::http_client::{ResponseMetadata, SlackWebAPIClient};
+use crate::id::ViewId;
 use crate::views::view::View;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -10,7 +11,7 @@ pub struct UpdateRequest {
     pub trigger_id: String,
     pub view: View,
     pub external_id: Option<String>,
-    pub view_id: Option<String>,
+    pub view_id: Option<ViewId>,
     pub hash: Option<String>,
 }
 
@@ -31,11 +32,10 @@ pub async fn update<T>(
 where
     T: SlackWebAPIClient,
 {
-    let url = get_slack_url("views.update");
     let json = serde_json::to_string(&param)?;
 
     client
-        .post_json(&url, &json, bot_token)
+        .call_json("views.update", &json, bot_token)
         .await
         .and_then(|result| {
             serde_json::from_str::<UpdateResponse>(&result).map_err(Error::SerdeJsonError)
@@ -133,7 +133,7 @@ mod test {
                 ]),
                 ..Default::default()
             },
-            view_id: Some("VMM512F2U".to_string()),
+            view_id: Some("VMM512F2U".into()),
             hash: Some("156772938.1827394".to_string()),
             ..Default::default()
         };
@@ -447,12 +447,12 @@ mod test {
                 ]),
                 ..Default::default()
             },
-            view_id: Some("VMM512F2U".to_string()),
+            view_id: Some("VMM512F2U".into()),
             hash: Some("156772938.1827394".to_string()),
             ..Default::default()
         };
         let mut mock = MockSlackWebAPIClient::new();
-        mock.expect_post_json().returning(|_, _, _| {
+        mock.expect_call_json().returning(|_, _, _| {
             Ok(r##"{
   "ok": true,
   "view": {
@@ -1,5 +1,9 @@
 use crate::block::block_object::TextBlockObject;
 use crate::block::blocks::{Block, BlockAction};
+use crate::block::validation::{
+    check_max_length, validate_blocks, Validate, ValidationError, MAX_VIEW_BLOCKS,
+};
+use crate::id::{AppId, BotId, TeamId, ViewId};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use std::collections::HashMap;
@@ -13,8 +17,8 @@ pub struct ViewState {
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
 pub struct View {
-    pub id: Option<String>,
-    pub team_id: Option<String>,
+    pub id: Option<ViewId>,
+    pub team_id: Option<TeamId>,
     #[serde(rename = "type")]
     pub type_filed: Option<ViewType>,
     pub blocks: Option<Vec<Block>>,
@@ -29,9 +33,11 @@ pub struct View {
     pub notify_on_close: Option<bool>,
     pub root_view_id: Option<String>,
     pub previous_view_id: Option<String>,
-    pub app_id: Option<String>,
+    pub app_id: Option<AppId>,
     pub external_id: Option<String>,
-    pub bot_id: Option<String>,
+    pub bot_id: Option<BotId>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 #[derive(Deserialize, Serialize, Debug, PartialEq)]
@@ -39,4 +45,64 @@ pub struct View {
 pub enum ViewType {
     Modal,
     Home,
+    /// A value this crate doesn't know yet.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+const MAX_VIEW_TEXT_LENGTH: usize = 24;
+const MAX_PRIVATE_METADATA_LENGTH: usize = 3000;
+const MAX_CALLBACK_ID_LENGTH: usize = 255;
+const MAX_EXTERNAL_ID_LENGTH: usize = 255;
+
+impl Validate for View {
+    fn validate_at(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if let Some(blocks) = &self.blocks {
+            validate_blocks(blocks, MAX_VIEW_BLOCKS, &format!("{}/blocks", path), errors);
+        }
+        if self.type_filed == Some(ViewType::Modal) && self.title.is_none() {
+            errors.push(ValidationError::new(
+                format!("{}/title", path),
+                "is required for modals".to_string(),
+            ));
+        }
+        for (name, text) in [
+            ("title", &self.title),
+            ("close", &self.close),
+            ("submit", &self.submit),
+        ] {
+            if let Some(text) = text {
+                check_max_length(
+                    &text.text,
+                    MAX_VIEW_TEXT_LENGTH,
+                    &format!("{}/{}/text", path, name),
+                    errors,
+                );
+            }
+        }
+        if let Some(private_metadata) = &self.private_metadata {
+            check_max_length(
+                private_metadata,
+                MAX_PRIVATE_METADATA_LENGTH,
+                &format!("{}/private_metadata", path),
+                errors,
+            );
+        }
+        if let Some(callback_id) = &self.callback_id {
+            check_max_length(
+                callback_id,
+                MAX_CALLBACK_ID_LENGTH,
+                &format!("{}/callback_id", path),
+                errors,
+            );
+        }
+        if let Some(external_id) = &self.external_id {
+            check_max_length(
+                external_id,
+                MAX_EXTERNAL_ID_LENGTH,
+                &format!("{}/external_id", path),
+                errors,
+            );
+        }
+    }
 }